@@ -0,0 +1,28 @@
+use bls381::{g1::G1Projective, scalar::Scalar};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use math::msm::{msm, naive_msm};
+use traits::traits::Field;
+
+const SIZES: [usize; 3] = [1 << 8, 1 << 10, 1 << 12];
+
+fn msm_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("msm_g1");
+    group.sample_size(10);
+    for size in SIZES {
+        let bases: Vec<G1Projective> = (0..size)
+            .map(|_| G1Projective::generator() * Scalar::random())
+            .collect();
+        let scalars: Vec<Scalar> = (0..size).map(|_| Scalar::random()).collect();
+
+        group.bench_with_input(BenchmarkId::new("pippenger", size), &size, |bench, _| {
+            bench.iter(|| msm(&bases, &scalars))
+        });
+        group.bench_with_input(BenchmarkId::new("naive", size), &size, |bench, _| {
+            bench.iter(|| naive_msm(&bases, &scalars))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(msm_group, msm_bench);
+criterion_main!(msm_group);
@@ -0,0 +1,187 @@
+use core::DeserializationError;
+
+use crypto_bigint::{
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater},
+    Encoding, U256,
+};
+use math::edwards::{
+    decode_y_with_sign, EdwardsAffine, EdwardsEncoding, EdwardsExtended, EdwardsParameters,
+};
+use traits::traits::Field;
+
+use crate::{
+    bandersnatch_scalar::{BandScalar, BANDSCALAR_MODULUS},
+    scalar::{Scalar, SCALAR_MODULUS},
+};
+
+// Bandersnatch: the twisted Edwards curve -5 * x^2 + y^2 = 1 + d * x^2 * y^2 over the scalar field
+// of BLS12-381 (https://eprint.iacr.org/2021/1152). The group of points has order 4 * n where n is
+// the modulus of BandScalar. Neither a nor d is a square, so the unified addition formulas are only
+// guaranteed to be complete on the prime order subgroup.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BandersnatchParameters;
+
+impl EdwardsParameters for BandersnatchParameters {
+    type BaseField = Scalar;
+
+    type ScalarField = BandScalar;
+
+    // a = -5
+    const COEFF_A: Scalar = Scalar(U256::from_be_hex(
+        "73eda753299d7d483339d80809a1d80553bda402fffe5bfefffffffefffffffc",
+    ));
+
+    // d = 45022363124591815672509500913686876175488063829319466900776701791074614335719
+    const COEFF_D: Scalar = Scalar(U256::from_be_hex(
+        "6389c12633c267cbc66e3bf86be3b6d8cb66677177e54f92b369f2f5188d58e7",
+    ));
+
+    // x = 18886178867200960497001835917649091219057080094937609519140440539760939937304
+    const GENERATOR_X: Scalar = Scalar(U256::from_be_hex(
+        "29c132cc2c0b34c5743711777bbe42f32b79c022ad998465e1e71866a252ae18",
+    ));
+
+    // y = 19188667384257783945677642223292697773471335439753913231509108946878080696678
+    const GENERATOR_Y: Scalar = Scalar(U256::from_be_hex(
+        "2a6c669eda123e0f157d8b50badcd586358cad81eee464605e3167b6cc974166",
+    ));
+
+    const SUBGROUP_ORDER: U256 = BANDSCALAR_MODULUS;
+
+    const COFACTOR: u64 = 4;
+}
+
+pub type BandersnatchAffine = EdwardsAffine<BandersnatchParameters>;
+pub type BandersnatchExtended = EdwardsExtended<BandersnatchParameters>;
+
+// ENDOMORPHISM AND GLV CONSTANTS
+// ================================================================================================
+
+// Coefficients b and c of the degree 2 endomorphism psi(x, y) = (f(y) * h(y) / (x * y), g(y) / h(y))
+// with f(y) = c * (1 - y^2), g(y) = b * (y^2 + b) and h(y) = y^2 - b
+const ENDO_B: Scalar = Scalar(U256::from_be_hex(
+    "52c9f28b828426a561f00d3a63511a882ea712770d9af4d6ee0f014d172510b4",
+));
+const ENDO_C: Scalar = Scalar(U256::from_be_hex(
+    "6cc624cf865457c3a97c6efd6c17d1078456abcfff36f4e9515c806cdf650b3d",
+));
+
+// Eigenvalue of psi on the prime order subgroup, a square root of -2 modulo n
+// lambda = 8913659658109529928382530854484400854125314752504019737736543920008458395397
+pub const ENDO_LAMBDA: BandScalar = BandScalar(U256::from_be_hex(
+    "13b4f3dc4a39a493edf849562b38c72bcfc49db970a5056ed13d21408783df05",
+));
+
+// Short basis (a1, b1), (a2, b2) of the lattice {(k1, k2) : k1 + k2 * lambda = 0 mod n}, with
+// b2 = -a1
+const GLV_A1: U256 = U256::from_be_hex("00000000000000000000000000000000555fe2004be6928e4b02f94a9789181f");
+const GLV_B1: U256 = U256::from_be_hex("000000000000000000000000000000000814b3eee55e8f5df8e2591a23d61f44");
+const GLV_A2: U256 = U256::from_be_hex("00000000000000000000000000000000102967ddcabd1ebbf1c4b23447ac3e88");
+
+// Rounding constants g1 = floor(-b2 * 2^256 / n) and g2 = floor(b1 * 2^256 / n)
+const GLV_G1: U256 = U256::from_be_hex("00000000000000000000000000000002f21df5b0541cf632debac77a3f4747c1");
+const GLV_G2: U256 = U256::from_be_hex("000000000000000000000000000000004760f127d8767bde993b75e7547768aa");
+
+// Both halves of the decomposition are smaller than 2^127 in absolute value
+const GLV_BITS: usize = 128;
+
+// n/2, used to decide whether a half of the decomposition is negative
+const HALF_BANDSCALAR_MODULUS: U256 = BANDSCALAR_MODULUS.shr_vartime(1);
+
+// Applies the endomorphism psi, which acts as multiplication by ENDO_LAMBDA on the prime order
+// subgroup. Costs a few multiplications, against ~250 doublings for a scalar multiplication.
+pub fn endomorphism(p: &BandersnatchExtended) -> BandersnatchExtended {
+    let y2 = p.y.square();
+    let z2 = p.z.square();
+    let bz2 = ENDO_B * z2;
+    let f = ENDO_C * (z2 - y2);
+    let g = ENDO_B * (y2 + bz2);
+    let h = y2 - bz2;
+    let xy = p.x * p.y;
+    let res = BandersnatchExtended {
+        x: f * h,
+        y: g * xy,
+        z: h * xy,
+        t: f * g,
+    };
+    // the formula is undefined at the identity, which is fixed by psi
+    BandersnatchExtended::conditional_select(
+        &res,
+        &BandersnatchExtended::identity(),
+        Choice::from(p.is_identity() as u8),
+    )
+}
+
+// Splits k into k1 + k2 * lambda mod n with |k1|, |k2| < 2^127 and returns the absolute values
+// along with their signs
+fn glv_decompose(k: &BandScalar) -> [(BandScalar, Choice); 2] {
+    let c1 = BandScalar::new(k.0.mul_wide(&GLV_G1).1);
+    let c2 = BandScalar::new(k.0.mul_wide(&GLV_G2).1);
+    let a1 = BandScalar(GLV_A1);
+    let b1 = BandScalar(GLV_B1);
+    let a2 = BandScalar(GLV_A2);
+    // k1 = k - c1 * a1 - c2 * a2 and k2 = -c1 * b1 + c2 * a1 since b2 = -a1
+    let k1 = *k - c1 * a1 - c2 * a2;
+    let k2 = c2 * a1 - c1 * b1;
+    [k1, k2].map(|ki| {
+        let is_neg = ki.0.ct_gt(&HALF_BANDSCALAR_MODULUS);
+        (BandScalar::conditional_select(&ki, &-ki, is_neg), is_neg)
+    })
+}
+
+// Scalar multiplication using the GLV method: [k]P = [k1]P + [k2]psi(P), evaluated with a joint
+// double and add over 128 bits instead of 253. Constant time in the scalar; P is expected to lie in
+// the prime order subgroup, where psi acts as ENDO_LAMBDA.
+pub fn glv_mul(p: &BandersnatchExtended, k: &BandScalar) -> BandersnatchExtended {
+    let [(k1, k1_neg), (k2, k2_neg)] = glv_decompose(k);
+    let p1 = BandersnatchExtended::conditional_select(p, &-*p, k1_neg);
+    let psi = endomorphism(p);
+    let p2 = BandersnatchExtended::conditional_select(&psi, &-psi, k2_neg);
+    let table = [BandersnatchExtended::identity(), p1, p2, p1 + p2];
+
+    let k1 = k1.0.to_words();
+    let k2 = k2.0.to_words();
+    let mut res = BandersnatchExtended::identity();
+    for i in (0..GLV_BITS).rev() {
+        res = res.double();
+        let bit1 = ((k1[i / 64] >> (i % 64)) & 1) as u8;
+        let bit2 = ((k2[i / 64] >> (i % 64)) & 1) as u8;
+        let index = bit1 | (bit2 << 1);
+        let mut addend = BandersnatchExtended::identity();
+        for (j, entry) in table.iter().enumerate() {
+            addend =
+                BandersnatchExtended::conditional_select(&addend, entry, (j as u8).ct_eq(&index));
+        }
+        res += addend;
+    }
+    res
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+// Size in bytes of a compressed point
+pub const COMPRESSED_BYTES: usize = 32;
+
+impl EdwardsEncoding for BandersnatchParameters {
+    const ENCODED_BYTES: usize = COMPRESSED_BYTES;
+
+    type Encoding = [u8; COMPRESSED_BYTES];
+
+    // Compressed encoding compatible with arkworks: y in little-endian, with the most significant
+    // bit of the last byte set when x is the larger of x and -x
+    fn encode(point: &BandersnatchAffine) -> [u8; COMPRESSED_BYTES] {
+        let mut bytes = point.y.0.to_le_bytes();
+        if point.x > -point.x {
+            bytes[COMPRESSED_BYTES - 1] |= 0x80;
+        }
+        bytes
+    }
+
+    fn decode(bytes: &[u8; COMPRESSED_BYTES]) -> Result<BandersnatchAffine, DeserializationError> {
+        decode_y_with_sign(bytes, |bytes| {
+            let y = U256::from_le_bytes(bytes);
+            (y < SCALAR_MODULUS).then_some(Scalar(y))
+        })
+    }
+}
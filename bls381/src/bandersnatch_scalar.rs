@@ -77,6 +77,12 @@ impl BandScalar {
         let inner = U256::from_be_byte_array(*bytes);
         <BandScalar as Field>::from_uint_reduced(BandScalar(inner))
     }
+    // returns the 512-bit little-endian integer reduced by the modulus.
+    pub fn from_bytes_wide(bytes: &[u8; 64]) -> BandScalar {
+        let lo = U256::from_le_slice(&bytes[..32]);
+        let hi = U256::from_le_slice(&bytes[32..]);
+        BandScalar(barrett_reduce(lo, hi))
+    }
     //zeroed vector
     pub fn zeroed_vector(n: usize) -> Vec<Self> {
         // this uses a specialized vector initialization code which requests zero-filled memory
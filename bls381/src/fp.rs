@@ -95,10 +95,10 @@ impl Fp {
     // returns 1  if the field element is quadratic residue and -1 if it is not a quadratic residue.
     pub fn legendre(&self) -> i32 {
         // s = self^((MODULUS - 1) // 2)
-        let s = self.power_by(&MODULUS_MINUS_ONE_DIV_TWO.0.to_words());
+        let s = self.power_by(MODULUS_MINUS_ONE_DIV_TWO.from_montgomery().0.to_words());
         if bool::from(s.is_zero()) {
             0
-        } else if s == Fp(U384::from_u8(1)) {
+        } else if s == Self::ONE {
             1 //  quadratic residue
         } else {
             -1 // non-quadratic residue
@@ -136,6 +136,27 @@ impl Fp {
     pub fn to_bytes(a: &Fp) -> FieldBytes {
         a.0.to_be_byte_array()
     }
+
+    // returns the big-endian encoding of the canonical (non-montgomery) value of the element
+    pub fn to_canonical_bytes(&self) -> [u8; ELEMENT_BYTES] {
+        self.from_montgomery().0.to_be_bytes()
+    }
+
+    // returns the element whose canonical value has the given big-endian encoding, or None if
+    // the value is not smaller than the modulus
+    pub fn from_canonical_bytes(bytes: &[u8; ELEMENT_BYTES]) -> Option<Fp> {
+        let value = U384::from_be_bytes(*bytes);
+        if value >= MODULUS {
+            return None;
+        }
+        Some(Fp(value).to_montgomery())
+    }
+
+    // checks whether the canonical value of the element is larger than (p - 1) / 2, which
+    // distinguishes y from -y in compressed point encodings
+    pub fn lexicographically_largest(&self) -> bool {
+        self.from_montgomery().0 > MODULUS_MINUS_ONE.shr_vartime(1)
+    }
 }
 
 impl Field for Fp {
@@ -285,6 +306,10 @@ impl PrimeField for Fp {
         assert!(k == 1, "2^{k}th root does not exist");
         Fp::new(U384::from_be_hex(TWO_ADIC_ROOT))
     }
+
+    fn to_canonical_words(&self) -> Vec<u64> {
+        self.from_montgomery().0.to_words().to_vec()
+    }
     // modulus of the prime field
     const MODULUS:&'static str="1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab";
 
@@ -117,6 +117,16 @@ impl <B:Extensible<2>+Extensible<3>>Fp12<B>{
     let d1=self.c1.mul_scalar(s);
     Self { c0: d0, c1: d1 }
     }
+    // multiplies the Fp12 element by the sparse element c0 + c1*v + c4*v*w, which is the shape
+    // of the line functions evaluated in the Miller loop
+    pub fn mul_by_014(&self, c0: &Fp2<B>, c1: &Fp2<B>, c4: &Fp2<B>) -> Self {
+        let aa = self.c0.mul_by_01(c0, c1);
+        let mut bb = self.c1.mul_by_1(c4);
+        let o = *c1 + *c4;
+        let c1 = (self.c1 + self.c0).mul_by_01(c0, &o) - aa - bb;
+        let c0 = bb.mul_by_nonresidue() + aa;
+        Self { c0, c1 }
+    }
     pub fn cyclotomic_inverse(&self) -> Self {
         self.conjugate()
     }
@@ -124,29 +134,29 @@ impl <B:Extensible<2>+Extensible<3>>Fp12<B>{
         // Faster Squaring in the Cyclotomic Subgroup of Sixth Degree Extensions
         // - Robert Granger and Michael Scott
         //
-        let fp2_nr = Fp6::mul_fp2_by_nonresidue_in_place;
+        let fp2_nr = |mut fe: Fp2<B>| *Fp6::mul_fp2_by_nonresidue_in_place(&mut fe);
 
         let r0 = self.c0.c0;
         let r4 = self.c0.c1;
-        let mut r3 = self.c0.c2;
+        let r3 = self.c0.c2;
         let r2 = self.c1.c0;
-        let mut r1 = self.c1.c1;
-        let mut r5 = self.c1.c2;
+        let r1 = self.c1.c1;
+        let r5 = self.c1.c2;
 
         // t0 + t1*y = (z0 + z1*y)^2 = a^2
         let mut tmp = r0 * r1;
-        let t0 = (r0 + r1) * (*fp2_nr(&mut r1) + r0) - tmp - *fp2_nr(&mut tmp);
+        let t0 = (r0 + r1) * (fp2_nr(r1) + r0) - tmp - fp2_nr(tmp);
         let t1 = tmp.double();
 
         // t2 + t3*y = (z2 + z3*y)^2 = b^2
         tmp = r2 * r3;
-        let t2 = (r2 + r3) * (*fp2_nr(&mut r3) + r2) - tmp - *fp2_nr(&mut tmp);
+        let t2 = (r2 + r3) * (fp2_nr(r3) + r2) - tmp - fp2_nr(tmp);
         let t3 = tmp.double();
 
         // t4 + t5*y = (z4 + z5*y)^2 = c^2
         tmp = r4 * r5;
-        let t4 = (r4 + r5) * (*fp2_nr(&mut r5) + r4) - tmp - *fp2_nr(&mut tmp);
-        let mut t5 = tmp.double();
+        let t4 = (r4 + r5) * (fp2_nr(r5) + r4) - tmp - fp2_nr(tmp);
+        let t5 = tmp.double();
 
         let mut z0 = self.c0.c0;
         let mut z4 = self.c0.c1;
@@ -170,7 +180,7 @@ impl <B:Extensible<2>+Extensible<3>>Fp12<B>{
         // for B
 
         // z2 = 3 * (xi * t5) + 2 * z2
-        tmp = *fp2_nr(&mut t5);
+        tmp = fp2_nr(t5);
         z2 += tmp;
         z2 = z2.double();
         z2 += tmp;
@@ -193,8 +203,8 @@ impl <B:Extensible<2>+Extensible<3>>Fp12<B>{
         z5 += t3;
 
         Self {
-            c0: Fp6::new(z0, z1, z2),
-            c1: Fp6::new(z3, z4, z5),
+            c0: Fp6::new(z0, z4, z3),
+            c1: Fp6::new(z2, z1, z5),
         }
     }
 
@@ -459,9 +459,19 @@ impl<B:Extensible<2>> Extensible<2> for Fp2<B>{
     }
 }
 impl<B:Extensible<2>> ExtensionOf<B> for Fp2<B> where Fp2<B>: From<B> {
+    const DEGREE: usize = 2;
+
     fn mul_base(self, other: B) -> Self {
         B::mul_base([self.c0, self.c1], other).into()
     }
+
+    fn to_base_elements(self) -> Vec<B> {
+        vec![self.c0, self.c1]
+    }
+
+    fn from_base_elements(elements: &[B]) -> Self {
+        Fp2::new(elements[0], elements[1])
+    }
 }
 
 impl <B:Extensible<2>> Display for Fp2<B>{
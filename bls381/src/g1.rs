@@ -0,0 +1,126 @@
+use core::DeserializationError;
+use crypto_bigint::{U256, U384};
+use math::weierstrass::{
+    AffinePoint, CurveEncoding, CurveParameters, JacobianPoint, ProjectivePoint,
+};
+use traits::traits::Field;
+
+use crate::{
+    fp::Fp,
+    scalar::{Scalar, SCALAR_MODULUS},
+};
+
+// G1 of BLS12-381: the order r subgroup of E(Fp) : y^2 = x^3 + 4
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct G1Parameters;
+
+impl CurveParameters for G1Parameters {
+    type BaseField = Fp;
+
+    type ScalarField = Scalar;
+
+    const COEFF_B: Fp = Fp(U384::from_u8(4)).to_montgomery();
+
+    const COEFF_B3: Fp = Fp(U384::from_u8(12)).to_montgomery();
+
+    // x = 3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507
+    const GENERATOR_X: Fp = Fp(U384::from_be_hex("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb")).to_montgomery();
+
+    // y = 1339506544944476473020471379941921221584933875938349620426543736416511423956333506472724655353366534992391756441569
+    const GENERATOR_Y: Fp = Fp(U384::from_be_hex("08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1")).to_montgomery();
+
+    const SUBGROUP_ORDER: U256 = SCALAR_MODULUS;
+}
+
+pub type G1Affine = AffinePoint<G1Parameters>;
+pub type G1Projective = ProjectivePoint<G1Parameters>;
+pub type G1Jacobian = JacobianPoint<G1Parameters>;
+
+// Size in bytes of a compressed G1 point
+pub const G1_COMPRESSED_BYTES: usize = 48;
+
+// Flags stored in the three most significant bits of the first byte of compressed points
+pub(crate) const FLAG_COMPRESSED: u8 = 0x80;
+pub(crate) const FLAG_INFINITY: u8 = 0x40;
+pub(crate) const FLAG_Y_LARGEST: u8 = 0x20;
+pub(crate) const FLAGS_MASK: u8 = 0xe0;
+
+// COMPRESSION
+// ================================================================================================
+// Compressed encoding of the ZCash BLS12-381 serialization format, used by Ethereum: x in big-endian
+// with the compression flag set, the infinity flag for the point at infinity, and the sign flag
+// when y is the lexicographically largest of the two square roots.
+
+impl CurveEncoding for G1Parameters {
+    const ENCODED_BYTES: usize = G1_COMPRESSED_BYTES;
+
+    type Encoding = [u8; G1_COMPRESSED_BYTES];
+
+    fn encode(point: &G1Affine) -> [u8; G1_COMPRESSED_BYTES] {
+        let mut bytes = [0u8; G1_COMPRESSED_BYTES];
+        if point.is_identity() {
+            bytes[0] = FLAG_COMPRESSED | FLAG_INFINITY;
+            return bytes;
+        }
+        bytes.copy_from_slice(&point.x.to_canonical_bytes());
+        bytes[0] |= FLAG_COMPRESSED;
+        if point.y.lexicographically_largest() {
+            bytes[0] |= FLAG_Y_LARGEST;
+        }
+        bytes
+    }
+
+    fn decode(bytes: &[u8; G1_COMPRESSED_BYTES]) -> Result<G1Affine, DeserializationError> {
+        let flags = read_flags(bytes[0])?;
+        let mut x_bytes = *bytes;
+        x_bytes[0] &= !FLAGS_MASK;
+        if flags & FLAG_INFINITY != 0 {
+            return decode_infinity(flags, &x_bytes);
+        }
+        let x = Fp::from_canonical_bytes(&x_bytes).ok_or_else(|| {
+            DeserializationError::InvalidValue(
+                "invalid point: x coordinate is not a canonical field element".to_string(),
+            )
+        })?;
+        let y = Option::<Fp>::from((x.cube() + G1Parameters::COEFF_B).sqrt())
+            .ok_or_else(not_on_curve)?;
+        let y = if y.lexicographically_largest() == (flags & FLAG_Y_LARGEST != 0) {
+            y
+        } else {
+            -y
+        };
+        Ok(G1Affine::new(x, y))
+    }
+}
+
+// Returns the flags of the first byte of a compressed point, which must have the compression flag
+pub(crate) fn read_flags(first_byte: u8) -> Result<u8, DeserializationError> {
+    let flags = first_byte & FLAGS_MASK;
+    if flags & FLAG_COMPRESSED == 0 {
+        return Err(DeserializationError::InvalidValue(
+            "invalid point: the compression flag is not set".to_string(),
+        ));
+    }
+    Ok(flags)
+}
+
+// Returns the point at infinity if its encoding is canonical: it has no sign and an all-zero
+// abscissa
+pub(crate) fn decode_infinity<C: CurveParameters>(
+    flags: u8,
+    x_bytes: &[u8],
+) -> Result<AffinePoint<C>, DeserializationError> {
+    if flags & FLAG_Y_LARGEST != 0 || x_bytes.iter().any(|&b| b != 0) {
+        return Err(DeserializationError::InvalidValue(
+            "invalid point: non canonical encoding of the point at infinity".to_string(),
+        ));
+    }
+    Ok(AffinePoint::identity())
+}
+
+// Error of an abscissa which is not the one of a point of the curve
+pub(crate) fn not_on_curve() -> DeserializationError {
+    DeserializationError::InvalidValue(
+        "invalid point: x coordinate does not belong to a point of the curve".to_string(),
+    )
+}
@@ -0,0 +1,117 @@
+use core::DeserializationError;
+use crypto_bigint::{U256, U384};
+use math::weierstrass::{
+    AffinePoint, CurveEncoding, CurveParameters, JacobianPoint, ProjectivePoint,
+};
+use traits::traits::Field;
+
+use crate::{
+    fp::{Fp, ELEMENT_BYTES},
+    fp2::Fp2,
+    g1::{
+        decode_infinity, not_on_curve, read_flags, FLAGS_MASK, FLAG_COMPRESSED, FLAG_INFINITY,
+        FLAG_Y_LARGEST,
+    },
+    scalar::{Scalar, SCALAR_MODULUS},
+};
+
+// G2 of BLS12-381: the order r subgroup of the sextic twist E'(Fp2) : y^2 = x^3 + 4(u + 1)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct G2Parameters;
+
+impl CurveParameters for G2Parameters {
+    type BaseField = Fp2<Fp>;
+
+    type ScalarField = Scalar;
+
+    const COEFF_B: Fp2<Fp> = Fp2::new(
+        Fp(U384::from_u8(4)).to_montgomery(),
+        Fp(U384::from_u8(4)).to_montgomery(),
+    );
+
+    const COEFF_B3: Fp2<Fp> = Fp2::new(
+        Fp(U384::from_u8(12)).to_montgomery(),
+        Fp(U384::from_u8(12)).to_montgomery(),
+    );
+
+    const GENERATOR_X: Fp2<Fp> = Fp2::new(
+        Fp(U384::from_be_hex("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8")).to_montgomery(),
+        Fp(U384::from_be_hex("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e")).to_montgomery(),
+    );
+
+    const GENERATOR_Y: Fp2<Fp> = Fp2::new(
+        Fp(U384::from_be_hex("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801")).to_montgomery(),
+        Fp(U384::from_be_hex("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be")).to_montgomery(),
+    );
+
+    const SUBGROUP_ORDER: U256 = SCALAR_MODULUS;
+}
+
+pub type G2Affine = AffinePoint<G2Parameters>;
+pub type G2Projective = ProjectivePoint<G2Parameters>;
+pub type G2Jacobian = JacobianPoint<G2Parameters>;
+
+// Size in bytes of a compressed G2 point
+pub const G2_COMPRESSED_BYTES: usize = 96;
+
+// COMPRESSION
+// ================================================================================================
+// Compressed encoding of the ZCash BLS12-381 serialization format: x.c1 || x.c0 in big-endian
+// with the same flags as G1 points in the first byte. An element of Fp2 is lexicographically
+// largest when its c1 coefficient is, or when c1 is zero and its c0 coefficient is.
+
+impl CurveEncoding for G2Parameters {
+    const ENCODED_BYTES: usize = G2_COMPRESSED_BYTES;
+
+    type Encoding = [u8; G2_COMPRESSED_BYTES];
+
+    fn encode(point: &G2Affine) -> [u8; G2_COMPRESSED_BYTES] {
+        let mut bytes = [0u8; G2_COMPRESSED_BYTES];
+        if point.is_identity() {
+            bytes[0] = FLAG_COMPRESSED | FLAG_INFINITY;
+            return bytes;
+        }
+        bytes[..ELEMENT_BYTES].copy_from_slice(&point.x.c1.to_canonical_bytes());
+        bytes[ELEMENT_BYTES..].copy_from_slice(&point.x.c0.to_canonical_bytes());
+        bytes[0] |= FLAG_COMPRESSED;
+        if lexicographically_largest(&point.y) {
+            bytes[0] |= FLAG_Y_LARGEST;
+        }
+        bytes
+    }
+
+    fn decode(bytes: &[u8; G2_COMPRESSED_BYTES]) -> Result<G2Affine, DeserializationError> {
+        let flags = read_flags(bytes[0])?;
+        let mut x_bytes = *bytes;
+        x_bytes[0] &= !FLAGS_MASK;
+        if flags & FLAG_INFINITY != 0 {
+            return decode_infinity(flags, &x_bytes);
+        }
+        let (c1_bytes, c0_bytes) = x_bytes.split_at(ELEMENT_BYTES);
+        let c0 = Fp::from_canonical_bytes(c0_bytes.try_into().unwrap());
+        let c1 = Fp::from_canonical_bytes(c1_bytes.try_into().unwrap());
+        let (Some(c0), Some(c1)) = (c0, c1) else {
+            return Err(DeserializationError::InvalidValue(
+                "invalid point: x coordinate is not a canonical field element".to_string(),
+            ));
+        };
+        let x = Fp2::new(c0, c1);
+        let y = Option::<Fp2<Fp>>::from((x.cube() + G2Parameters::COEFF_B).sqrt())
+            .ok_or_else(not_on_curve)?;
+        let y = if lexicographically_largest(&y) == (flags & FLAG_Y_LARGEST != 0) {
+            y
+        } else {
+            -y
+        };
+        Ok(G2Affine::new(x, y))
+    }
+}
+
+// Checks whether y is larger than -y in the lexicographic order of (c1, c0)
+fn lexicographically_largest(y: &Fp2<Fp>) -> bool {
+    if y.c1.is_zero() {
+        y.c0.lexicographically_largest()
+    } else {
+        y.c1.lexicographically_largest()
+    }
+}
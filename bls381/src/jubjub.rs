@@ -0,0 +1,102 @@
+use core::DeserializationError;
+
+use crypto_bigint::{Encoding, Integer, U256};
+use math::edwards::{EdwardsAffine, EdwardsEncoding, EdwardsExtended, EdwardsParameters};
+use traits::traits::Field;
+
+use crate::{
+    jubjub_scalar::{JubScalar, JUB_SCALAR_MODULUS},
+    scalar::{Scalar, SCALAR_MODULUS},
+};
+
+// Jubjub: the twisted Edwards curve -x^2 + y^2 = 1 + d * x^2 * y^2 with d = -(10240/10241) over the
+// scalar field of BLS12-381, as used by Zcash Sapling. The group of points has order 8 * r where r
+// is the modulus of JubScalar. d is not a square, so the addition formulas are complete on the
+// whole curve.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct JubjubParameters;
+
+impl EdwardsParameters for JubjubParameters {
+    type BaseField = Scalar;
+
+    type ScalarField = JubScalar;
+
+    // a = -1
+    const COEFF_A: Scalar = Scalar(U256::from_be_hex(
+        "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000",
+    ));
+
+    // d = -(10240/10241)
+    const COEFF_D: Scalar = Scalar(U256::from_be_hex(
+        "2a9318e74bfa2b48f5fd9207e6bd7fd4292d7f6d37579d2601065fd6d6343eb1",
+    ));
+
+    // generator of the prime order subgroup used by the jubjub crate of zkcrypto
+    // x = 8076246640662884909881801758704306714034609987455869804520522091855516602923
+    const GENERATOR_X: Scalar = Scalar(U256::from_be_hex(
+        "11dafe5d23e1218086a365b99fbf3d3be72f6afd7d1f72623e6b071492d1122b",
+    ));
+
+    // y = 13262374693698910701929044844600465831413122818447359594527400194675274060458
+    const GENERATOR_Y: Scalar = Scalar(U256::from_be_hex(
+        "1d523cf1ddab1a1793132e78c866c0c33e26ba5cc220fed7cc3f870e59d292aa",
+    ));
+
+    const SUBGROUP_ORDER: U256 = JUB_SCALAR_MODULUS;
+
+    const COFACTOR: u64 = 8;
+}
+
+pub type AffinePoint = EdwardsAffine<JubjubParameters>;
+pub type ExtendedPoint = EdwardsExtended<JubjubParameters>;
+
+// SERIALIZATION / DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+// Size in bytes of an encoded point
+pub const POINT_BYTES: usize = 32;
+
+impl EdwardsEncoding for JubjubParameters {
+    const ENCODED_BYTES: usize = POINT_BYTES;
+
+    type Encoding = [u8; POINT_BYTES];
+
+    // Zcash encoding: y in little-endian, with the most significant bit of the last byte set to the
+    // least significant bit of x
+    fn encode(point: &AffinePoint) -> [u8; POINT_BYTES] {
+        let mut bytes = point.y.0.to_le_bytes();
+        if bool::from(point.x.0.is_odd()) {
+            bytes[POINT_BYTES - 1] |= 0x80;
+        }
+        bytes
+    }
+
+    // Non canonical encodings are rejected as required by ZIP 216: y must be reduced and the sign
+    // bit must not be set when x = 0
+    fn decode(bytes: &[u8; POINT_BYTES]) -> Result<AffinePoint, DeserializationError> {
+        let mut bytes = *bytes;
+        let sign = (bytes[POINT_BYTES - 1] >> 7) == 1;
+        bytes[POINT_BYTES - 1] &= 0x7f;
+        let y = U256::from_le_bytes(bytes);
+        if y >= SCALAR_MODULUS {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid point: y coordinate {y} is greater than or equal to the field modulus"
+            )));
+        }
+        let y = Scalar(y);
+        let x = AffinePoint::recover_x(&y);
+        if bool::from(x.is_none()) {
+            return Err(DeserializationError::InvalidValue(
+                "invalid point: y coordinate does not belong to a point of the curve".to_string(),
+            ));
+        }
+        let x = x.unwrap();
+        if sign && x.is_zero() {
+            return Err(DeserializationError::InvalidValue(
+                "invalid point: sign bit set for x = 0".to_string(),
+            ));
+        }
+        let x = if bool::from(x.0.is_odd()) == sign { x } else { -x };
+        Ok(AffinePoint::new(x, y))
+    }
+}
@@ -64,6 +64,12 @@ impl JubScalar {
         let inner = U256::from_be_byte_array(*bytes);
         <JubScalar as Field>::from_uint_reduced(JubScalar(inner))
     }
+    // returns the 512-bit little-endian integer reduced by the modulus.
+    pub fn from_bytes_wide(bytes: &[u8; 64]) -> JubScalar {
+        let lo = U256::from_le_slice(&bytes[..32]);
+        let hi = U256::from_le_slice(&bytes[32..]);
+        JubScalar(barrett_reduce(lo, hi))
+    }
     // Shift right by one bit
     pub fn shr1(&mut self) {
         self.0 >>= 1;
@@ -0,0 +1,210 @@
+use std::{fmt, fs, path::Path};
+
+use crypto_bigint::U256;
+use math::{
+    fft::{bit_reverse_permutation, EvaluationDomain},
+    kzg::{KzgError, Srs},
+    polynomial::DensePolynomial,
+};
+
+use crate::{
+    g1::{G1Affine, G1Projective, G1_COMPRESSED_BYTES},
+    g2::{G2Affine, G2Projective, G2_COMPRESSED_BYTES},
+    pairing::Bls381,
+    scalar::{Scalar, SCALAR_MODULUS},
+};
+
+// Number of field elements of a blob
+pub const FIELD_ELEMENTS_PER_BLOB: usize = 4096;
+
+// Size in bytes of a field element of a blob
+pub const BYTES_PER_FIELD_ELEMENT: usize = 32;
+
+// Size in bytes of a blob
+pub const BYTES_PER_BLOB: usize = FIELD_ELEMENTS_PER_BLOB * BYTES_PER_FIELD_ELEMENT;
+
+// TRUSTED SETUP
+// ================================================================================================
+// The reference string of the Ethereum KZG ceremony, in the text format of c-kzg-4844: the number
+// of G1 points and the number of G2 points, followed by the G1 points in the Lagrange basis, the
+// G2 powers and the G1 powers, one compressed point in hex per line. Files of the earlier format
+// end after the G2 powers; their setup has the G1 generator as only monomial power, so it only
+// commits in the Lagrange basis.
+
+// Reads the reference string from a trusted setup file
+pub fn load_trusted_setup<P: AsRef<Path>>(path: P) -> Result<Srs<Bls381>, Eip4844Error> {
+    let contents = fs::read_to_string(path).map_err(|error| Eip4844Error::Io(error.to_string()))?;
+    parse_trusted_setup(&contents)
+}
+
+// Parses the reference string from the contents of a trusted setup file. The points are checked
+// to be on the curve but, as in c-kzg-4844, not to be in the prime order subgroup, which the
+// ceremony transcript already guarantees.
+pub fn parse_trusted_setup(contents: &str) -> Result<Srs<Bls381>, Eip4844Error> {
+    let mut tokens = contents.split_whitespace();
+    let g1_count = read_count(tokens.next())?;
+    let g2_count = read_count(tokens.next())?;
+
+    let g1_lagrange = (0..g1_count)
+        .map(|i| read_g1(tokens.next(), i))
+        .collect::<Result<Vec<G1Projective>, Eip4844Error>>()?;
+    let g2_powers = (0..g2_count)
+        .map(|i| read_g2(tokens.next(), i))
+        .collect::<Result<Vec<G2Projective>, Eip4844Error>>()?;
+    let mut tokens = tokens.peekable();
+    let g1_powers = if tokens.peek().is_none() {
+        vec![G1Projective::generator()]
+    } else {
+        (0..g1_count)
+            .map(|i| read_g1(tokens.next(), g1_count + i))
+            .collect::<Result<Vec<G1Projective>, Eip4844Error>>()?
+    };
+    if tokens.next().is_some() {
+        return Err(Eip4844Error::TrailingData);
+    }
+    Ok(Srs::new(g1_powers, g2_powers, g1_lagrange)?)
+}
+
+// BLOBS
+// ================================================================================================
+// A blob holds the evaluations of a polynomial of degree less than 4096 over the subgroup of size
+// 4096, in bit-reversed order, each as a big-endian integer smaller than the modulus.
+
+// Returns the field elements of the blob, in the order of the blob
+pub fn blob_to_field_elements(blob: &[u8]) -> Result<Vec<Scalar>, Eip4844Error> {
+    if blob.len() != BYTES_PER_BLOB {
+        return Err(Eip4844Error::InvalidBlobLength(blob.len()));
+    }
+    blob.chunks(BYTES_PER_FIELD_ELEMENT)
+        .enumerate()
+        .map(|(i, bytes)| {
+            let value = U256::from_be_slice(bytes);
+            if value >= SCALAR_MODULUS {
+                return Err(Eip4844Error::NonCanonicalFieldElement(i));
+            }
+            Ok(Scalar(value))
+        })
+        .collect()
+}
+
+// Returns the polynomial whose evaluations the blob holds, in coefficient form
+pub fn blob_to_polynomial(blob: &[u8]) -> Result<DensePolynomial<Scalar>, Eip4844Error> {
+    let mut values = blob_to_field_elements(blob)?;
+    bit_reverse_permutation(&mut values);
+    let domain = EvaluationDomain::<Scalar>::new(FIELD_ELEMENTS_PER_BLOB).unwrap();
+    domain
+        .ifft(&mut values)
+        .expect("a blob holds FIELD_ELEMENTS_PER_BLOB field elements");
+    Ok(DensePolynomial::new(values))
+}
+
+// Returns the commitment to the blob as in EIP-4844, computed in the Lagrange basis
+pub fn blob_to_commitment(srs: &Srs<Bls381>, blob: &[u8]) -> Result<G1Affine, Eip4844Error> {
+    let mut values = blob_to_field_elements(blob)?;
+    bit_reverse_permutation(&mut values);
+    Ok(srs.commit_lagrange(&values)?.to_affine())
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+// Reads a number of points of the header
+fn read_count(token: Option<&str>) -> Result<usize, Eip4844Error> {
+    let token = token.ok_or(Eip4844Error::UnexpectedEnd)?;
+    token
+        .parse()
+        .map_err(|_| Eip4844Error::InvalidCount(token.to_string()))
+}
+
+// Reads the compressed G1 point at the index of the file
+fn read_g1(token: Option<&str>, index: usize) -> Result<G1Projective, Eip4844Error> {
+    let mut bytes = [0u8; G1_COMPRESSED_BYTES];
+    decode_hex(token.ok_or(Eip4844Error::UnexpectedEnd)?, &mut bytes)
+        .and_then(|_| G1Affine::from_compressed_unchecked(&bytes).ok())
+        .map(G1Projective::from)
+        .ok_or(Eip4844Error::InvalidG1Point(index))
+}
+
+// Reads the compressed G2 point at the index of the file
+fn read_g2(token: Option<&str>, index: usize) -> Result<G2Projective, Eip4844Error> {
+    let mut bytes = [0u8; G2_COMPRESSED_BYTES];
+    decode_hex(token.ok_or(Eip4844Error::UnexpectedEnd)?, &mut bytes)
+        .and_then(|_| G2Affine::from_compressed_unchecked(&bytes).ok())
+        .map(G2Projective::from)
+        .ok_or(Eip4844Error::InvalidG2Point(index))
+}
+
+// Decodes the hex string, with an optional 0x prefix, into out, which it must fill exactly
+fn decode_hex(hex: &str, out: &mut [u8]) -> Option<()> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex).as_bytes();
+    if hex.len() != 2 * out.len() {
+        return None;
+    }
+    for (byte, pair) in out.iter_mut().zip(hex.chunks(2)) {
+        let high = (pair[0] as char).to_digit(16)?;
+        let low = (pair[1] as char).to_digit(16)?;
+        *byte = (high * 16 + low) as u8;
+    }
+    Some(())
+}
+
+// ERRORS
+// ================================================================================================
+
+// Errors which can occur when loading a trusted setup or committing to a blob
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Eip4844Error {
+    // The trusted setup file could not be read
+    Io(String),
+    // The trusted setup ends before all of its points
+    UnexpectedEnd,
+    // A number of points of the header is not a valid integer
+    InvalidCount(String),
+    // The G1 point at the index of the file is not a compressed point of the curve
+    InvalidG1Point(usize),
+    // The G2 point at the index of the file is not a compressed point of the twist
+    InvalidG2Point(usize),
+    // The trusted setup has data after its points
+    TrailingData,
+    // The points of the trusted setup do not form a valid reference string
+    Setup(KzgError),
+    // The blob does not have 131072 bytes
+    InvalidBlobLength(usize),
+    // The field element at the index of the blob is not smaller than the modulus
+    NonCanonicalFieldElement(usize),
+}
+
+impl From<KzgError> for Eip4844Error {
+    fn from(error: KzgError) -> Self {
+        Self::Setup(error)
+    }
+}
+
+impl fmt::Display for Eip4844Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read the trusted setup: {error}"),
+            Self::UnexpectedEnd => write!(f, "the trusted setup ends before all of its points"),
+            Self::InvalidCount(token) => {
+                write!(f, "expected a number of points, but found {token}")
+            }
+            Self::InvalidG1Point(index) => {
+                write!(f, "G1 point {index} of the trusted setup is invalid")
+            }
+            Self::InvalidG2Point(index) => {
+                write!(f, "G2 point {index} of the trusted setup is invalid")
+            }
+            Self::TrailingData => write!(f, "the trusted setup has data after its points"),
+            Self::Setup(error) => write!(f, "{error}"),
+            Self::InvalidBlobLength(len) => {
+                write!(f, "a blob must have {BYTES_PER_BLOB} bytes, but had {len}")
+            }
+            Self::NonCanonicalFieldElement(index) => {
+                write!(
+                    f,
+                    "field element {index} of the blob is not smaller than the modulus"
+                )
+            }
+        }
+    }
+}
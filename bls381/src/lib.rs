@@ -7,3 +7,11 @@ pub mod test;
 pub mod jubjub_scalar;
 pub mod bandersnatch_scalar;
 pub mod hash_bandersnatch;
+pub mod g1;
+pub mod g2;
+pub mod pairing;
+pub mod kzg;
+pub mod bandersnatch;
+pub mod jubjub;
+pub mod hash_to_curve;
+pub mod signature;
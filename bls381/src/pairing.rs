@@ -0,0 +1,315 @@
+use std::ops::{Mul, MulAssign, Neg};
+
+use traits::traits::{Field, Pairing};
+
+use crate::{
+    fp::Fp,
+    fp12::Fp12,
+    fp2::Fp2,
+    g1::{G1Affine, G1Projective},
+    g2::{G2Affine, G2Jacobian, G2Projective},
+    scalar::Scalar,
+};
+
+// |x| where x = -0xd201000000010000 is the BLS12-381 curve parameter
+pub const BLS_X: u64 = 0xd201_0000_0001_0000;
+pub const BLS_X_IS_NEGATIVE: bool = true;
+
+// Coefficients of a line function, evaluated at a G1 point by `ell`
+type LineCoeffs = (Fp2<Fp>, Fp2<Fp>, Fp2<Fp>);
+
+// TARGET GROUP
+// ================================================================================================
+
+// Element of the order r subgroup of the multiplicative group of Fp12, written multiplicatively
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Gt(pub Fp12<Fp>);
+
+impl Gt {
+    // returns the identity of the target group
+    pub fn identity() -> Self {
+        Gt(Fp12::one())
+    }
+    // returns e(G1, G2) for the standard generators
+    pub fn generator() -> Self {
+        pairing(&G1Affine::generator(), &G2Affine::generator()).into()
+    }
+    // checks whether the element is the identity
+    pub fn is_identity(&self) -> bool {
+        self.0 == Fp12::one()
+    }
+    // returns self^2 using the cyclotomic squaring of Fp12
+    pub fn double(&self) -> Self {
+        Gt(self.0.cyclotomic_square())
+    }
+}
+
+impl From<Fp12<Fp>> for Gt {
+    fn from(f: Fp12<Fp>) -> Self {
+        Gt(f)
+    }
+}
+
+impl Neg for Gt {
+    type Output = Gt;
+    // Inversion in the cyclotomic subgroup is conjugation
+    fn neg(self) -> Gt {
+        Gt(self.0.cyclotomic_inverse())
+    }
+}
+
+impl Mul for Gt {
+    type Output = Gt;
+    // The group law of Gt is multiplication in Fp12
+    fn mul(self, rhs: Gt) -> Gt {
+        Gt(self.0 * rhs.0)
+    }
+}
+
+impl MulAssign for Gt {
+    fn mul_assign(&mut self, rhs: Gt) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<Scalar> for Gt {
+    type Output = Gt;
+    // Exponentiation by a scalar, squaring with the cyclotomic squaring
+    fn mul(self, rhs: Scalar) -> Gt {
+        let mut res = Fp12::one();
+        for word in rhs.0.to_words().iter().rev() {
+            for i in (0..64).rev() {
+                res = res.cyclotomic_square();
+                if ((word >> i) & 1) == 1 {
+                    res *= self.0;
+                }
+            }
+        }
+        Gt(res)
+    }
+}
+
+// MILLER LOOP
+// ================================================================================================
+
+// Line coefficients of the Miller loop for a fixed G2 point. Preparing a point once lets it be
+// paired with many G1 points without recomputing the G2 doublings and additions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G2Prepared {
+    pub coeffs: Vec<LineCoeffs>,
+    pub infinity: bool,
+}
+
+impl From<G2Affine> for G2Prepared {
+    fn from(q: G2Affine) -> Self {
+        let infinity = q.is_identity();
+        let q = if infinity { G2Affine::generator() } else { q };
+        let mut cur = G2Jacobian::from(q);
+        let mut coeffs = Vec::with_capacity(68);
+        for bit in loop_bits() {
+            coeffs.push(doubling_step(&mut cur));
+            if bit {
+                coeffs.push(addition_step(&mut cur, &q));
+            }
+        }
+        coeffs.push(doubling_step(&mut cur));
+        G2Prepared { coeffs, infinity }
+    }
+}
+
+// Bits of |x| >> 1 below the leading one, most significant first. The last doubling of the loop
+// is done outside of it, since its square is not needed.
+fn loop_bits() -> impl Iterator<Item = bool> {
+    let x = BLS_X >> 1;
+    let top = 63 - x.leading_zeros();
+    (0..top).rev().map(move |b| ((x >> b) & 1) == 1)
+}
+
+// Doubles r in place and returns the tangent line at r. Adaptation of Algorithm 26,
+// https://eprint.iacr.org/2010/354.pdf
+fn doubling_step(r: &mut G2Jacobian) -> LineCoeffs {
+    let tmp0 = r.x.square();
+    let tmp1 = r.y.square();
+    let tmp2 = tmp1.square();
+    let tmp3 = ((tmp1 + r.x).square() - tmp0 - tmp2).double();
+    let tmp4 = tmp0.triple();
+    let tmp6 = r.x + tmp4;
+    let tmp5 = tmp4.square();
+    let zsquared = r.z.square();
+    r.x = tmp5 - tmp3.double();
+    r.z = (r.z + r.y).square() - tmp1 - zsquared;
+    r.y = (tmp3 - r.x) * tmp4 - tmp2.double().double().double();
+    let tmp3 = -(tmp4 * zsquared).double();
+    let tmp6 = tmp6.square() - tmp0 - tmp5 - tmp1.double().double();
+    let tmp0 = (r.z * zsquared).double();
+    (tmp0, tmp3, tmp6)
+}
+
+// Adds q to r in place and returns the line through r and q. Adaptation of Algorithm 27,
+// https://eprint.iacr.org/2010/354.pdf
+fn addition_step(r: &mut G2Jacobian, q: &G2Affine) -> LineCoeffs {
+    let zsquared = r.z.square();
+    let ysquared = q.y.square();
+    let t0 = zsquared * q.x;
+    let t1 = ((q.y + r.z).square() - ysquared - zsquared) * zsquared;
+    let t2 = t0 - r.x;
+    let t3 = t2.square();
+    let t4 = t3.double().double();
+    let t5 = t4 * t2;
+    let t6 = t1 - r.y.double();
+    let t9 = t6 * q.x;
+    let t7 = t4 * r.x;
+    r.x = t6.square() - t5 - t7.double();
+    r.z = (r.z + t2).square() - zsquared - t3;
+    let t10 = q.y + r.z;
+    let t8 = (t7 - r.x) * t6;
+    let t0 = (r.y * t5).double();
+    r.y = t8 - t0;
+    let t10 = t10.square() - ysquared - r.z.square();
+    let t9 = t9.double() - t10;
+    let t10 = r.z.double();
+    let t1 = (-t6).double();
+    (t10, t1, t9)
+}
+
+// Multiplies f by the line with the given coefficients evaluated at p
+fn ell(f: Fp12<Fp>, coeffs: &LineCoeffs, p: &G1Affine) -> Fp12<Fp> {
+    let c0 = coeffs.0.mul_scalar(p.y);
+    let c1 = coeffs.1.mul_scalar(p.x);
+    f.mul_by_014(&coeffs.2, &c1, &c0)
+}
+
+// Returns the product of the Miller loops f_{x, Q}(P) of all pairs. The output still has to be
+// raised to (p^12 - 1) / r by `final_exponentiation`. Pairs with a point at infinity contribute 1.
+pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Prepared)]) -> Fp12<Fp> {
+    let terms: Vec<(&G1Affine, &G2Prepared)> = terms
+        .iter()
+        .filter(|(p, q)| !p.is_identity() && !q.infinity)
+        .copied()
+        .collect();
+    let mut f = Fp12::one();
+    let mut idx = 0;
+    for bit in loop_bits() {
+        for (p, q) in terms.iter() {
+            f = ell(f, &q.coeffs[idx], p);
+        }
+        idx += 1;
+        if bit {
+            for (p, q) in terms.iter() {
+                f = ell(f, &q.coeffs[idx], p);
+            }
+            idx += 1;
+        }
+        f = f.square();
+    }
+    for (p, q) in terms.iter() {
+        f = ell(f, &q.coeffs[idx], p);
+    }
+    if BLS_X_IS_NEGATIVE {
+        f = f.conjugate();
+    }
+    f
+}
+
+// Returns the Miller loop f_{x, Q}(P) of a single pair
+pub fn miller_loop(p: &G1Affine, q: &G2Affine) -> Fp12<Fp> {
+    multi_miller_loop(&[(p, &G2Prepared::from(*q))])
+}
+
+// FINAL EXPONENTIATION
+// ================================================================================================
+
+// Returns f^x for f in the cyclotomic subgroup, where x is the (negative) curve parameter
+fn cyclotomic_exp(f: Fp12<Fp>) -> Fp12<Fp> {
+    let mut tmp = Fp12::one();
+    let mut found_one = false;
+    for bit in (0..64).rev().map(|b| ((BLS_X >> b) & 1) == 1) {
+        if found_one {
+            tmp = tmp.cyclotomic_square();
+        } else {
+            found_one = bit;
+        }
+        if bit {
+            tmp *= f;
+        }
+    }
+    tmp.cyclotomic_inverse()
+}
+
+// Returns f^(p^k), applying the Frobenius map to a copy of f
+fn frobenius(f: Fp12<Fp>, power: usize) -> Fp12<Fp> {
+    let mut res = f;
+    res.frobenius_map(power);
+    res
+}
+
+// Raises the output of the Miller loop to (p^12 - 1) / r. The easy part (p^6 - 1)(p^2 + 1) maps
+// f into the cyclotomic subgroup, after which the hard part (p^4 - p^2 + 1) / r only needs cyclotomic
+// squarings. The hard part follows Algorithm 5.5.4 of "Guide to Pairing-Based Cryptography" and
+// computes a fixed power of the reduced pairing, as blst and zkcrypto do, so outputs agree with them.
+pub fn final_exponentiation(f: &Fp12<Fp>) -> Fp12<Fp> {
+    let t0 = frobenius(*f, 6);
+    let t1 = f.invert().unwrap();
+    let mut t2 = t0 * t1;
+    let t1 = t2;
+    t2 = frobenius(t2, 2) * t1;
+    let t1 = t2.cyclotomic_square().conjugate();
+    let t3 = cyclotomic_exp(t2);
+    let t4 = t3.cyclotomic_square();
+    let t5 = t1 * t3;
+    let t1 = cyclotomic_exp(t5);
+    let t0 = cyclotomic_exp(t1);
+    let t6 = cyclotomic_exp(t0) * t4;
+    let t4 = cyclotomic_exp(t6) * t5.conjugate() * t2;
+    let t5 = t2.conjugate();
+    let t1 = frobenius(t1 * t2, 3);
+    let t6 = frobenius(t6 * t5, 1);
+    let t3 = frobenius(t3 * t0, 2) * t1 * t6;
+    t3 * t4
+}
+
+// PAIRING
+// ================================================================================================
+
+// Returns the optimal ate pairing e(p, q)
+pub fn pairing(p: &G1Affine, q: &G2Affine) -> Fp12<Fp> {
+    final_exponentiation(&miller_loop(p, q))
+}
+
+// Returns the product of the pairings e(p_i, q_i), sharing the Miller loop squarings and a single
+// final exponentiation between all pairs
+pub fn multi_pairing(terms: &[(G1Affine, G2Affine)]) -> Fp12<Fp> {
+    let prepared: Vec<G2Prepared> = terms.iter().map(|(_, q)| G2Prepared::from(*q)).collect();
+    let refs: Vec<(&G1Affine, &G2Prepared)> = terms
+        .iter()
+        .zip(prepared.iter())
+        .map(|((p, _), q)| (p, q))
+        .collect();
+    final_exponentiation(&multi_miller_loop(&refs))
+}
+
+// Checks whether the product of the pairings e(p_i, q_i) is 1, which is how pairing equations are
+// verified without computing the pairings separately
+pub fn pairing_check(terms: &[(G1Affine, G2Affine)]) -> bool {
+    multi_pairing(terms) == Fp12::one()
+}
+
+// PAIRING ENGINE
+// ================================================================================================
+
+// The optimal ate pairing of BLS12-381, for the protocols of the math crate that are generic over
+// the Pairing trait
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Bls381;
+
+impl Pairing for Bls381 {
+    type ScalarField = Scalar;
+    type G1 = G1Projective;
+    type G2 = G2Projective;
+
+    fn pairing_check(terms: &[(G1Projective, G2Projective)]) -> bool {
+        let terms: Vec<(G1Affine, G2Affine)> =
+            terms.iter().map(|(p, q)| (p.to_affine(), q.to_affine())).collect();
+        pairing_check(&terms)
+    }
+}
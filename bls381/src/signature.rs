@@ -0,0 +1,285 @@
+use std::fmt;
+
+use hash::hash_to_field::{from_be_bytes_reduced, HashToFieldError};
+use hkdf::Hkdf;
+use math::{
+    hash_to_curve::{hash_to_curve, HashToCurveParameters},
+    weierstrass::{AffinePoint, CurveParameters, ProjectivePoint},
+};
+use sha2::{Digest, Sha256};
+use traits::traits::Field;
+
+use crate::{
+    g1::{G1Affine, G1Parameters},
+    g2::{G2Affine, G2Parameters},
+    pairing::pairing_check,
+    scalar::Scalar,
+};
+
+// Salt of the first iteration of KeyGen, hashed before use
+const KEYGEN_SALT: &[u8] = b"BLS-SIG-KEYGEN-SALT-";
+
+// Length in bytes of the HKDF output reduced to a secret key: ceil((3 * ceil(log2(r))) / 16)
+const KEYGEN_OKM_BYTES: usize = 48;
+
+// Minimum length in bytes of the input keying material of KeyGen
+pub const MIN_IKM_BYTES: usize = 32;
+
+// BLS SIGNATURES
+// ================================================================================================
+// BLS signatures of draft-irtf-cfrg-bls-signature-05 with the proof of possession scheme, which
+// is the one of Ethereum consensus. Secret keys are scalars, public keys are [sk]G in one group
+// and signatures are [sk]H(msg) in the other: min-pk has public keys in G1 and signatures in G2,
+// as in Ethereum, and min-sig swaps them. Since public keys come with a proof of possession,
+// signatures of the same message aggregate into a signature of the aggregate public key.
+// Verification runs in variable time; all its inputs are public. Signing fails, and verification
+// rejects, when hash_to_curve does, which only happens with an empty domain separation tag.
+
+// Ciphersuite of the proof of possession scheme, which fixes the groups of public keys and
+// signatures and the domain separation tags of hash_to_curve
+pub trait Ciphersuite {
+    // Curve of the public keys
+    type PublicKeyCurve: HashToCurveParameters<ScalarField = Scalar>;
+    // Curve of the signatures, to which messages are hashed
+    type SignatureCurve: HashToCurveParameters<ScalarField = Scalar>;
+
+    // Domain separation tag of the signatures of messages
+    const SIGNATURE_DST: &'static [u8];
+    // Domain separation tag of the proofs of possession
+    const POP_DST: &'static [u8];
+
+    // Returns the compressed encoding of a public key, which is signed by its proof of possession
+    fn public_key_bytes(pk: &AffinePoint<Self::PublicKeyCurve>) -> Vec<u8>;
+
+    // Arranges a point of each group as the G1 and G2 arguments of the pairing
+    fn pairing_pair(
+        pk: AffinePoint<Self::PublicKeyCurve>,
+        sig: AffinePoint<Self::SignatureCurve>,
+    ) -> (G1Affine, G2Affine);
+}
+
+// BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_: public keys in G1 and signatures in G2
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MinPk;
+
+impl Ciphersuite for MinPk {
+    type PublicKeyCurve = G1Parameters;
+    type SignatureCurve = G2Parameters;
+
+    const SIGNATURE_DST: &'static [u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+    const POP_DST: &'static [u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+    fn public_key_bytes(pk: &G1Affine) -> Vec<u8> {
+        pk.to_compressed().to_vec()
+    }
+
+    fn pairing_pair(pk: G1Affine, sig: G2Affine) -> (G1Affine, G2Affine) {
+        (pk, sig)
+    }
+}
+
+// BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_: public keys in G2 and signatures in G1
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MinSig;
+
+impl Ciphersuite for MinSig {
+    type PublicKeyCurve = G2Parameters;
+    type SignatureCurve = G1Parameters;
+
+    const SIGNATURE_DST: &'static [u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+    const POP_DST: &'static [u8] = b"BLS_POP_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+
+    fn public_key_bytes(pk: &G2Affine) -> Vec<u8> {
+        pk.to_compressed().to_vec()
+    }
+
+    fn pairing_pair(pk: G2Affine, sig: G1Affine) -> (G1Affine, G2Affine) {
+        (sig, pk)
+    }
+}
+
+// Derives a secret key from at least 32 bytes of input keying material and optional key
+// information with HKDF-SHA256, as specified by KeyGen. With an empty key_info this is the
+// derivation of the master key of EIP-2333.
+pub fn key_gen(ikm: &[u8], key_info: &[u8]) -> Result<Scalar, BlsError> {
+    if ikm.len() < MIN_IKM_BYTES {
+        return Err(BlsError::IkmTooShort(ikm.len()));
+    }
+    let ikm = [ikm, &[0u8]].concat();
+    let info = [key_info, &(KEYGEN_OKM_BYTES as u16).to_be_bytes()].concat();
+    let mut salt = Sha256::digest(KEYGEN_SALT);
+    loop {
+        let mut okm = [0u8; KEYGEN_OKM_BYTES];
+        Hkdf::<Sha256>::new(Some(&salt), &ikm)
+            .expand(&info, &mut okm)
+            .expect("48 bytes is a valid length for HKDF-SHA256");
+        let sk = from_be_bytes_reduced::<Scalar>(&okm);
+        if !sk.is_zero() {
+            return Ok(sk);
+        }
+        // a zero key occurs with probability 2^-255; the derivation is then retried with a new
+        // salt
+        salt = Sha256::digest(salt);
+    }
+}
+
+// Returns the public key [sk]G of a secret key
+pub fn sk_to_pk<S: Ciphersuite>(sk: &Scalar) -> AffinePoint<S::PublicKeyCurve> {
+    (ProjectivePoint::<S::PublicKeyCurve>::generator() * *sk).to_affine()
+}
+
+// Checks whether a public key is valid: a point of the prime order subgroup other than the
+// identity
+pub fn key_validate<C: CurveParameters>(pk: &AffinePoint<C>) -> bool {
+    !pk.is_identity() && pk.is_on_curve() && pk.is_torsion_free()
+}
+
+// Signs a message: the signature is [sk]H(msg)
+pub fn sign<S: Ciphersuite>(
+    sk: &Scalar,
+    msg: &[u8],
+) -> Result<AffinePoint<S::SignatureCurve>, BlsError> {
+    let q = hash_to_curve::<S::SignatureCurve>(msg, S::SIGNATURE_DST)?;
+    Ok((q * *sk).to_affine())
+}
+
+// Verifies the signature of a message for a public key by checking e(pk, H(msg)) = e(G, sig)
+pub fn verify<S: Ciphersuite>(
+    pk: &AffinePoint<S::PublicKeyCurve>,
+    msg: &[u8],
+    sig: &AffinePoint<S::SignatureCurve>,
+) -> bool {
+    core_verify::<S>(pk, msg, sig, S::SIGNATURE_DST)
+}
+
+// Aggregates signatures, or public keys, into their sum. At least one point is required.
+pub fn aggregate<C: CurveParameters>(
+    points: &[AffinePoint<C>],
+) -> Result<AffinePoint<C>, BlsError> {
+    if points.is_empty() {
+        return Err(BlsError::EmptyAggregate);
+    }
+    let sum = points
+        .iter()
+        .fold(ProjectivePoint::identity(), |acc, point| acc + *point);
+    Ok(sum.to_affine())
+}
+
+// Verifies an aggregate signature of the messages msgs[i] signed with the secret keys of pks[i].
+// The messages need not be distinct, since every public key comes with a proof of possession.
+pub fn aggregate_verify<S: Ciphersuite>(
+    pks: &[AffinePoint<S::PublicKeyCurve>],
+    msgs: &[&[u8]],
+    sig: &AffinePoint<S::SignatureCurve>,
+) -> bool {
+    if pks.is_empty() || pks.len() != msgs.len() {
+        return false;
+    }
+    if !sig.is_on_curve() || !sig.is_torsion_free() || !pks.iter().all(key_validate) {
+        return false;
+    }
+    // e(pk_1, H(msg_1)) * ... * e(pk_n, H(msg_n)) * e(-G, sig) = 1
+    let terms: Result<Vec<(G1Affine, G2Affine)>, HashToFieldError> = pks
+        .iter()
+        .zip(msgs)
+        .map(|(pk, msg)| {
+            let q = hash_to_curve::<S::SignatureCurve>(msg, S::SIGNATURE_DST)?.to_affine();
+            Ok(S::pairing_pair(*pk, q))
+        })
+        .collect();
+    let Ok(mut terms) = terms else {
+        return false;
+    };
+    terms.push(S::pairing_pair(-AffinePoint::generator(), *sig));
+    pairing_check(&terms)
+}
+
+// Verifies an aggregate signature of a single message signed with the secret keys of all pks,
+// against the aggregate public key
+pub fn fast_aggregate_verify<S: Ciphersuite>(
+    pks: &[AffinePoint<S::PublicKeyCurve>],
+    msg: &[u8],
+    sig: &AffinePoint<S::SignatureCurve>,
+) -> bool {
+    if !pks.iter().all(key_validate) {
+        return false;
+    }
+    match aggregate(pks) {
+        Ok(pk) => verify::<S>(&pk, msg, sig),
+        Err(_) => false,
+    }
+}
+
+// Returns the proof of possession of a secret key: the signature of its public key under the
+// proof of possession domain separation tag
+pub fn pop_prove<S: Ciphersuite>(
+    sk: &Scalar,
+) -> Result<AffinePoint<S::SignatureCurve>, BlsError> {
+    let pk_bytes = S::public_key_bytes(&sk_to_pk::<S>(sk));
+    let q = hash_to_curve::<S::SignatureCurve>(&pk_bytes, S::POP_DST)?;
+    Ok((q * *sk).to_affine())
+}
+
+// Verifies the proof of possession of the secret key of a public key
+pub fn pop_verify<S: Ciphersuite>(
+    pk: &AffinePoint<S::PublicKeyCurve>,
+    proof: &AffinePoint<S::SignatureCurve>,
+) -> bool {
+    core_verify::<S>(pk, &S::public_key_bytes(pk), proof, S::POP_DST)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+// CoreVerify: checks the signature and the public key, then e(pk, H(msg)) * e(-G, sig) = 1
+fn core_verify<S: Ciphersuite>(
+    pk: &AffinePoint<S::PublicKeyCurve>,
+    msg: &[u8],
+    sig: &AffinePoint<S::SignatureCurve>,
+    dst: &[u8],
+) -> bool {
+    if !sig.is_on_curve() || !sig.is_torsion_free() || !key_validate(pk) {
+        return false;
+    }
+    let Ok(q) = hash_to_curve::<S::SignatureCurve>(msg, dst) else {
+        return false;
+    };
+    let q = q.to_affine();
+    pairing_check(&[
+        S::pairing_pair(*pk, q),
+        S::pairing_pair(-AffinePoint::generator(), *sig),
+    ])
+}
+
+// ERRORS
+// ================================================================================================
+
+// Errors which can occur when deriving keys, signing or aggregating
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BlsError {
+    // The input keying material is shorter than 32 bytes
+    IkmTooShort(usize),
+    // No signature or public key was given to aggregate
+    EmptyAggregate,
+    // Hashing the message to the curve failed
+    HashToField(HashToFieldError),
+}
+
+impl From<HashToFieldError> for BlsError {
+    fn from(error: HashToFieldError) -> Self {
+        Self::HashToField(error)
+    }
+}
+
+impl fmt::Display for BlsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IkmTooShort(len) => write!(
+                f,
+                "the input keying material has {len} bytes, at least {MIN_IKM_BYTES} are required"
+            ),
+            Self::EmptyAggregate => write!(f, "at least one point is required to aggregate"),
+            Self::HashToField(error) => write!(f, "hashing to the curve failed: {error}"),
+        }
+    }
+}
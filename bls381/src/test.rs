@@ -1,9 +1,29 @@
+// Helpers shared by the test modules below
+#[cfg(test)]
+mod helpers {
+    use crypto_bigint::U384;
+
+    use crate::fp::Fp;
+
+    // Returns the field element of the given big-endian hex value
+    pub fn fp(hex: &str) -> Fp {
+        Fp(U384::from_be_hex(hex)).to_montgomery()
+    }
+
+    pub fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+}
+
 #[cfg(test)]
 mod tests{
 
     use crypto_bigint::{Uint, Limb, U384, U256};
+    use hash::gmimc::GMIMCHASH;
+    use hash::packing::{PackingError, PackingLayout};
     use hash::rescue::{apply_sbox, apply_inv_sbox, apply_mds, apply_inv_mds, Rescue};
-    use traits::traits::{Field, PrimeField};
+    use hash::transcript::Transcript;
+    use traits::traits::{Field, GMIMCParameter, PrimeField};
 
     use crate::{fp::{Fp, MODULUS_MINUS_ONE, R, MODULUS}, scalar::Scalar, fp2::Fp2, fp6::Fp6, fp12::Fp12};
     use traits::traits::Hasher;
@@ -1046,6 +1066,61 @@ for i in 0..50{
         assert_eq!(b, c);
     }
 
+    #[test]
+    fn test_gmimc_legacy() {
+        // digest of 0, 1, ..., 67 computed by the fixed width GMIMCHASH::gmimic
+        let v: Vec<Scalar> = (0..<Scalar as GMIMCParameter<Scalar>>::NO_OF_COLUMNS as u64)
+            .map(Scalar::from)
+            .collect();
+        assert_eq!(
+            <GMIMCHASH<Scalar>>::gmimic_legacy(&v),
+            Scalar(U256::from_be_hex("5C185DF293109B73B77C85E7B9C4DDF3F2A120A721D9B55D8E99E42E5DCFBA5C"))
+        );
+        // the sponge absorbs all the elements
+        let mut w = v.clone();
+        w[67] += Scalar::ONE;
+        assert_ne!(<GMIMCHASH<Scalar>>::gmimc_hash(&v), <GMIMCHASH<Scalar>>::gmimc_hash(&w));
+    }
+
+    // Transcript of a fixed sequence of operations
+    fn sample_transcript() -> Transcript<Rescue<Scalar>> {
+        let mut transcript = <Transcript<Rescue<Scalar>>>::new(b"test protocol");
+        transcript.append_field(b"commitment", &[Scalar::from(1u64), Scalar::from(2u64), Scalar::from(3u64)]);
+        transcript.append_bytes(b"message", b"the quick brown fox jumps over the lazy dog");
+        transcript
+    }
+
+    // Packs the bits in a u64, the first bit being the least significant
+    fn bits_to_u64(bits: &[bool]) -> u64 {
+        bits.iter().rev().fold(0, |acc, bit| (acc << 1) | *bit as u64)
+    }
+
+    #[test]
+    fn test_transcript_golden() {
+        let mut transcript = sample_transcript();
+        let alpha = transcript.challenge_scalar(b"alpha");
+        let bits = transcript.challenge_bits(b"queries", 40);
+        assert_eq!(alpha, Scalar(U256::from_be_hex("42C14CFF1E1CDB2CC4144EC270EC3DEC15201974A85111ACCE13277C892E302C")));
+        assert_eq!(bits_to_u64(&bits), 0x73DD3CFB38);
+    }
+
+    #[test]
+    fn test_packing_montgomery() {
+        // Fp is stored in Montgomery form, the layout must read the canonical values
+        let layout = <PackingLayout<Fp>>::new(&[8, 8, 64]).unwrap();
+        let values = vec![Fp::from(1u128), Fp::from(255u128), Fp::from(u64::MAX as u128)];
+        let packed = layout.pack(&values).unwrap();
+        assert_eq!(packed, vec![Fp::from(1u128 | 255 << 8 | (u64::MAX as u128) << 16)]);
+        assert_eq!(layout.unpack(&packed), Ok(values.clone()));
+
+        let mut wrong = values.clone();
+        wrong[1] = Fp::from(256u128);
+        assert_eq!(layout.pack(&wrong), Err(PackingError::ValueOutOfRange(1, 8)));
+        assert_eq!(layout.truncate(&wrong)[1], Fp::ZERO);
+        wrong[1] = Fp::from(0x1ffu128);
+        assert_eq!(layout.truncate(&wrong)[1], Fp::from(255u128));
+    }
+
     // this code is used for generating 50 random elements of scalar
     // let mut a:Vec<Scalar>=Vec::new();
     // let mut b:Vec<String>=Vec::new();
@@ -1335,3 +1410,1317 @@ fn scalar_root(){
     }
 }
 
+
+#[cfg(test)]
+mod curvetest {
+    use crypto_bigint::U384;
+    use traits::traits::Field;
+
+    use crate::fp::Fp;
+    use crate::fp2::Fp2;
+    use crate::g1::{G1Affine, G1Jacobian, G1Projective};
+    use crate::g2::{G2Affine, G2Jacobian, G2Projective};
+    use crate::scalar::{Scalar, SCALAR_MODULUS_MINUS_ONE};
+    use super::helpers::fp;
+
+    #[test]
+    fn g1_generator_check() {
+        let g = G1Affine::generator();
+        assert!(g.is_on_curve());
+        assert!(g.is_torsion_free());
+        assert!(G1Affine::identity().is_on_curve());
+        assert!(G1Projective::identity().is_torsion_free());
+    }
+
+    #[test]
+    fn g1_double_check() {
+        // [2]G1 from the reference implementation
+        let expected = G1Affine::new(
+            fp("0572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e"),
+            fp("166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d28"),
+        );
+        let g = G1Projective::generator();
+        assert_eq!(g.double().to_affine(), expected);
+        assert_eq!((g + g).to_affine(), expected);
+        assert_eq!(G1Jacobian::generator().double().to_affine(), expected);
+        assert_eq!((G1Jacobian::generator() + G1Jacobian::generator()).to_affine(), expected);
+        assert_eq!((g * Scalar::from(2u128)).to_affine(), expected);
+    }
+
+    #[test]
+    fn g1_identity_check() {
+        let g = G1Projective::generator();
+        let id = G1Projective::identity();
+        assert_eq!(g + id, g);
+        assert_eq!(id + g, g);
+        assert_eq!(id.double(), id);
+        assert!((g - g).is_identity());
+        assert!((g + (-G1Affine::generator())).is_identity());
+        assert_eq!(g + G1Affine::identity(), g);
+        let j = G1Jacobian::generator();
+        assert!((j - j).is_identity());
+        assert_eq!(j + G1Jacobian::identity(), j);
+        assert_eq!(G1Affine::from(id), G1Affine::identity());
+    }
+
+    #[test]
+    fn g1_scalar_mul_check() {
+        let g = G1Projective::generator();
+        for _ in 0..5 {
+            let a = Scalar::random();
+            let b = Scalar::random();
+            assert_eq!(g * a + g * b, g * (a + b));
+            assert_eq!((g * a) * b, g * (a * b));
+            assert_eq!(G1Jacobian::generator() * a, G1Jacobian::from(g * a));
+            assert_eq!(G1Affine::generator() * a, g * a);
+            assert!((g * a).is_on_curve());
+        }
+        assert_eq!(g * Scalar(SCALAR_MODULUS_MINUS_ONE), -g);
+        assert!((g * Scalar::ZERO).is_identity());
+    }
+
+    #[test]
+    fn g1_coordinates_check() {
+        let g = G1Projective::generator() * Scalar::random();
+        let j = G1Jacobian::from(g);
+        assert!(j.is_on_curve());
+        assert_eq!(G1Projective::from(j), g);
+        assert_eq!(j.to_affine(), g.to_affine());
+        assert_eq!(j + G1Affine::generator(), G1Jacobian::from(g + G1Affine::generator()));
+        assert_eq!(G1Projective::from(g.to_affine()), g);
+    }
+
+    #[test]
+    fn g1_subgroup_check() {
+        // (0, 2) lies on the curve but has order 3
+        let p = G1Affine::new(Fp::ZERO, Fp(U384::from_u8(2)).to_montgomery());
+        assert!(p.is_on_curve());
+        assert!(!p.is_torsion_free());
+        assert!(G1Projective::from(p).mul_by_words(&[3]).is_identity());
+    }
+
+    #[test]
+    fn g2_generator_check() {
+        let g = G2Affine::generator();
+        assert!(g.is_on_curve());
+        assert!(g.is_torsion_free());
+        assert!(G2Jacobian::generator().is_on_curve());
+    }
+
+    #[test]
+    fn g2_double_check() {
+        // [2]G2 from the reference implementation
+        let expected = G2Affine::new(
+            Fp2::new(
+                fp("1638533957d540a9d2370f17cc7ed5863bc0b995b8825e0ee1ea1e1e4d00dbae81f14b0bf3611b78c952aacab827a053"),
+                fp("0a4edef9c1ed7f729f520e47730a124fd70662a904ba1074728114d1031e1572c6c886f6b57ec72a6178288c47c33577"),
+            ),
+            Fp2::new(
+                fp("0468fb440d82b0630aeb8dca2b5256789a66da69bf91009cbfe6bd221e47aa8ae88dece9764bf3bd999d95d71e4c9899"),
+                fp("0f6d4552fa65dd2638b361543f887136a43253d9c66c411697003f7a13c308f5422e1aa0a59c8967acdefd8b6e36ccf3"),
+            ),
+        );
+        let g = G2Projective::generator();
+        assert_eq!(g.double().to_affine(), expected);
+        assert_eq!((g + g).to_affine(), expected);
+        assert_eq!(G2Jacobian::generator().double().to_affine(), expected);
+        assert_eq!((g * Scalar::from(2u128)).to_affine(), expected);
+    }
+
+    #[test]
+    fn g2_scalar_mul_check() {
+        let g = G2Projective::generator();
+        for _ in 0..3 {
+            let a = Scalar::random();
+            let b = Scalar::random();
+            assert_eq!(g * a + g * b, g * (a + b));
+            assert_eq!((g * a) * b, g * (a * b));
+            assert_eq!(G2Jacobian::generator() * a, G2Jacobian::from(g * a));
+            assert!((g * a).is_on_curve());
+        }
+        assert_eq!(g * Scalar(SCALAR_MODULUS_MINUS_ONE), -g);
+        assert!((g - g).is_identity());
+    }
+
+    #[test]
+    fn g2_subgroup_check() {
+        // a random point of the twist is almost surely outside the order r subgroup
+        loop {
+            let x = Fp2::<Fp>::random();
+            let four = Fp(U384::from_u8(4)).to_montgomery();
+            let y = (x.cube() + Fp2::new(four, four)).sqrt();
+            if bool::from(y.is_some()) {
+                let p = G2Affine::new(x, y.unwrap());
+                assert!(p.is_on_curve());
+                assert!(!p.is_torsion_free());
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod pairingtest {
+    use traits::traits::Field;
+
+    use crate::fp::Fp;
+    use crate::fp12::Fp12;
+    use crate::fp2::Fp2;
+    use crate::fp6::Fp6;
+    use crate::g1::{G1Affine, G1Projective};
+    use crate::g2::{G2Affine, G2Projective};
+    use crate::pairing::{
+        final_exponentiation, miller_loop, multi_miller_loop, multi_pairing, pairing, G2Prepared,
+        Gt,
+    };
+    use crate::scalar::{Scalar, SCALAR_MODULUS};
+    use super::helpers::fp;
+
+    fn fp12(c: [&str; 12]) -> Fp12<Fp> {
+        Fp12::new(
+            Fp6::new(
+                Fp2::new(fp(c[0]), fp(c[1])),
+                Fp2::new(fp(c[2]), fp(c[3])),
+                Fp2::new(fp(c[4]), fp(c[5])),
+            ),
+            Fp6::new(
+                Fp2::new(fp(c[6]), fp(c[7])),
+                Fp2::new(fp(c[8]), fp(c[9])),
+                Fp2::new(fp(c[10]), fp(c[11])),
+            ),
+        )
+    }
+
+    #[test]
+    fn pairing_generator_check() {
+        // e(G1, G2), matching the target group generator of blst and zkcrypto
+        let expected = fp12([
+            "1250ebd871fc0a92a7b2d83168d0d727272d441befa15c503dd8e90ce98db3e7b6d194f60839c508a84305aaca1789b6",
+            "089a1c5b46e5110b86750ec6a532348868a84045483c92b7af5af689452eafabf1a8943e50439f1d59882a98eaa0170f",
+            "1368bb445c7c2d209703f239689ce34c0378a68e72a6b3b216da0e22a5031b54ddff57309396b38c881c4c849ec23e87",
+            "193502b86edb8857c273fa075a50512937e0794e1e65a7617c90d8bd66065b1fffe51d7a579973b1315021ec3c19934f",
+            "01b2f522473d171391125ba84dc4007cfbf2f8da752f7c74185203fcca589ac719c34dffbbaad8431dad1c1fb597aaa5",
+            "018107154f25a764bd3c79937a45b84546da634b8f6be14a8061e55cceba478b23f7dacaa35c8ca78beae9624045b4b6",
+            "19f26337d205fb469cd6bd15c3d5a04dc88784fbb3d0b2dbdea54d43b2b73f2cbb12d58386a8703e0f948226e47ee89d",
+            "06fba23eb7c5af0d9f80940ca771b6ffd5857baaf222eb95a7d2809d61bfe02e1bfd1b68ff02f0b8102ae1c2d5d5ab1a",
+            "11b8b424cd48bf38fcef68083b0b0ec5c81a93b330ee1a677d0d15ff7b984e8978ef48881e32fac91b93b47333e2ba57",
+            "03350f55a7aefcd3c31b4fcb6ce5771cc6a0e9786ab5973320c806ad360829107ba810c5a09ffdd9be2291a0c25a99a2",
+            "04c581234d086a9902249b64728ffd21a189e87935a954051c7cdba7b3872629a4fafc05066245cb9108f0242d0fe3ef",
+            "0f41e58663bf08cf068672cbd01a7ec73baca4d72ca93544deff686bfd6df543d48eaa24afe47e1efde449383b676631",
+        ]);
+        assert_eq!(pairing(&G1Affine::generator(), &G2Affine::generator()), expected);
+        assert_eq!(Gt::generator(), Gt(expected));
+    }
+
+    #[test]
+    fn pairing_bilinearity_check() {
+        let a = Scalar::random();
+        let b = Scalar::random();
+        let p = (G1Projective::generator() * a).to_affine();
+        let q = (G2Projective::generator() * b).to_affine();
+        let res = Gt(pairing(&p, &q));
+        assert_eq!(res, Gt::generator() * (a * b));
+        let p2 = (G1Projective::generator() * (a * b)).to_affine();
+        assert_eq!(res, Gt(pairing(&p2, &G2Affine::generator())));
+        let q2 = (G2Projective::generator() * (a * b)).to_affine();
+        assert_eq!(res, Gt(pairing(&G1Affine::generator(), &q2)));
+    }
+
+    #[test]
+    fn pairing_order_check() {
+        let g = Gt::generator();
+        assert!(!g.is_identity());
+        assert_eq!(g.0.power_by(SCALAR_MODULUS.to_words()), Fp12::one());
+        assert_eq!(g * Scalar::ONE, g);
+        assert!((g * -g).is_identity());
+        assert_eq!(g.double(), g * g);
+    }
+
+    #[test]
+    fn pairing_identity_check() {
+        assert_eq!(pairing(&G1Affine::identity(), &G2Affine::generator()), Fp12::one());
+        assert_eq!(pairing(&G1Affine::generator(), &G2Affine::identity()), Fp12::one());
+        assert_eq!(pairing(&G1Affine::identity(), &G2Affine::identity()), Fp12::one());
+    }
+
+    #[test]
+    fn multi_pairing_check() {
+        let a = Scalar::random();
+        let b = Scalar::random();
+        let p1 = (G1Projective::generator() * a).to_affine();
+        let q1 = (G2Projective::generator() * b).to_affine();
+        let p2 = (G1Projective::generator() * b).to_affine();
+        let q2 = (G2Projective::generator() * a).to_affine();
+        let expected = pairing(&p1, &q1) * pairing(&p2, &q2);
+        assert_eq!(multi_pairing(&[(p1, q1), (p2, q2)]), expected);
+        // e(aP, bQ) * e(-bP, aQ) = 1
+        assert_eq!(multi_pairing(&[(p1, q1), (-p2, q2)]), Fp12::one());
+        let prepared = G2Prepared::from(q1);
+        let f = multi_miller_loop(&[(&p1, &prepared), (&G1Affine::identity(), &prepared)]);
+        assert_eq!(final_exponentiation(&f), pairing(&p1, &q1));
+        assert_eq!(f, miller_loop(&p1, &q1));
+    }
+
+    #[test]
+    fn mul_by_014_check() {
+        let f = Fp12::<Fp>::random();
+        let c0 = Fp2::<Fp>::random();
+        let c1 = Fp2::<Fp>::random();
+        let c4 = Fp2::<Fp>::random();
+        let sparse = Fp12::new(
+            Fp6::new(c0, c1, Fp2::zero()),
+            Fp6::new(Fp2::zero(), c4, Fp2::zero()),
+        );
+        assert_eq!(f.mul_by_014(&c0, &c1, &c4), f * sparse);
+    }
+
+    #[test]
+    fn cyclotomic_square_check() {
+        // f^((p^6 - 1)(p^2 + 1)) lies in the cyclotomic subgroup
+        let f = Fp12::<Fp>::random();
+        let mut t = f;
+        t.frobenius_map(6);
+        let mut g = t * f.invert().unwrap();
+        let mut h = g;
+        h.frobenius_map(2);
+        g *= h;
+        assert_eq!(g.cyclotomic_square(), g.square());
+    }
+}
+
+#[cfg(test)]
+mod bandersnatchtest {
+    use core::{Deserializable, Serializable, SliceReader};
+    use crypto_bigint::U256;
+    use traits::traits::Field;
+
+    use crate::bandersnatch::{
+        endomorphism, glv_mul, BandersnatchAffine, BandersnatchExtended, ENDO_LAMBDA,
+    };
+    use crate::bandersnatch_scalar::BandScalar;
+    use crate::scalar::Scalar;
+
+    fn scalar(hex: &str) -> Scalar {
+        Scalar(U256::from_be_hex(hex))
+    }
+
+    #[test]
+    fn generator_check() {
+        let g = BandersnatchAffine::generator();
+        assert!(g.is_on_curve());
+        assert!(g.is_torsion_free());
+        assert!(!g.is_identity());
+        assert!(BandersnatchExtended::generator().is_on_curve());
+        assert!(BandersnatchAffine::identity().is_on_curve());
+    }
+
+    #[test]
+    fn group_law_check() {
+        let g = BandersnatchExtended::generator();
+        let a = g * BandScalar::random();
+        let b = g * BandScalar::random();
+        let c = g * BandScalar::random();
+        assert_eq!(a + b, b + a);
+        assert_eq!((a + b) + c, a + (b + c));
+        assert_eq!(a + a, a.double());
+        assert_eq!(a - a, BandersnatchExtended::identity());
+        assert!((a - a).is_identity());
+        assert_eq!(a + BandersnatchExtended::identity(), a);
+        assert!((a + b).is_on_curve());
+        assert!(a.double().is_on_curve());
+    }
+
+    #[test]
+    fn scalar_mul_check() {
+        // [5]G and [k]G computed with the affine addition law
+        let g = BandersnatchExtended::generator();
+        let five = BandersnatchAffine::new(
+            scalar("68cbece0b8fb55450410cbc058928a567eed293d168faef44bfde25f943aabe0"),
+            scalar("4e6cc4fe276029f8390f0a114280e0310dbee412018f03504695b21fdc684238"),
+        );
+        assert_eq!((g * BandScalar::from(5u8)).to_affine(), five);
+        let k = BandScalar(U256::from_be_hex(
+            "00123456789abcdef0123456789abcdef0123456789abcdef0123456789abcde",
+        ));
+        let kg = BandersnatchAffine::new(
+            scalar("18b1d45ea45b818505965eeb6e30e29ec73231b85fe09f193b2277dfc8964ad5"),
+            scalar("64e894bfcbafb639e6796c5abcbd1ce681beedb5e192ae8c3c7ff76897f24d59"),
+        );
+        assert_eq!((g * k).to_affine(), kg);
+        assert!((g * BandScalar::BANDSCALAR_MODULUS_1 + g).is_identity());
+        assert!((g * BandScalar::ZERO).is_identity());
+    }
+
+    #[test]
+    fn endomorphism_check() {
+        let g = BandersnatchExtended::generator();
+        assert_eq!(endomorphism(&g), g * ENDO_LAMBDA);
+        assert!(endomorphism(&g).is_on_curve());
+        let p = g * BandScalar::random();
+        assert_eq!(endomorphism(&p), p * ENDO_LAMBDA);
+        assert!(endomorphism(&BandersnatchExtended::identity()).is_identity());
+    }
+
+    #[test]
+    fn glv_mul_check() {
+        let g = BandersnatchExtended::generator();
+        for _ in 0..10 {
+            let k = BandScalar::random();
+            assert_eq!(glv_mul(&g, &k), g * k);
+        }
+        for k in [
+            BandScalar::ZERO,
+            BandScalar::ONE,
+            BandScalar::BANDSCALAR_MODULUS_1,
+            ENDO_LAMBDA,
+        ] {
+            assert_eq!(glv_mul(&g, &k), g * k);
+        }
+    }
+
+    #[test]
+    fn small_order_check() {
+        // (0, -1) has order 2
+        let t = BandersnatchAffine::new(Scalar::ZERO, -Scalar::ONE);
+        assert!(t.is_on_curve());
+        assert!(t.is_small_order());
+        assert!(!t.is_torsion_free());
+        let g = BandersnatchExtended::generator();
+        let p = g + t.to_extended();
+        assert!(!p.is_torsion_free());
+        assert!(p.mul_by_cofactor().is_torsion_free());
+    }
+
+    #[test]
+    fn compression_check() {
+        // arkworks serialization of the generator
+        let g = BandersnatchAffine::generator();
+        let expected = "664197ccb667315e6064e4ee81ad8c3586d5dcba508b7d150f3e12da9e666c2a";
+        let bytes = g.to_compressed();
+        assert_eq!(
+            bytes.iter().map(|b| format!("{b:02x}")).collect::<String>(),
+            expected
+        );
+        assert_eq!(BandersnatchAffine::from_compressed(&bytes).unwrap(), g);
+        assert_eq!(BandersnatchAffine::from_compressed(&(-g).to_compressed()).unwrap(), -g);
+
+        for _ in 0..10 {
+            let p = (BandersnatchExtended::generator() * BandScalar::random()).to_affine();
+            let bytes = p.to_bytes();
+            let mut reader = SliceReader::new(&bytes);
+            assert_eq!(BandersnatchAffine::read_from(&mut reader).unwrap(), p);
+        }
+
+        // the point of order 2 only decodes without the subgroup check
+        let t = BandersnatchAffine::new(Scalar::ZERO, -Scalar::ONE);
+        assert_eq!(BandersnatchAffine::from_compressed_unchecked(&t.to_compressed()).unwrap(), t);
+        assert!(BandersnatchAffine::from_compressed(&t.to_compressed()).is_err());
+        // so is a y coordinate larger than the modulus
+        assert!(BandersnatchAffine::from_compressed(&[0xff; 32]).is_err());
+    }
+}
+
+#[cfg(test)]
+mod jubjubtest {
+    use core::{Deserializable, Serializable, SliceReader};
+    use crypto_bigint::U256;
+    use traits::traits::Field;
+
+    use crate::jubjub::{AffinePoint, ExtendedPoint};
+    use crate::jubjub_scalar::JubScalar;
+    use crate::scalar::Scalar;
+    use super::helpers::to_hex;
+
+    fn scalar(hex: &str) -> Scalar {
+        Scalar(U256::from_be_hex(hex))
+    }
+
+    // generator of the full group, of order 8 * r
+    fn full_generator() -> AffinePoint {
+        AffinePoint::new(
+            scalar("62edcbb8bf3787c88b0f03ddd60a8187caf55d1b29bf81afe4b3d35df1a7adfe"),
+            Scalar::from(11u8),
+        )
+    }
+
+    #[test]
+    fn generator_check() {
+        let g = AffinePoint::generator();
+        assert!(g.is_on_curve());
+        assert!(g.is_torsion_free());
+        assert!(!g.is_small_order());
+        assert!(ExtendedPoint::generator().is_on_curve());
+    }
+
+    #[test]
+    fn group_law_check() {
+        let g = ExtendedPoint::generator();
+        let a = g * JubScalar::random();
+        let b = g * JubScalar::random();
+        let c = g * JubScalar::random();
+        assert_eq!(a + b, b + a);
+        assert_eq!((a + b) + c, a + (b + c));
+        assert_eq!(a + a, a.double());
+        assert!((a - a).is_identity());
+        assert!((a + b).is_on_curve());
+        // the formulas are complete on the whole curve
+        let f = full_generator().to_extended();
+        assert_eq!(f + f, f.double());
+        assert_eq!((f + a) - a, f);
+    }
+
+    #[test]
+    fn scalar_mul_check() {
+        let g = ExtendedPoint::generator();
+        let five = AffinePoint::new(
+            scalar("58bb4d87a7f00b0af420f3f879c5e8f9d2187a0e5caf4a9cda29c3b6a63f70ae"),
+            scalar("52e89296dc23c170390ee73c5f00ef2f532943fe9f7869cebf2cd7ba4585569e"),
+        );
+        assert_eq!((g * JubScalar::from(5u8)).to_affine(), five);
+        let k = JubScalar(U256::from_be_hex(
+            "00123456789abcdef0123456789abcdef0123456789abcdef0123456789abcde",
+        ));
+        let kg = AffinePoint::new(
+            scalar("1f2467ca24df8e74f39444fd4397634ff5208f77c4119aff74b797f7cbf1f9cd"),
+            scalar("1ba0a22ce52d0a7207ec6c382a76f230630b7dd34a4fac2246d05cc51f510321"),
+        );
+        assert_eq!((g * k).to_affine(), kg);
+        assert!((g * JubScalar::ZERO).is_identity());
+        assert!((g * -JubScalar::ONE + g).is_identity());
+    }
+
+    #[test]
+    fn cofactor_check() {
+        let f = full_generator();
+        assert!(f.is_on_curve());
+        assert!(!f.is_torsion_free());
+        assert!(!f.is_small_order());
+        let cleared = f.to_extended().mul_by_cofactor();
+        assert!(cleared.is_torsion_free());
+        assert!(!cleared.is_identity());
+        // (0, -1) has order 2
+        let t = AffinePoint::new(Scalar::ZERO, -Scalar::ONE);
+        assert!(t.is_on_curve());
+        assert!(t.is_small_order());
+        assert!(!t.is_torsion_free());
+    }
+
+    #[test]
+    fn encoding_check() {
+        let g = AffinePoint::generator();
+        let bytes = g.to_compressed();
+        assert_eq!(
+            to_hex(&bytes),
+            "aa92d2590e873fccd7fe20c25cba263ec3c066c8782e1393171aabddf13c529d"
+        );
+        assert_eq!(AffinePoint::from_compressed(&bytes).unwrap(), g);
+        assert_eq!(
+            to_hex(&(-g).to_compressed()),
+            "aa92d2590e873fccd7fe20c25cba263ec3c066c8782e1393171aabddf13c521d"
+        );
+        assert_eq!(AffinePoint::from_compressed(&(-g).to_compressed()).unwrap(), -g);
+        assert_eq!(
+            to_hex(&full_generator().to_compressed()),
+            "0b00000000000000000000000000000000000000000000000000000000000000"
+        );
+
+        for _ in 0..10 {
+            let p = (ExtendedPoint::generator() * JubScalar::random()).to_affine();
+            let bytes = p.to_bytes();
+            let mut reader = SliceReader::new(&bytes);
+            assert_eq!(AffinePoint::read_from(&mut reader).unwrap(), p);
+        }
+
+        // points outside of the subgroup only decode without the subgroup check
+        let bytes = full_generator().to_compressed();
+        assert_eq!(AffinePoint::from_compressed_unchecked(&bytes).unwrap(), full_generator());
+        assert!(AffinePoint::from_compressed(&bytes).is_err());
+        assert!(AffinePoint::read_from(&mut SliceReader::new(&bytes)).is_err());
+
+        // non canonical encodings of the identity and of y are rejected (ZIP 216)
+        let mut identity = AffinePoint::identity().to_compressed();
+        assert_eq!(AffinePoint::from_compressed(&identity).unwrap(), AffinePoint::identity());
+        identity[31] |= 0x80;
+        assert!(AffinePoint::from_compressed(&identity).is_err());
+        assert!(AffinePoint::from_compressed(&[0xff; 32]).is_err());
+    }
+}
+
+#[cfg(test)]
+mod polynomialtest {
+    use math::polynomial::DensePolynomial;
+    use traits::traits::Field;
+
+    use crate::{fp::Fp, fp2::Fp2};
+
+    // Fp is in Montgomery form, which interpolation must not mistake for the canonical one
+    #[test]
+    fn test_interpolate() {
+        let xs: Vec<Fp> = (0..8).map(|_| Fp::random()).collect();
+        let ys: Vec<Fp> = (0..8).map(|_| Fp::random()).collect();
+        let polynomial = DensePolynomial::interpolate(&xs, &ys).unwrap();
+        assert_eq!(polynomial.evaluate_many(&xs), ys);
+
+        let xs: Vec<Fp2<Fp>> = (0..8).map(|_| Fp2::random()).collect();
+        let ys: Vec<Fp2<Fp>> = (0..8).map(|_| Fp2::random()).collect();
+        let polynomial = DensePolynomial::interpolate(&xs, &ys).unwrap();
+        assert_eq!(polynomial.evaluate_many(&xs), ys);
+    }
+}
+
+#[cfg(test)]
+mod kzgtest {
+    use math::kzg::Srs;
+    use traits::traits::Field;
+
+    use crate::fp::Fp;
+    use crate::g1::{G1Affine, G1Projective};
+    use crate::g2::{G2Affine, G2Projective};
+    use crate::kzg::{
+        blob_to_commitment, blob_to_field_elements, blob_to_polynomial, parse_trusted_setup,
+        Eip4844Error, BYTES_PER_BLOB, FIELD_ELEMENTS_PER_BLOB,
+    };
+    use crate::scalar::Scalar;
+    use super::helpers::to_hex;
+
+    #[test]
+    fn test_compression() {
+        assert_eq!(
+            to_hex(&G1Affine::generator().to_compressed()),
+            "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"
+        );
+        assert_eq!(
+            to_hex(&G2Affine::generator().to_compressed()),
+            "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"
+        );
+        for _ in 0..4 {
+            let p = (G1Projective::generator() * Scalar::random()).to_affine();
+            assert_eq!(G1Affine::from_compressed(&p.to_compressed()).unwrap(), p);
+            assert_eq!(G1Affine::from_compressed(&(-p).to_compressed()).unwrap(), -p);
+            let q = (G2Projective::generator() * Scalar::random()).to_affine();
+            assert_eq!(G2Affine::from_compressed(&q.to_compressed()).unwrap(), q);
+            assert_eq!(G2Affine::from_compressed(&(-q).to_compressed()).unwrap(), -q);
+        }
+
+        let infinity = G1Affine::identity().to_compressed();
+        assert_eq!(infinity[0], 0xc0);
+        assert_eq!(G1Affine::from_compressed(&infinity).unwrap(), G1Affine::identity());
+        assert_eq!(
+            G2Affine::from_compressed(&G2Affine::identity().to_compressed()).unwrap(),
+            G2Affine::identity()
+        );
+
+        // missing compression flag, sign or abscissa on the point at infinity
+        let mut bytes = G1Affine::generator().to_compressed();
+        bytes[0] &= 0x7f;
+        assert!(G1Affine::from_compressed(&bytes).is_err());
+        let mut bytes = infinity;
+        bytes[0] |= 0x20;
+        assert!(G1Affine::from_compressed(&bytes).is_err());
+        let mut bytes = infinity;
+        bytes[47] = 1;
+        assert!(G1Affine::from_compressed(&bytes).is_err());
+        // abscissa not reduced modulo p
+        let mut bytes = [0xffu8; 48];
+        bytes[0] = 0x9f;
+        assert!(G1Affine::from_compressed_unchecked(&bytes).is_err());
+
+        // (0, 2) is on the curve and has order 3, so it is only accepted without the subgroup check
+        let mut bytes = [0u8; 48];
+        bytes[0] = 0x80;
+        let point = G1Affine::new(Fp::ZERO, Fp::ONE.double());
+        assert_eq!(G1Affine::from_compressed_unchecked(&bytes).unwrap(), point);
+        assert!(G1Affine::from_compressed(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_eip4844() {
+        let srs = parse_trusted_setup(include_str!("../trusted_setup.txt")).unwrap();
+        assert_eq!(srs.g1_powers().len(), FIELD_ELEMENTS_PER_BLOB);
+        assert_eq!(srs.g1_lagrange().len(), FIELD_ELEMENTS_PER_BLOB);
+        assert_eq!(srs.g2_powers().len(), 65);
+
+        // commitment and proof at z = 5 computed with c-kzg-4844
+        let mut blob = vec![0u8; BYTES_PER_BLOB];
+        for (i, chunk) in blob.chunks_mut(32).enumerate() {
+            chunk[24..].copy_from_slice(&((i as u64 * 7 + 3) % 1000).to_be_bytes());
+        }
+        let commitment = blob_to_commitment(&srs, &blob).unwrap();
+        assert_eq!(
+            to_hex(&commitment.to_compressed()),
+            "a4febe176e14dfcc7d6829208d57d75a09ae939c2494880fc03edd7d9b3e3ef236cf05374d5d791e0daf75a0776ca3c1"
+        );
+
+        let poly = blob_to_polynomial(&blob).unwrap();
+        assert_eq!(srs.commit(&poly).unwrap().to_affine(), commitment);
+        let point = Scalar::from(5u64);
+        let (value, proof) = srs.open(&poly, point).unwrap();
+        assert_eq!(
+            to_hex(&Scalar::to_bytes(&value)),
+            "3a33a0c9f80620063997ee00d6252485c76d90c5f593e854b751945190f9c8a1"
+        );
+        assert_eq!(
+            to_hex(&proof.to_affine().to_compressed()),
+            "877270b6a22ed497a573e9bbbc25fc417d2c99454703bdc12b7bdbc4a125ad31913876dbed5d9ed47b14eb4717aec4a3"
+        );
+        assert!(srs.verify(&commitment.into(), point, value, &proof));
+
+        assert_eq!(
+            blob_to_commitment(&srs, &blob[1..]),
+            Err(Eip4844Error::InvalidBlobLength(BYTES_PER_BLOB - 1))
+        );
+        blob[32..64].copy_from_slice(&[0xff; 32]);
+        assert_eq!(
+            blob_to_field_elements(&blob),
+            Err(Eip4844Error::NonCanonicalFieldElement(1))
+        );
+    }
+
+    #[test]
+    fn test_trusted_setup_format() {
+        let generator = to_hex(&G1Affine::generator().to_compressed());
+        let g2 = to_hex(&G2Affine::generator().to_compressed());
+        let g2_double = to_hex(&G2Projective::generator().double().to_affine().to_compressed());
+        // a setup of the earlier format, with only the Lagrange basis of size 1
+        let srs = parse_trusted_setup(&format!("1\n2\n{generator}\n{g2}\n{g2_double}\n")).unwrap();
+        assert_eq!(srs.g1_powers(), &[G1Projective::generator()]);
+        assert_eq!(srs.g1_lagrange(), &[G1Projective::generator()]);
+
+        assert_eq!(
+            parse_trusted_setup(&format!("1\n2\n{generator}\n{g2}\n")),
+            Err(Eip4844Error::UnexpectedEnd)
+        );
+        assert_eq!(
+            parse_trusted_setup(&format!("1\n2\n{generator}\n{g2}\n{g2}\n{generator}\n{generator}")),
+            Err(Eip4844Error::TrailingData)
+        );
+        assert_eq!(
+            parse_trusted_setup(&format!("1\n2\n{}\n{g2}\n{g2}\n", &generator[2..])),
+            Err(Eip4844Error::InvalidG1Point(0))
+        );
+        assert_eq!(
+            parse_trusted_setup(&format!("1\n2\n{generator}\n{g2}\n{generator}\n")),
+            Err(Eip4844Error::InvalidG2Point(1))
+        );
+        assert_eq!(parse_trusted_setup("x\n2\n"), Err(Eip4844Error::InvalidCount("x".to_string())));
+        assert_eq!(
+            parse_trusted_setup(&format!("1\n2\n{generator}\n{g2}\n{g2}\n")),
+            Ok(Srs::new(
+                vec![G1Projective::generator()],
+                vec![G2Projective::generator(); 2],
+                vec![G1Projective::generator()]
+            )
+            .unwrap())
+        );
+    }
+}
+
+#[cfg(test)]
+mod hashtofieldtest {
+    use crypto_bigint::U256;
+    use hash::hash_to_field::{
+        expand_message_xmd, field_bytes_len, from_be_bytes_reduced,
+        hash_to_extension_field, hash_to_field, ExpandMessage, HashToFieldError,
+    };
+
+    use crate::{
+        bandersnatch_scalar::BandScalar, fp::Fp, fp2::Fp2, jubjub_scalar::JubScalar,
+        scalar::Scalar,
+    };
+    use super::helpers::{fp, to_hex};
+
+    fn scalar(hex: &str) -> Scalar {
+        Scalar(U256::from_be_hex(hex))
+    }
+
+    fn random_bytes() -> [u8; 64] {
+        std::array::from_fn(|_| rand::random())
+    }
+
+    // Domain separation tags of 256 bytes, which are hashed before being used
+    fn long_dst(prefix: &str) -> Vec<u8> {
+        let mut dst = prefix.as_bytes().to_vec();
+        dst.resize(256, b'1');
+        dst
+    }
+
+    // Test vectors of RFC 9380, appendix K
+    #[test]
+    fn test_expand_message_xmd() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let vectors: [(&[u8], usize, &str); 4] = [
+            (b"", 0x20, "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"),
+            (b"abc", 0x20, "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"),
+            (
+                b"abcdef0123456789",
+                0x20,
+                "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+            ),
+            (
+                b"",
+                0x80,
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+            ),
+        ];
+        for (msg, len, expected) in vectors {
+            assert_eq!(to_hex(&expand_message_xmd(msg, dst, len).unwrap()), expected);
+        }
+
+        let dst = long_dst("QUUX-V01-CS02-with-expander-SHA256-128-long-DST-");
+        assert_eq!(
+            to_hex(&expand_message_xmd(b"", &dst, 0x20).unwrap()),
+            "e8dc0c8b686b7ef2074086fbdd2f30e3f8bfbd3bdf177f73f04b97ce618a3ed3"
+        );
+        assert_eq!(
+            to_hex(&expand_message_xmd(b"abc", &dst, 0x20).unwrap()),
+            "52dbf4f36cf560fca57dedec2ad924ee9c266341d8f3d6afe5171733b16bbb12"
+        );
+
+        // at most 255 blocks of SHA-256 can be output
+        assert_eq!(expand_message_xmd(b"abc", b"DST", 255 * 32).unwrap().len(), 255 * 32);
+        assert_eq!(
+            expand_message_xmd(b"abc", b"DST", 255 * 32 + 1),
+            Err(HashToFieldError::OutputTooLong(255 * 32 + 1))
+        );
+        assert_eq!(expand_message_xmd(b"abc", b"", 32), Err(HashToFieldError::EmptyDst));
+    }
+
+    #[test]
+    fn test_expand_message_xof() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHAKE128";
+        assert_eq!(
+            to_hex(&ExpandMessage::XofShake128.expand(b"", dst, 0x20).unwrap()),
+            "86518c9cd86581486e9485aa74ab35ba150d1c75c88e26b7043e44e2acd735a2"
+        );
+        assert_eq!(
+            to_hex(&ExpandMessage::XofShake128.expand(b"abc", dst, 0x20).unwrap()),
+            "8696af52a4d862417c0763556073f47bc9b9ba43c99b505305cb1ec04a9ab468"
+        );
+        let dst = long_dst("QUUX-V01-CS02-with-expander-SHAKE128-long-DST-");
+        assert_eq!(
+            to_hex(&ExpandMessage::XofShake128.expand(b"", &dst, 0x20).unwrap()),
+            "827c6216330a122352312bccc0c8d6e7a146c5257a776dbd9ad9d75cd880fc53"
+        );
+        assert_eq!(
+            to_hex(&ExpandMessage::XofShake128.expand(b"abc", &dst, 0x20).unwrap()),
+            "690c8d82c7213b4282c6cb41c00e31ea1d3e2005f93ad19bbf6da40f15790c5c"
+        );
+
+        let dst = b"QUUX-V01-CS02-with-expander-SHAKE256";
+        assert_eq!(
+            to_hex(&ExpandMessage::XofShake256.expand(b"", dst, 0x20).unwrap()),
+            "2ffc05c48ed32b95d72e807f6eab9f7530dd1c2f013914c8fed38c5ccc15ad76"
+        );
+        assert_eq!(
+            to_hex(&ExpandMessage::XofShake256.expand(b"abc", dst, 0x20).unwrap()),
+            "b39e493867e2767216792abce1f2676c197c0692aed061560ead251821808e07"
+        );
+        assert_eq!(
+            to_hex(&ExpandMessage::XofShake256.expand(b"", dst, 0x80).unwrap()),
+            "7a1361d2d7d82d79e035b8880c5a3c86c5afa719478c007d96e6c88737a3f631dd74a2c88df79a4cb5e5d9f7504957c70d669ec6bfedc31e01e2bacc4ff3fdf9b6a00b17cc18d9d72ace7d6b81c2e481b4f73f34f9a7505dccbe8f5485f3d20c5409b0310093d5d6492dea4e18aa6979c23c8ea5de01582e9689612afbb353df"
+        );
+
+        assert_eq!(
+            ExpandMessage::XofShake128.expand(b"abc", dst, 1 << 16),
+            Err(HashToFieldError::OutputTooLong(1 << 16))
+        );
+    }
+
+    // The field elements of the BLS12381G1_XMD:SHA-256_SSWU_RO_ and
+    // BLS12381G2_XMD:SHA-256_SSWU_RO_ suites of RFC 9380, appendix J.9
+    #[test]
+    fn test_hash_to_field() {
+        assert_eq!(field_bytes_len::<Fp>(), 64);
+        assert_eq!(field_bytes_len::<Scalar>(), 48);
+
+        let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+        let u = hash_to_field::<Fp>(b"", dst, 2, ExpandMessage::XmdSha256).unwrap();
+        assert_eq!(
+            u,
+            [
+                fp("0ba14bd907ad64a016293ee7c2d276b8eae71f25a4b941eece7b0d89f17f75cb3ae5438a614fb61d6835ad59f29c564f"),
+                fp("019b9bd7979f12657976de2884c7cce192b82c177c80e0ec604436a7f538d231552f0d96d9f7babe5fa3b19b3ff25ac9"),
+            ]
+        );
+
+        let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+        let u = hash_to_extension_field::<Fp, Fp2<Fp>>(b"abc", dst, 2, ExpandMessage::XmdSha256)
+            .unwrap();
+        assert_eq!(
+            u,
+            [
+                Fp2::new(
+                    fp("15f7c0aa8f6b296ab5ff9c2c7581ade64f4ee6f1bf18f55179ff44a2cf355fa53dd2a2158c5ecb17d7c52f63e7195771"),
+                    fp("01c8067bf4c0ba709aa8b9abc3d1cef589a4758e09ef53732d670fd8739a7274e111ba2fcaa71b3d33df2a3a0c8529dd"),
+                ),
+                Fp2::new(
+                    fp("187111d5e088b6b9acfdfad078c4dacf72dcd17ca17c82be35e79f8c372a693f60a033b461d81b025864a0ad051a06e4"),
+                    fp("08b852331c96ed983e497ebc6dee9b75e373d923b729194af8e72a051ea586f3538a6ebb1e80881a082fa2b24df9f566"),
+                ),
+            ]
+        );
+
+        let u = hash_to_field::<Scalar>(
+            b"abc",
+            b"QUUX-V01-CS02-with-expander-SHAKE128",
+            2,
+            ExpandMessage::XofShake128,
+        )
+        .unwrap();
+        assert_eq!(
+            u,
+            [
+                scalar("2112f1232d09c0559efec043e8b8f7ddb5cdb111ccf14852eabf7f13c4caa140"),
+                scalar("65e2a1633d9235c817e76ef74cb400ed0ed6312246628082a968c2dabf467684"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scalar_from_bytes_wide() {
+        // reductions of 48 bytes, as used by hash_to_field
+        let vectors: [(&[u8; 48], &str); 3] = [
+            (&[0u8; 48], "0000000000000000000000000000000000000000000000000000000000000000"),
+            (
+                b"aaaaaabbbbbbccccccddddddeeeeeeffffffgggggghhhhhh",
+                "2228450bf55d8fe62395161bd3677ff6fc28e45b89bc87e02a818eda11a8c5da",
+            ),
+            (
+                b"111111222222333333444444555555666666777777888888",
+                "4aa543cbd2f0c8f37f8a375ce2e383eb343e7e3405f61e438b0a15fb8899d1ae",
+            ),
+        ];
+        for (okm, expected) in vectors {
+            let mut bytes = [0u8; 64];
+            bytes[16..].copy_from_slice(okm);
+            bytes.reverse();
+            assert_eq!(Scalar::from_bytes_wide(&bytes), scalar(expected));
+            assert_eq!(from_be_bytes_reduced::<Scalar>(okm), scalar(expected));
+        }
+        assert_eq!(
+            Scalar::from_bytes_wide(&[0xff; 64]),
+            scalar("0748d9d99f59ff1105d314967254398f2b6cedcb87925c23c999e990f3f29c6c")
+        );
+
+        for _ in 0..20 {
+            let bytes = random_bytes();
+            let mut be_bytes = bytes;
+            be_bytes.reverse();
+            assert_eq!(Scalar::from_bytes_wide(&bytes), from_be_bytes_reduced(&be_bytes));
+            assert_eq!(JubScalar::from_bytes_wide(&bytes), from_be_bytes_reduced(&be_bytes));
+            assert_eq!(BandScalar::from_bytes_wide(&bytes), from_be_bytes_reduced(&be_bytes));
+        }
+    }
+}
+
+#[cfg(test)]
+mod hashtocurvetest {
+    use hash::hash_to_field::HashToFieldError;
+    use math::hash_to_curve::{
+        clear_cofactor, hash_to_curve, map_to_curve, map_to_curve_simple_swu,
+        HashToCurveParameters,
+    };
+    use traits::traits::Field;
+
+    use crate::{
+        fp::Fp,
+        fp2::Fp2,
+        g1::{G1Affine, G1Parameters},
+        g2::{G2Affine, G2Parameters},
+    };
+    use super::helpers::fp;
+
+    #[test]
+    fn test_legendre() {
+        assert_eq!(Fp::ZERO.legendre(), 0);
+        assert_eq!(Fp::ONE.legendre(), 1);
+        // p = 3 mod 4, so -1 is not a square
+        assert_eq!((-Fp::ONE).legendre(), -1);
+        assert_eq!(
+            <G1Parameters as HashToCurveParameters>::SSWU_Z.legendre(),
+            -1
+        );
+        for _ in 0..20 {
+            let a = Fp::random();
+            assert_eq!(a.square().legendre(), 1);
+            assert_eq!((-a.square()).legendre(), -1);
+            assert_eq!(a.legendre() == 1, a.is_square());
+        }
+    }
+
+    // Test vectors of the BLS12381G1_XMD:SHA-256_SSWU_RO_ suite, RFC 9380, appendix J.9.1
+    #[test]
+    fn test_hash_to_g1() {
+        let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+        let vectors: [(&[u8], &str, &str); 3] = [
+            (
+                b"",
+                "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+                "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+            ),
+            (
+                b"abc",
+                "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+                "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+            ),
+            (
+                b"abcdef0123456789",
+                "11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98",
+                "03a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709",
+            ),
+        ];
+        for (msg, x, y) in vectors {
+            let p = hash_to_curve::<G1Parameters>(msg, dst).unwrap();
+            assert_eq!(p.to_affine(), G1Affine::new(fp(x), fp(y)));
+            assert!(p.is_torsion_free());
+        }
+        assert_eq!(
+            hash_to_curve::<G1Parameters>(b"abc", b""),
+            Err(HashToFieldError::EmptyDst)
+        );
+    }
+
+    // Test vectors of the BLS12381G2_XMD:SHA-256_SSWU_RO_ suite, RFC 9380, appendix J.10.1
+    #[test]
+    fn test_hash_to_g2() {
+        let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+        let vectors: [(&[u8], [&str; 4]); 2] = [
+            (
+                b"",
+                [
+                    "0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a",
+                    "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d",
+                    "0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92",
+                    "12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6",
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    "02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6",
+                    "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8",
+                    "1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48",
+                    "00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16",
+                ],
+            ),
+        ];
+        for (msg, [x0, x1, y0, y1]) in vectors {
+            let p = hash_to_curve::<G2Parameters>(msg, dst).unwrap();
+            let expected = G2Affine::new(Fp2::new(fp(x0), fp(x1)), Fp2::new(fp(y0), fp(y1)));
+            assert_eq!(p.to_affine(), expected);
+            assert!(p.is_torsion_free());
+        }
+    }
+
+    #[test]
+    fn test_map_to_curve() {
+        for _ in 0..10 {
+            let u = Fp::random();
+            let (x, y) = map_to_curve_simple_swu::<G1Parameters>(&u);
+            assert_eq!(
+                y.square(),
+                x.cube() + G1Parameters::ISO_A * x + G1Parameters::ISO_B
+            );
+            let p = map_to_curve::<G1Parameters>(&u);
+            assert!(p.is_on_curve());
+            assert!(clear_cofactor(&p.to_projective()).is_torsion_free());
+
+            let u = Fp2::new(Fp::random(), Fp::random());
+            let (x, y) = map_to_curve_simple_swu::<G2Parameters>(&u);
+            assert_eq!(
+                y.square(),
+                x.cube() + G2Parameters::ISO_A * x + G2Parameters::ISO_B
+            );
+            let p = map_to_curve::<G2Parameters>(&u);
+            assert!(p.is_on_curve());
+            assert!(clear_cofactor(&p.to_projective()).is_torsion_free());
+        }
+
+        // u = 0 hits the exceptional case of the simplified SWU map
+        assert!(map_to_curve::<G1Parameters>(&Fp::ZERO).is_on_curve());
+        assert!(map_to_curve::<G2Parameters>(&Fp2::ZERO).is_on_curve());
+    }
+}
+
+#[cfg(test)]
+mod signaturetest {
+    use crypto_bigint::U256;
+    use hash::hash_to_field::HashToFieldError;
+
+    use crate::{
+        g1::{G1Affine, G1Parameters, G1_COMPRESSED_BYTES},
+        g2::{G2Affine, G2Parameters, G2_COMPRESSED_BYTES},
+        scalar::Scalar,
+        signature::{
+            aggregate, aggregate_verify, fast_aggregate_verify, key_gen, key_validate, pop_prove,
+            pop_verify, sign, sk_to_pk, verify, BlsError, Ciphersuite, MinPk, MinSig,
+        },
+    };
+
+    // Secret keys and messages of the BLS test vectors of the Ethereum consensus specs
+    const SECRET_KEYS: [&str; 3] = [
+        "263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3",
+        "47b8192d77bf871b62e87859d653922725724a5c031afeabc60bcef5ff665138",
+        "328388aff0d4a5b7dc9205abd374e7e98f3cd9f3418edb4eafda5fb16473d216",
+    ];
+    const MESSAGES: [[u8; 32]; 3] = [[0x00; 32], [0x56; 32], [0xab; 32]];
+
+    // Public keys of SECRET_KEYS
+    const PUBLIC_KEYS: [&str; 3] = [
+        "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a",
+        "b301803f8b5ac4a1133581fc676dfedc60d891dd5fa99028805e5ea5b08d3491af75d0707adab3b70c6a6a580217bf81",
+        "b53d21a4cfd562c469cc81514d4ce5a6b577d8403d32a394dc265dd190b47fa9f829fdd7963afdf972e5e77854051f6f",
+    ];
+
+    // SIGNATURES[i][j] is the signature of MESSAGES[j] with SECRET_KEYS[i]
+    const SIGNATURES: [[&str; 3]; 3] = [
+        [
+            "b6ed936746e01f8ecf281f020953fbf1f01debd5657c4a383940b020b26507f6076334f91e2366c96e9ab279fb5158090352ea1c5b0c9274504f4f0e7053af24802e51e4568d164fe986834f41e55c8e850ce1f98458c0cfc9ab380b55285a55",
+            "882730e5d03f6b42c3abc26d3372625034e1d871b65a8a6b900a56dae22da98abbe1b68f85e49fe7652a55ec3d0591c20767677e33e5cbb1207315c41a9ac03be39c2e7668edc043d6cb1d9fd93033caa8a1c5b0e84bedaeb6c64972503a43eb",
+            "91347bccf740d859038fcdcaf233eeceb2a436bcaaee9b2aa3bfb70efe29dfb2677562ccbea1c8e061fb9971b0753c240622fab78489ce96768259fc01360346da5b9f579e5da0d941e4c6ba18a0e64906082375394f337fa1af2b7127b0d121",
+        ],
+        [
+            "b23c46be3a001c63ca711f87a005c200cc550b9429d5f4eb38d74322144f1b63926da3388979e5321012fb1a0526bcd100b5ef5fe72628ce4cd5e904aeaa3279527843fae5ca9ca675f4f51ed8f83bbf7155da9ecc9663100a885d5dc6df96d9",
+            "af1390c3c47acdb37131a51216da683c509fce0e954328a59f93aebda7e4ff974ba208d9a4a2a2389f892a9d418d618418dd7f7a6bc7aa0da999a9d3a5b815bc085e14fd001f6a1948768a3f4afefc8b8240dda329f984cb345c6363272ba4fe",
+            "9674e2228034527f4c083206032b020310face156d4a4685e2fcaec2f6f3665aa635d90347b6ce124eb879266b1e801d185de36a0a289b85e9039662634f2eea1e02e670bc7ab849d006a70b2f93b84597558a05b879c8d445f387a5d5b653df",
+        ],
+        [
+            "948a7cb99f76d616c2c564ce9bf4a519f1bea6b0a624a02276443c245854219fabb8d4ce061d255af5330b078d5380681751aa7053da2c98bae898edc218c75f07e24d8802a17cd1f6833b71e58f5eb5b94208b4d0bb3848cecb075ea21be115",
+            "a4efa926610b8bd1c8330c918b7a5e9bf374e53435ef8b7ec186abf62e1b1f65aeaaeb365677ac1d1172a1f5b44b4e6d022c252c58486c0a759fbdc7de15a756acc4d343064035667a594b4c2a6f0b0b421975977f297dba63ee2f63ffe47bb6",
+            "ae82747ddeefe4fd64cf9cedb9b04ae3e8a43420cd255e3c7cd06a8d88b7c7f8638543719981c5d16fa3527c468c25f0026704a6951bde891360c7e8d12ddee0559004ccdbe6046b55bae1b257ee97f7cdb955773d7cf29adf3ccbb9975e4eb9",
+        ],
+    ];
+
+    // Aggregates of the signatures of each message with all the secret keys
+    const AGGREGATES: [&str; 3] = [
+        "9683b3e6701f9a4b706709577963110043af78a5b41991b998475a3d3fd62abf35ce03b33908418efc95a058494a8ae504354b9f626231f6b3f3c849dfdeaf5017c4780e2aee1850ceaf4b4d9ce70971a3d2cfcd97b7e5ecf6759f8da5f76d31",
+        "ad38fc73846583b08d110d16ab1d026c6ea77ac2071e8ae832f56ac0cbcdeb9f5678ba5ce42bd8dce334cc47b5abcba40a58f7f1f80ab304193eb98836cc14d8183ec14cc77de0f80c4ffd49e168927a968b5cdaa4cf46b9805be84ad7efa77b",
+        "9712c3edd73a209c742b8250759db12549b3eaf43b5ca61376d9f30e2747dbcf842d8b2ac0901d2a093713e20284a7670fcf6954e9ab93de991bb9b313e664785a075fc285806fa5224c82bde146561b446ccfc706a64b8579513cfc4ff1d930",
+    ];
+
+    fn decode_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        decode_hex(hex).try_into().unwrap()
+    }
+
+    fn secret_key(hex: &str) -> Scalar {
+        Scalar(U256::from_be_hex(hex))
+    }
+
+    fn public_key(hex: &str) -> G1Affine {
+        G1Affine::from_compressed(&from_hex::<G1_COMPRESSED_BYTES>(hex)).unwrap()
+    }
+
+    fn signature(hex: &str) -> G2Affine {
+        G2Affine::from_compressed(&from_hex::<G2_COMPRESSED_BYTES>(hex)).unwrap()
+    }
+
+    // Master secret keys of the test cases of EIP-2333
+    #[test]
+    fn test_key_gen() {
+        let vectors = [
+            (
+                "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+                "0d7359d57963ab8fbbde1852dcf553fedbc31f464d80ee7d40ae683122b45070",
+            ),
+            (
+                "3141592653589793238462643383279502884197169399375105820974944592",
+                "41c9e07822b092a93fd6797396338c3ada4170cc81829fdfce6b5d34bd5e7ec7",
+            ),
+            (
+                "0099ff991111002299dd7744ee3355bbdd8844115566cc55663355668888cc00",
+                "3cfa341ab3910a7d00d933d8f7c4fe87c91798a0397421d6b19fd5b815132e80",
+            ),
+        ];
+        for (ikm, sk) in vectors {
+            assert_eq!(key_gen(&decode_hex(ikm), b"").unwrap(), secret_key(sk));
+        }
+        assert_ne!(
+            key_gen(&[1; 32], b"").unwrap(),
+            key_gen(&[1; 32], b"info").unwrap()
+        );
+        assert_eq!(key_gen(&[1; 31], b""), Err(BlsError::IkmTooShort(31)));
+    }
+
+    // Sign and verify test cases of the Ethereum consensus specs
+    #[test]
+    fn test_sign_and_verify() {
+        for (i, sk) in SECRET_KEYS.iter().enumerate() {
+            let sk = secret_key(sk);
+            let pk = sk_to_pk::<MinPk>(&sk);
+            assert_eq!(pk, public_key(PUBLIC_KEYS[i]));
+            for (j, msg) in MESSAGES.iter().enumerate() {
+                let sig = sign::<MinPk>(&sk, msg).unwrap();
+                assert_eq!(sig.to_compressed(), from_hex(SIGNATURES[i][j]));
+                assert!(verify::<MinPk>(&pk, msg, &sig));
+                assert!(!verify::<MinPk>(&pk, &MESSAGES[(j + 1) % 3], &sig));
+                assert!(!verify::<MinPk>(
+                    &public_key(PUBLIC_KEYS[(i + 1) % 3]),
+                    msg,
+                    &sig
+                ));
+            }
+        }
+
+        // the point at infinity is neither a valid public key nor accepted with its signature
+        assert!(!key_validate(&G1Affine::identity()));
+        assert!(!verify::<MinPk>(
+            &G1Affine::identity(),
+            &MESSAGES[2],
+            &G2Affine::identity()
+        ));
+    }
+
+    #[test]
+    fn test_aggregate() {
+        for (j, expected) in AGGREGATES.iter().enumerate() {
+            let sigs: Vec<G2Affine> = SIGNATURES.iter().map(|sigs| signature(sigs[j])).collect();
+            assert_eq!(
+                aggregate(&sigs).unwrap().to_compressed(),
+                from_hex(expected)
+            );
+        }
+        assert_eq!(
+            aggregate(&[G2Affine::identity()]).unwrap(),
+            G2Affine::identity()
+        );
+        assert_eq!(
+            aggregate::<<MinPk as Ciphersuite>::SignatureCurve>(&[]),
+            Err(BlsError::EmptyAggregate)
+        );
+    }
+
+    #[test]
+    fn test_fast_aggregate_verify() {
+        let pks: Vec<G1Affine> = PUBLIC_KEYS.iter().map(|pk| public_key(pk)).collect();
+        for (j, msg) in MESSAGES.iter().enumerate() {
+            let sig = signature(AGGREGATES[j]);
+            assert!(fast_aggregate_verify::<MinPk>(&pks, msg, &sig));
+            assert!(!fast_aggregate_verify::<MinPk>(&pks[..2], msg, &sig));
+            assert!(!fast_aggregate_verify::<MinPk>(
+                &pks,
+                &MESSAGES[(j + 1) % 3],
+                &sig
+            ));
+        }
+
+        let sig = signature(AGGREGATES[2]);
+        let with_infinity = [pks.clone(), vec![G1Affine::identity()]].concat();
+        assert!(!fast_aggregate_verify::<MinPk>(
+            &with_infinity,
+            &MESSAGES[2],
+            &sig
+        ));
+        assert!(!fast_aggregate_verify::<MinPk>(
+            &[],
+            &MESSAGES[2],
+            &G2Affine::identity()
+        ));
+    }
+
+    #[test]
+    fn test_aggregate_verify() {
+        let pks: Vec<G1Affine> = PUBLIC_KEYS.iter().map(|pk| public_key(pk)).collect();
+        let msgs: Vec<&[u8]> = MESSAGES.iter().map(|msg| &msg[..]).collect();
+        let sigs: Vec<G2Affine> = (0..3).map(|i| signature(SIGNATURES[i][i])).collect();
+        let sig = aggregate(&sigs).unwrap();
+        assert!(aggregate_verify::<MinPk>(&pks, &msgs, &sig));
+
+        // the same message may be signed by several keys
+        let sig = signature(AGGREGATES[1]);
+        assert!(aggregate_verify::<MinPk>(
+            &pks,
+            &[&MESSAGES[1][..]; 3],
+            &sig
+        ));
+
+        let mut tampered = msgs.clone();
+        tampered.swap(0, 1);
+        assert!(!aggregate_verify::<MinPk>(&pks, &tampered, &sig));
+        assert!(!aggregate_verify::<MinPk>(&pks, &msgs[..2], &sig));
+        assert!(!aggregate_verify::<MinPk>(&[], &[], &G2Affine::identity()));
+        let with_infinity = [pks.clone(), vec![G1Affine::identity()]].concat();
+        let msgs = [msgs.clone(), vec![&MESSAGES[0][..]]].concat();
+        assert!(!aggregate_verify::<MinPk>(&with_infinity, &msgs, &sig));
+    }
+
+    #[test]
+    fn test_proof_of_possession() {
+        let sk = secret_key(SECRET_KEYS[0]);
+        let other = secret_key(SECRET_KEYS[1]);
+
+        let pk = sk_to_pk::<MinPk>(&sk);
+        let proof = pop_prove::<MinPk>(&sk).unwrap();
+        assert!(pop_verify::<MinPk>(&pk, &proof));
+        assert!(!pop_verify::<MinPk>(&sk_to_pk::<MinPk>(&other), &proof));
+        // proofs are not signatures of the encoded public key, thanks to their own tag
+        assert!(!verify::<MinPk>(&pk, &pk.to_compressed(), &proof));
+
+        let pk = sk_to_pk::<MinSig>(&sk);
+        let proof = pop_prove::<MinSig>(&sk).unwrap();
+        assert!(pop_verify::<MinSig>(&pk, &proof));
+        assert!(!pop_verify::<MinSig>(&sk_to_pk::<MinSig>(&other), &proof));
+    }
+
+    #[test]
+    fn test_min_sig() {
+        let sks: Vec<Scalar> = SECRET_KEYS.iter().map(|sk| secret_key(sk)).collect();
+        let pks: Vec<_> = sks.iter().map(sk_to_pk::<MinSig>).collect();
+        let msg = &MESSAGES[2];
+        let sigs: Vec<_> = sks.iter().map(|sk| sign::<MinSig>(sk, msg).unwrap()).collect();
+        for (pk, sig) in pks.iter().zip(&sigs) {
+            assert!(verify::<MinSig>(pk, msg, sig));
+            assert!(!verify::<MinSig>(pk, &MESSAGES[0], sig));
+        }
+        let sig = aggregate(&sigs).unwrap();
+        assert!(fast_aggregate_verify::<MinSig>(&pks, msg, &sig));
+        assert!(!fast_aggregate_verify::<MinSig>(&pks[1..], msg, &sig));
+
+        let msgs: Vec<&[u8]> = MESSAGES.iter().map(|msg| &msg[..]).collect();
+        let sigs: Vec<_> = (0..3).map(|i| sign::<MinSig>(&sks[i], msgs[i]).unwrap()).collect();
+        assert!(aggregate_verify::<MinSig>(
+            &pks,
+            &msgs,
+            &aggregate(&sigs).unwrap()
+        ));
+    }
+
+    // MinPk with empty domain separation tags, which hash_to_curve rejects
+    struct EmptyDst;
+
+    impl Ciphersuite for EmptyDst {
+        type PublicKeyCurve = G1Parameters;
+        type SignatureCurve = G2Parameters;
+
+        const SIGNATURE_DST: &'static [u8] = b"";
+        const POP_DST: &'static [u8] = b"";
+
+        fn public_key_bytes(pk: &G1Affine) -> Vec<u8> {
+            MinPk::public_key_bytes(pk)
+        }
+
+        fn pairing_pair(pk: G1Affine, sig: G2Affine) -> (G1Affine, G2Affine) {
+            MinPk::pairing_pair(pk, sig)
+        }
+    }
+
+    #[test]
+    fn test_empty_dst() {
+        let sk = secret_key(SECRET_KEYS[0]);
+        let pk = sk_to_pk::<EmptyDst>(&sk);
+        let error = Err(BlsError::HashToField(HashToFieldError::EmptyDst));
+        assert_eq!(sign::<EmptyDst>(&sk, &MESSAGES[0]), error);
+        assert_eq!(pop_prove::<EmptyDst>(&sk), error);
+
+        let sig = sign::<MinPk>(&sk, &MESSAGES[0]).unwrap();
+        assert!(!verify::<EmptyDst>(&pk, &MESSAGES[0], &sig));
+        assert!(!aggregate_verify::<EmptyDst>(&[pk], &[&MESSAGES[0][..]], &sig));
+        assert!(!pop_verify::<EmptyDst>(&pk, &pop_prove::<MinPk>(&sk).unwrap()));
+    }
+}
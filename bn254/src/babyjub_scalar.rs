@@ -71,6 +71,12 @@ impl BabyjubScalar {
         let inner = U256::from_be_byte_array(*bytes);
         <BabyjubScalar as Field>::from_uint_reduced(BabyjubScalar(inner))
     }
+    // returns the 512-bit little-endian integer reduced by the modulus.
+    pub fn from_bytes_wide(bytes: &[u8; 64]) -> BabyjubScalar {
+        let lo = U256::from_le_slice(&bytes[..32]);
+        let hi = U256::from_le_slice(&bytes[32..]);
+        BabyjubScalar(barrett_reduce(lo, hi))
+    }
     // returns the remainder obtained when self is divided by d.
     pub fn rem(self, d: Self) -> Self {
         let mut a = self;
@@ -0,0 +1,95 @@
+use core::DeserializationError;
+
+use crypto_bigint::{Encoding, U256};
+use math::edwards::{
+    decode_y_with_sign, EdwardsAffine, EdwardsEncoding, EdwardsExtended, EdwardsParameters,
+};
+use traits::traits::Field;
+
+use crate::{
+    babyjub_scalar::{BabyjubScalar, BABYJUB_SCALAR_MODULUS},
+    scalar::{Scalar, SCALAR_MODULUS},
+};
+
+// Baby Jubjub: the twisted Edwards curve 168700 * x^2 + y^2 = 1 + 168696 * x^2 * y^2 over the
+// scalar field of BN254, with the parameters of EIP-2494 and circomlib. The group of points has
+// order 8 * l where l is the modulus of BabyjubScalar. a is a square and d is not, so the addition
+// formulas are complete on the whole curve.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BabyJubjubParameters;
+
+impl EdwardsParameters for BabyJubjubParameters {
+    type BaseField = Scalar;
+
+    type ScalarField = BabyjubScalar;
+
+    // a = 168700
+    const COEFF_A: Scalar = Scalar(U256::from_u32(168700));
+
+    // d = 168696
+    const COEFF_D: Scalar = Scalar(U256::from_u32(168696));
+
+    // Base8 of circomlib, the generator of the prime order subgroup
+    // x = 5299619240641551281634865583518297030282874472190772894086521144482721001553
+    const GENERATOR_X: Scalar = Scalar(U256::from_be_hex(
+        "0bb77a6ad63e739b4eacb2e09d6277c12ab8d8010534e0b62893f3f6bb957051",
+    ));
+
+    // y = 16950150798460657717958625567821834550301663161624707787222815936182638968203
+    const GENERATOR_Y: Scalar = Scalar(U256::from_be_hex(
+        "25797203f7a0b24925572e1cd16bf9edfce0051fb9e133774b3c257a872d7d8b",
+    ));
+
+    const SUBGROUP_ORDER: U256 = BABYJUB_SCALAR_MODULUS;
+
+    const COFACTOR: u64 = 8;
+}
+
+pub type BabyJubjubAffine = EdwardsAffine<BabyJubjubParameters>;
+pub type BabyJubjubExtended = EdwardsExtended<BabyJubjubParameters>;
+
+// Generator of the full group, of order 8 * l, called Generator in circomlib. The generator of the
+// prime order subgroup is 8 times this point.
+pub fn full_generator() -> BabyJubjubAffine {
+    // x = 995203441582195749578291179787384436505546430278305826713579947235728471134
+    // y = 5472060717959818805561601436314318772137091100104008585924551046643952123905
+    BabyJubjubAffine::new(
+        Scalar(U256::from_be_hex(
+            "023343e3445b673d38bcba38f25645adb494b1255b1162bb40f41a59f4d4b45e",
+        )),
+        Scalar(U256::from_be_hex(
+            "0c19139cb84c680a6e14116da06056174a0cfa121e6e5c2450f87d64fc000001",
+        )),
+    )
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+// Size in bytes of a compressed point
+pub const COMPRESSED_BYTES: usize = 32;
+
+impl EdwardsEncoding for BabyJubjubParameters {
+    const ENCODED_BYTES: usize = COMPRESSED_BYTES;
+
+    type Encoding = [u8; COMPRESSED_BYTES];
+
+    // Compressed encoding of packPoint in circomlib: y in little-endian, with the most significant
+    // bit of the last byte set when x > (p - 1) / 2, p being the modulus of the base field
+    fn encode(point: &BabyJubjubAffine) -> [u8; COMPRESSED_BYTES] {
+        let mut bytes = point.y.0.to_le_bytes();
+        if point.x > -point.x {
+            bytes[COMPRESSED_BYTES - 1] |= 0x80;
+        }
+        bytes
+    }
+
+    // Decodes a point like unpackPoint in circomlib. Unlike circomlib, y must be reduced and the
+    // sign bit must not be set when x = 0.
+    fn decode(bytes: &[u8; COMPRESSED_BYTES]) -> Result<BabyJubjubAffine, DeserializationError> {
+        decode_y_with_sign(bytes, |bytes| {
+            let y = U256::from_le_bytes(bytes);
+            (y < SCALAR_MODULUS).then_some(Scalar(y))
+        })
+    }
+}
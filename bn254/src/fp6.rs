@@ -53,6 +53,15 @@ impl <B:Extensible<3>+ Extensible<2>>  Fp6<B>{
         Self { c0: res[0], c1: res[1], c2: res[2] }
 
     }
+    // multiplies the Fp6 element by the sparse element c0 + c1*v
+    pub fn mul_by_01(self, c0: &Fp2<B>, c1: &Fp2<B>) -> Self {
+        let a_a = self.c0 * *c0;
+        let b_b = self.c1 * *c1;
+        let t1 = (self.c2 * *c1).mul_by_nonresidue() + a_a;
+        let t2 = (*c0 + *c1) * (self.c0 + self.c1) - a_a - b_b;
+        let t3 = self.c2 * *c0 + b_b;
+        Self { c0: t1, c1: t2, c2: t3 }
+    }
     // elements as bytes
     pub fn elements_as_bytes(elements: &[Self]) -> &[u8] {
         unsafe {
@@ -0,0 +1,32 @@
+use crypto_bigint::U256;
+use math::weierstrass::{AffinePoint, CurveParameters, JacobianPoint, ProjectivePoint};
+
+use crate::{
+    fp::Fp,
+    scalar::{Scalar, SCALAR_MODULUS},
+};
+
+// G1 of BN254 (alt_bn128): E(Fp) : y^2 = x^3 + 3. The curve has prime order r, so every point
+// on it is in G1.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct G1Parameters;
+
+impl CurveParameters for G1Parameters {
+    type BaseField = Fp;
+    type ScalarField = Scalar;
+
+    const COEFF_B: Fp = Fp(U256::from_u8(3));
+
+    const COEFF_B3: Fp = Fp(U256::from_u8(9));
+
+    // The generator (1, 2) used by the Ethereum precompiles
+    const GENERATOR_X: Fp = Fp(U256::ONE);
+
+    const GENERATOR_Y: Fp = Fp(U256::from_u8(2));
+
+    const SUBGROUP_ORDER: U256 = SCALAR_MODULUS;
+}
+
+pub type G1Affine = AffinePoint<G1Parameters>;
+pub type G1Projective = ProjectivePoint<G1Parameters>;
+pub type G1Jacobian = JacobianPoint<G1Parameters>;
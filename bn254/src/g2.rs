@@ -0,0 +1,45 @@
+use crypto_bigint::U256;
+use math::weierstrass::{AffinePoint, CurveParameters, JacobianPoint, ProjectivePoint};
+
+use crate::{
+    fp::Fp,
+    fp2::Fp2,
+    scalar::{Scalar, SCALAR_MODULUS},
+};
+
+// G2 of BN254 (alt_bn128): the order r subgroup of the sextic twist E'(Fp2) : y^2 = x^3 + 3/(u + 9)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct G2Parameters;
+
+impl CurveParameters for G2Parameters {
+    type BaseField = Fp2<Fp>;
+    type ScalarField = Scalar;
+
+    // b = 3/(u + 9)
+    const COEFF_B: Fp2<Fp> = Fp2::new(
+        Fp(U256::from_be_hex("2b149d40ceb8aaae81be18991be06ac3b5b4c5e559dbefa33267e6dc24a138e5")),
+        Fp(U256::from_be_hex("009713b03af0fed4cd2cafadeed8fdf4a74fa084e52d1852e4a2bd0685c315d2")),
+    );
+
+    const COEFF_B3: Fp2<Fp> = Fp2::new(
+        Fp(U256::from_be_hex("20753adca9c6bfb81499be5e509e8f8ff21b7c8d3cb039cf1ef69c66bce9b021")),
+        Fp(U256::from_be_hex("01c53b10b0d2fc7e67860f09cc8af9ddf5eee18eaf8748f8ade8371391494176")),
+    );
+
+    // The generator used by the Ethereum pairing precompile (EIP-197)
+    const GENERATOR_X: Fp2<Fp> = Fp2::new(
+        Fp(U256::from_be_hex("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed")),
+        Fp(U256::from_be_hex("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2")),
+    );
+
+    const GENERATOR_Y: Fp2<Fp> = Fp2::new(
+        Fp(U256::from_be_hex("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa")),
+        Fp(U256::from_be_hex("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b")),
+    );
+
+    const SUBGROUP_ORDER: U256 = SCALAR_MODULUS;
+}
+
+pub type G2Affine = AffinePoint<G2Parameters>;
+pub type G2Projective = ProjectivePoint<G2Parameters>;
+pub type G2Jacobian = JacobianPoint<G2Parameters>;
@@ -1,18 +1,506 @@
 use crypto_bigint::Uint;
 use traits::traits::{Field, PrimeField};
 
-// Stores the matrix values
-pub fn rescue_mds<F: Field + PrimeField>() -> [F; 16] {
-    unimplemented!()
-        
+// Stores the matrix values 
+pub fn rescue_mds<F:Field + PrimeField>() -> [F; 16] {
+    [
+       F::from(Uint::from_be_hex(
+            "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfa6e",
+       )),
+       F::from(Uint::from_be_hex(
+            "0000000000000000000000000000000000000000000000000000000000000438",
+       )),
+       F::from(Uint::from_be_hex(
+            "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfbc1",
+       )),
+       F::from(Uint::from_be_hex(
+            "0000000000000000000000000000000000000000000000000000000000000028",
+       )),
+       F::from(Uint::from_be_hex(
+            "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87c8b5f",
+       )),
+       F::from(Uint::from_be_hex(
+            "000000000000000000000000000000000000000000000000000000000000a5e7",
+       )),
+       F::from(Uint::from_be_hex(
+            "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cc48f",
+       )),
+       F::from(Uint::from_be_hex(
+            "00000000000000000000000000000000000000000000000000000000000004ba",
+       )),
+       F::from(Uint::from_be_hex(
+            "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d86f879d",
+       )),
+       F::from(Uint::from_be_hex(
+            "0000000000000000000000000000000000000000000000000000000000137ec8",
+       )),
+       F::from(Uint::from_be_hex(
+            "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d8766fd2",
+       )),
+       F::from(Uint::from_be_hex(
+            "0000000000000000000000000000000000000000000000000000000000008458",
+       )),
+       F::from(Uint::from_be_hex(
+            "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d7041eaf",
+       )),
+       F::from(Uint::from_be_hex(
+            "000000000000000000000000000000000000000000000000000000000220dd96",
+       )),
+       F::from(Uint::from_be_hex(
+            "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d7c6ddff",
+       )),
+       F::from(Uint::from_be_hex(
+            "00000000000000000000000000000000000000000000000000000000000e204b",
+       )),
+    ]
 }
 
-// Stores the inverse matrix values
-pub fn rescue_inv_mds<F: Field + PrimeField>() -> [F; 16] {
-    unimplemented!()
+// Stores the inverse matrix values 
+pub fn rescue_inv_mds<F:Field + PrimeField>() -> [F; 16] {
+    [
+       F::from(Uint::from_be_hex(
+            "288bd37294c82c1009533b2233efa125e6693d9be8b65acb4e3861cdddfe4d9b",
+       )),
+       F::from(Uint::from_be_hex(
+            "07860071f43dfc3d987b550340c75482c010b5694d9b81d30e63c3ea54cad533",
+       )),
+       F::from(Uint::from_be_hex(
+            "0702951b17bcf4a22a0c7934aa2dffcd1b6bf9a7cc50af83f5dd9ec56696a24f",
+       )),
+       F::from(Uint::from_be_hex(
+            "29b433e621a02363a4c58212e41dbb456d1ce875ce4108f825c753b0179a3572",
+       )),
+       F::from(Uint::from_be_hex(
+            "244cf5ed8de9bfc1a6cbe1a09a23d54189a8dfcd48642261275ae3aeb4f81dd1",
+       )),
+       F::from(Uint::from_be_hex(
+            "1bfd7a8778e400b5dea2741b97f951ac3cb75fcb3540920904c3f99c2202ad56",
+       )),
+       F::from(Uint::from_be_hex(
+            "02a5e6a24b6a49327cf2abc5b54003955fe270ca2be1b1286b7223a283e9d6b7",
+       )),
+       F::from(Uint::from_be_hex(
+            "1dd845ce702b36a96e3f89eb1ba5863808c024c0275d2f87e0b01740561558b1",
+       )),
+       F::from(Uint::from_be_hex(
+            "13731d126e2ce0db0a2acc4deb021ef36654ac697fa9b254dbc409f368eba6c5",
+       )),
+       F::from(Uint::from_be_hex(
+            "27bf5df8e91dae0142045cb051f22e2a4d94ac29a17bb42ea19087aaa9db5dd3",
+       )),
+       F::from(Uint::from_be_hex(
+            "028cadbbb594e1b4b517b308e311b00cf1534b6127f80d711703e303f2ab0240",
+       )),
+       F::from(Uint::from_be_hex(
+            "2309741eb583cfc26f59af65e2fcb39089c6312e87c62125e3e8a38bab87f3b7",
+       )),
+       F::from(Uint::from_be_hex(
+            "08f6217d91ffb55d0f2b4f47b92aea702589be66c77d5e6601a736630ba55e51",
+       )),
+       F::from(Uint::from_be_hex(
+            "10ed5bb44caa1dafc751cea3ebfbbad3d520a0893feccec0adca1165c0aa23eb",
+       )),
+       F::from(Uint::from_be_hex(
+            "15d6e1bc72f999c0a7cff2832d73f7114e352373d6fe5409a901b20dd26d153f",
+       )),
+       F::from(Uint::from_be_hex(
+            "00a9ef848f8e335c3a033547aee6bc084ea1e82d8a09495ce3ad924039c065cd",
+       )),
+    ]
 }
-
 // Stores the constant values
-pub fn rescue_ark<F: Field + PrimeField>() -> [Vec<F>; 16] {
-    unimplemented!()
-}
\ No newline at end of file
+pub fn rescue_ark<F:Field + PrimeField>() -> [Vec<F>; 16] {
+    [
+        [
+           F::from(Uint::from_be_hex(
+                "2669602b879d506ab2b00c5869438f19b55cfc87d1c3352c0a8a730f3b76efe2",
+           )),
+           F::from(Uint::from_be_hex(
+                "0c1eb32ff42b5ac484fdd226f90b38010925b551aef48c04e65417af422d9849",
+           )),
+           F::from(Uint::from_be_hex(
+                "079c8c84be0526f1e66b37ca0121db7e7f11bdbd3dfd535d6b9366da212c9547",
+           )),
+           F::from(Uint::from_be_hex(
+                "01d5cae3e467677bd2a8242057cb75a399267b206ac43cb3f2864f7367a9d399",
+           )),
+           F::from(Uint::from_be_hex(
+                "1403bdda05b7ecebb5ccd89d428ec7067b4429cb552bad98d644a468a5b28734",
+           )),
+           F::from(Uint::from_be_hex(
+                "0080fc36f226eaab5635d79a8cac24ac69cf0ffa4847b502456b2657d408f8ef",
+           )),
+           F::from(Uint::from_be_hex(
+                "24ba6d9b8c3f0c07a8d0b5d7d74fc4be256d2257898bf68aaf054d031fc177a0",
+           )),
+           F::from(Uint::from_be_hex(
+                "2c6973f2a0694883fc828bd0661356fd7096915ff9731784d4d88a68d80a7b04",
+           )),
+        ].to_vec(),
+        [
+           F::from(Uint::from_be_hex(
+                "2632b55c4edf501f902616ae806302ea6dbe2b39c2dee5203aa34c75a7777e0d",
+           )),
+           F::from(Uint::from_be_hex(
+                "21108169917463091b1387c81e8cf74143c3409a2234d15f999a1b7bcb055136",
+           )),
+           F::from(Uint::from_be_hex(
+                "1d39f26aa500570dd27dd5ec08818d273c32269737457dc88a312b2d310d2156",
+           )),
+           F::from(Uint::from_be_hex(
+                "083879a1b0c3edf0401948e21e50bcd2888ed2d470347308b6ea84bd161ea0ab",
+           )),
+           F::from(Uint::from_be_hex(
+                "28b7d3f0fff3276c147631a56bb94677793f3806cde24327c3dca6091319a641",
+           )),
+           F::from(Uint::from_be_hex(
+                "2787d828bbd1220a12b205029ab2ff0769e8c45783e82d7e58c91833ab98a849",
+           )),
+           F::from(Uint::from_be_hex(
+                "20ea51b0d041316f6e86b112cfacd7c72eb5eab577c524433258c7c9343ab60a",
+           )),
+           F::from(Uint::from_be_hex(
+                "2dbacd15da826a8785fe3045b1cde30d46d1103ff65b107abc42ca7a3dca283f",
+           )),
+        ].to_vec(),
+        [
+           F::from(Uint::from_be_hex(
+                "0955aea2cc4d79c87ed5770c4b3b870af308fd733f8636f4a89fc62b9edd9a10",
+           )),
+           F::from(Uint::from_be_hex(
+                "207f9b9180aa998ad963b9784383c273f3ecc72564e1bd6384d0ece2e1b2814a",
+           )),
+           F::from(Uint::from_be_hex(
+                "0944b038ca854605c55788aec70ff22226e1e8aa352dedf58f97e8400f28904c",
+           )),
+           F::from(Uint::from_be_hex(
+                "21d240d96d9dac5068ba5da15d3413d23299ac9aa2b9e8d455819abb201106c8",
+           )),
+           F::from(Uint::from_be_hex(
+                "019bc8b8c3990bb2f69c640e4dd7810702397dc66a521b7e1d33e6c827edfb96",
+           )),
+           F::from(Uint::from_be_hex(
+                "2d2dd29db628944cfe5a0c42f3a482791d31ba6cae2ea45e782d30bda3aa9570",
+           )),
+           F::from(Uint::from_be_hex(
+                "04a3f77576fa2350ca4d67b25b3d62592123c5d7c769f7d69adb0cc5c79c8da2",
+           )),
+           F::from(Uint::from_be_hex(
+                "029b773066f3daf4491deb27ae544341d7b5f9661a9d4f73f0c9b6fe0a2641d9",
+           )),
+        ].to_vec(),
+        [
+           F::from(Uint::from_be_hex(
+                "13196e79718c47bf030b686ff6ebde592f7e8a653797f31a4f80a881b9912487",
+           )),
+           F::from(Uint::from_be_hex(
+                "2c612debc080ec6bbc82d96f117ee7016af1a2845b6e4e446ab0a753297b7a57",
+           )),
+           F::from(Uint::from_be_hex(
+                "0290f46801551d09493b093bbe2843bfcffa425be15b7425d89bfe9f29c0e0ab",
+           )),
+           F::from(Uint::from_be_hex(
+                "252899481c78226885d78ab30d9ab646b5989e41d0141886e291ad07f535a2b6",
+           )),
+           F::from(Uint::from_be_hex(
+                "1aaae7694f499bd3dde3bd50b5c40fbc566eef4425518ac9b16ca8bedda15546",
+           )),
+           F::from(Uint::from_be_hex(
+                "1f260f77dd960565475bc05c68adf97830146206338557974460ef4bf2991fae",
+           )),
+           F::from(Uint::from_be_hex(
+                "27e7b7d70efd08647a399aa77c6b541b5490eb328f6f5c518fcdddd8be8cb3d7",
+           )),
+           F::from(Uint::from_be_hex(
+                "21537c1433b45055eb1a1983ee42819bd2e63cc7033610ee1396008ca7a11e50",
+           )),
+        ].to_vec(),
+        [
+           F::from(Uint::from_be_hex(
+                "2afe61ebe9bbdd157f890c246e96562bb888c66099c776906a97e40719d7f034",
+           )),
+           F::from(Uint::from_be_hex(
+                "2452f7b58c0d0a0b53f849e8b580a0bf9a5b4a48e51de7d09c8f5361f005972f",
+           )),
+           F::from(Uint::from_be_hex(
+                "0adb48f5bb321e78ae3a3ac23b2e076249d0b5d5ad899fb473bf25a2fcbc9e38",
+           )),
+           F::from(Uint::from_be_hex(
+                "16b68b0207b737bc9decb75eff0fe56990136d9b23f1677257a4c42691ae8f17",
+           )),
+           F::from(Uint::from_be_hex(
+                "2b25de7c3d70f36ace2b7e80b2303b22b2152478ba4b01a5e8de3eec8dc65fec",
+           )),
+           F::from(Uint::from_be_hex(
+                "27cbda01591fb243fad666a1265a19f77749d043420c2120de1cbf92b85d1009",
+           )),
+           F::from(Uint::from_be_hex(
+                "14879f967bb40c9a0194a7a13a6d8968ab1086af514cc20b3efd961457359585",
+           )),
+           F::from(Uint::from_be_hex(
+                "0da6062b75da8d4dbe5a69d139066ed5f33f6bda3bf7fdaf55ea95a95550577a",
+           )),
+        ].to_vec(),
+        [
+           F::from(Uint::from_be_hex(
+                "0c26012a8f3b7b55ce20266590212a94c1bb17f37d21bde3fb8825b366e92ee3",
+           )),
+           F::from(Uint::from_be_hex(
+                "2a5fedb987de89820b68d15da9fae749d184de54767a4493e062736bd9c5286d",
+           )),
+           F::from(Uint::from_be_hex(
+                "1523ba85ad48d04e439428a44fd5a9b1c952d1f8ecac51d9632bd82e44f206df",
+           )),
+           F::from(Uint::from_be_hex(
+                "2c7f8f97b8d807fff7cf447c105015dbe6dd096ed1ff65b80e6f395a2bb93bdc",
+           )),
+           F::from(Uint::from_be_hex(
+                "1df1991a06d375432a790fe18649d6d5a4be0c75cf6e77dfb70c19f94f88cab6",
+           )),
+           F::from(Uint::from_be_hex(
+                "195b5277284b02d0a2e878609e6817f1c8926ee310fb37917119ba7f7f1e2a72",
+           )),
+           F::from(Uint::from_be_hex(
+                "1c8444f829a7a9fcd274ad9ddd434ff56e349fad9ffbf9904b5a9bb5310506f0",
+           )),
+           F::from(Uint::from_be_hex(
+                "2358d2002a3461da55d6391783139845a6212f69452c3f2b489b4608c11f96d9",
+           )),
+        ].to_vec(),
+        [
+           F::from(Uint::from_be_hex(
+                "2713b0cf23bce0065d29072f7634b94d22d0100192cfc8ca5f787d198bf243ce",
+           )),
+           F::from(Uint::from_be_hex(
+                "04fa02d5857c99c00b446052deb1ece6aa6e147bb4c556e410e01b64d725e6f4",
+           )),
+           F::from(Uint::from_be_hex(
+                "0b237f132b680f2179a04fa40f00c5a080150869160e0585b0b45bc8ff19415f",
+           )),
+           F::from(Uint::from_be_hex(
+                "0516329dccb540f06a890c6857de8a4607f6fb1b4cbc46d4b36401909d821d09",
+           )),
+           F::from(Uint::from_be_hex(
+                "029cdd4cf47215fa9744763029a7b4ee4d93ddae83e3094e24fa6453fb99bef6",
+           )),
+           F::from(Uint::from_be_hex(
+                "005644d7124f1e3303a96f1c0ef765be992fa94d6076f390bc62b2022264394a",
+           )),
+           F::from(Uint::from_be_hex(
+                "0b945bbb859db8b09f62ba8900831d4fb3e06b701973538295d1e92673d82145",
+           )),
+           F::from(Uint::from_be_hex(
+                "0d94dfbd659d7f5e1f27840f3aadf13cee6ad20f8115730144eb129d73f9f7be",
+           )),
+        ].to_vec(),
+        [
+           F::from(Uint::from_be_hex(
+                "0fb20b19f91a11ae1926f74443a8677345ae4d2cb5ca3a07d691393d7dcc8f72",
+           )),
+           F::from(Uint::from_be_hex(
+                "2e5dc8042262237d5255f5604ee6dedf63cd765c6b3c916e9a79ecf4aaf0e90a",
+           )),
+           F::from(Uint::from_be_hex(
+                "1f258f66cf3d69fb4056f3fdc36f9fbf82c280290465491b8954809a2585d34f",
+           )),
+           F::from(Uint::from_be_hex(
+                "2cbf6d4442071ad43c38edf40c22b522a257181700e315b940f188601827715c",
+           )),
+           F::from(Uint::from_be_hex(
+                "2dbe7c75430cc1059f5aa944167309b9544cb90ac6009d3dc73a40c9a27670a6",
+           )),
+           F::from(Uint::from_be_hex(
+                "14e75ca22e17e6583a7c5feb9945ec7a8c425a315db75e751c5c5cb6ed642a1d",
+           )),
+           F::from(Uint::from_be_hex(
+                "07a4975b9e778026a666eedcbdf51bfb405e5fb8ff6ca5805ee473ca41265d58",
+           )),
+           F::from(Uint::from_be_hex(
+                "29aa540f9c02ca6d17c6a49e2edc3a60ae312c9d266a901c933aaab6f7b6553a",
+           )),
+        ].to_vec(),
+        [
+           F::from(Uint::from_be_hex(
+                "28ffc3e23ca1b594bf39d6ff71c48a4486df8b73136fc5350d3591dea588d6e5",
+           )),
+           F::from(Uint::from_be_hex(
+                "00640741b521c0243dbe224346d3a1e782337a0c9a5d0d6f97efe59c5e3b864e",
+           )),
+           F::from(Uint::from_be_hex(
+                "1f19dd3071eb959655ac9ad4e8b8d4c3ca60a9b5256f6e22b73389d62bd32e3b",
+           )),
+           F::from(Uint::from_be_hex(
+                "0c4912bdd92f22dd4560387ae1dd46bb3889282f693628d4e4f6dfae22677f4a",
+           )),
+           F::from(Uint::from_be_hex(
+                "17788b256ceb46b2769616b2e4ccb803c5f36fd46f627d8267fc9473248fb209",
+           )),
+           F::from(Uint::from_be_hex(
+                "28b01cbbbe2d2a799885a7ba0c725b61134a946fed98b139a7cd16b9e875dd4c",
+           )),
+           F::from(Uint::from_be_hex(
+                "116c24254eff91c9d7f48e7b5ecf6dd5c8f4c0965e124d18fbf5c123d4f88447",
+           )),
+           F::from(Uint::from_be_hex(
+                "08c9a857970d3698152b2100ea979ec5c2e5ae9cbd0ba1b5aa568f4701ec7d6f",
+           )),
+        ].to_vec(),
+        [
+           F::from(Uint::from_be_hex(
+                "14e71a7cdf11d9b60281bd198490f73bb2734ac8b5ff796a2cbcc8a8bca08d15",
+           )),
+           F::from(Uint::from_be_hex(
+                "1f92f43a8efffa560f7cc0b6f8bb740ffc5dadcd827cffae066b2a6457137d5d",
+           )),
+           F::from(Uint::from_be_hex(
+                "07d5a38ce6f727bf15d770f3880c3c14b2c5c522d90fd6db5a6505f69e79b550",
+           )),
+           F::from(Uint::from_be_hex(
+                "007676dc1611e38302ef6418ec8ed7406dfc2b92374c6bbc8453db7486a5d7f3",
+           )),
+           F::from(Uint::from_be_hex(
+                "2c8cd9749f68f1403130585825260d0b1be1fc14a251f9fb1e57dfd725e0d46b",
+           )),
+           F::from(Uint::from_be_hex(
+                "2c56cadbcbc5df29a43e9a1e9a646c7c5d365cd8f56be7d3eccf06ea521c1143",
+           )),
+           F::from(Uint::from_be_hex(
+                "0f5fd64902088e3b9b96b606f1571fe6c6e2a156104e5a7736f98d304f1f6a87",
+           )),
+           F::from(Uint::from_be_hex(
+                "0bc660481020bf2c34e85f8beb62ee34767cf3d56316f24ad0b19903c61b9023",
+           )),
+        ].to_vec(),
+        [
+           F::from(Uint::from_be_hex(
+                "250af1a3524167ec6477e9285eaf358927c47f53d668bd39f6e9ea7882c14ada",
+           )),
+           F::from(Uint::from_be_hex(
+                "07e65645c178f0acbc9d10e484bcdaaa91b3ecfccaf35e6fa47f2c27afac2343",
+           )),
+           F::from(Uint::from_be_hex(
+                "2357fece8a507deb3d446a1e647b9bfa25fb8697a10c6cc3d27fde4c1578c01d",
+           )),
+           F::from(Uint::from_be_hex(
+                "0f7851bdcba83f74c682cd9f2200beab0a2323b2f0a446e8b615d6f2ba1aab3f",
+           )),
+           F::from(Uint::from_be_hex(
+                "10dff0d5db72280b7faa0b954621fb69b715c5726201383620eedcf21740c19c",
+           )),
+           F::from(Uint::from_be_hex(
+                "2b88e830ef5ecc36368dfd511d61be69781e7b680bc29ed525cc37a98eae2ccf",
+           )),
+           F::from(Uint::from_be_hex(
+                "17e41b8d019eb992b6610a383194d75390c90ca0c9f6a7cccafc7fff5449ed30",
+           )),
+           F::from(Uint::from_be_hex(
+                "253e9203e48a0500edd35c00e20ad21e3e6680993886b1db77bd4fee64855aef",
+           )),
+        ].to_vec(),
+        [
+           F::from(Uint::from_be_hex(
+                "18e7bf865ea47e5bf07e7dbc37facd186e500bf90404aa884b1c0b56398d2039",
+           )),
+           F::from(Uint::from_be_hex(
+                "2dce90c505f3b077e29401b97ff434c0d3fd4f87305206c74d3583219338f8f3",
+           )),
+           F::from(Uint::from_be_hex(
+                "1d1be426245667e55766d875dcf2ac17a45a82175c89a397ba7c7895c916e710",
+           )),
+           F::from(Uint::from_be_hex(
+                "0e210e06c179785876f2bc534d363957157932a00e873a54121dbe1e2962ec8f",
+           )),
+           F::from(Uint::from_be_hex(
+                "1a00b3d533df353bffe09e39033c68fdfcf4179a155cca623288118dca2e2d2b",
+           )),
+           F::from(Uint::from_be_hex(
+                "2b43e9053674e91fb6ab56683e3a5607799990c72a25f4d1e60218871438476f",
+           )),
+           F::from(Uint::from_be_hex(
+                "1409af3131077d40ba5502b7b098675e5673c7a9a26c886fef32bb059fbdc7ef",
+           )),
+           F::from(Uint::from_be_hex(
+                "08fd41243080a00eacccc8a94b5b252c466c394cc7725ab92d8fe8862cb77156",
+           )),
+        ].to_vec(),
+        [
+           F::from(Uint::from_be_hex(
+                "17810374244c09be273ce4ec9eff64e7fd0f9048b694b2d368ecce7a4ce1e4c7",
+           )),
+           F::from(Uint::from_be_hex(
+                "014283887fc7135e77a072d20dc872684ff12aec8f4bc95a525ae29482bc23e0",
+           )),
+           F::from(Uint::from_be_hex(
+                "16e342f5fcf74e796f800c312951b0115ca0c4eea794bae9f7c146b616f66150",
+           )),
+           F::from(Uint::from_be_hex(
+                "12ef12082b42bc0749b51fc1aa69a8be0ce19f918bb00cb61ae3424bea24390e",
+           )),
+           F::from(Uint::from_be_hex(
+                "1aa5967c10b356ee9ddbfdf088539cf849992b018406f9fe4edeab2d8aeb4b2d",
+           )),
+           F::from(Uint::from_be_hex(
+                "2898535e90a0759670c2ceb6a74a435df348a0daaeff650696e2d63d615ac951",
+           )),
+           F::from(Uint::from_be_hex(
+                "2599035413596036f468a0afc9b597004c028594e5ba202cff05f45a3b752f00",
+           )),
+           F::from(Uint::from_be_hex(
+                "21994a394f15749c5216db8014018e4ef886d4f03bfbcc1dbe171a2fdfa43294",
+           )),
+        ].to_vec(),
+        [
+           F::from(Uint::from_be_hex(
+                "0832f99433468e99ac91dd91dfac7dd65ce5fafd3334ed29eb162a8a90b946eb",
+           )),
+           F::from(Uint::from_be_hex(
+                "21b18ba9ae956b05d90e58653add67c93d6117325bb8dea0dd4d083e1c01d5ab",
+           )),
+           F::from(Uint::from_be_hex(
+                "3049647d9ef57c77624c50f23cd5084da9dce1c2ecfce028a73d5e37ec2cec2b",
+           )),
+           F::from(Uint::from_be_hex(
+                "1ba06bfdfc461e98ec7db7624a5028536fb33b6567d2c3b2ecf633fe871774d3",
+           )),
+           F::from(Uint::from_be_hex(
+                "147773b57b7dcff0cf83f3d175a4cd51086aaf33570b1ba0db28a223e8ae0a57",
+           )),
+           F::from(Uint::from_be_hex(
+                "2338f7c0cddad79f7ac25c2b12f47b1bea9944c56ab9cd2065a5b0445b07567f",
+           )),
+           F::from(Uint::from_be_hex(
+                "0e01ff0cc7ea71bbc45e420e9ff1eb8c099b19afbe135861c916a519cbb9bd6f",
+           )),
+           F::from(Uint::from_be_hex(
+                "0f447ec68eeee2a847b8577b765584d70760202d1044dbbff61ac3ae3da898e6",
+           )),
+        ].to_vec(),
+        [
+           F::from(Uint::from_be_hex(
+                "2e5e7c911e7341f658a2837efee375e0ed947ee5a9cf385fe4a1bf253dd43a33",
+           )),
+           F::from(Uint::from_be_hex(
+                "2dcc9237aa1b8beabfe57153301492cf7ef95c62d447fad3c7805bf4e3da36d6",
+           )),
+           F::from(Uint::from_be_hex(
+                "1bd23e12a31585d1687c8410e4948ecbce01f4f2ddec5d4ec4246adc9710da37",
+           )),
+           F::from(Uint::from_be_hex(
+                "2709db0980071b84a782c54e850cefd73ed3c730df1bd5fef3eef43467d331e1",
+           )),
+           F::from(Uint::from_be_hex(
+                "06a771b2074acffc9764340a029251ae3ddb9842bc742abab7613b7a50acd4d0",
+           )),
+           F::from(Uint::from_be_hex(
+                "1943df287ebcaa53f6943dd2efd233d086818b84b627874286f2ef67e2554261",
+           )),
+           F::from(Uint::from_be_hex(
+                "0df7b31c193031a3a367dbc645e64f843fbc346b941a9410ca94e8682a2c7e37",
+           )),
+           F::from(Uint::from_be_hex(
+                "121572c0e20b8ee0a72968ef183083241a03a40b0bcc37ace5b61d7e23fa005a",
+           )),
+        ].to_vec(),
+        [F::ZERO; 8].to_vec(),
+    ]
+}
@@ -7,3 +7,8 @@ pub mod scalar;
 pub mod babyjub_scalar;
 pub mod hash_bn_jubjub;
 pub mod hash_bn;
+pub mod g1;
+pub mod g2;
+pub mod pairing;
+pub mod precompile;
+pub mod babyjubjub;
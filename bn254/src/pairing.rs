@@ -0,0 +1,344 @@
+use std::ops::{Mul, MulAssign, Neg};
+
+use crypto_bigint::U256;
+use math::weierstrass::CurveParameters;
+use traits::traits::{Field, Pairing};
+
+use crate::{
+    fp::Fp,
+    fp12::Fp12,
+    fp2::Fp2,
+    g1::{G1Affine, G1Projective},
+    g2::{G2Affine, G2Parameters, G2Projective},
+    scalar::Scalar,
+};
+
+// u = 4965661367192848881 is the BN254 curve parameter, p = 36u^4 + 36u^3 + 24u^2 + 6u + 1
+pub const BN_U: u64 = 0x44e9_92b4_4a69_09f1;
+
+// Non-adjacent form of 6u + 2, least significant digit first
+pub const ATE_LOOP_COUNT: [i8; 66] = [
+    0, 0, 0, 1, 0, 1, 0, -1, 0, 0, -1, 0, 0, 0, 1, 0, 0, -1, 0, -1, 0, 0, 0, 1, 0, -1, 0, 0, 0, 0,
+    -1, 0, 0, 1, 0, -1, 0, 0, 1, 0, 0, 0, 0, 0, -1, 0, 0, -1, 0, 1, 0, -1, 0, 0, 0, -1, 0, -1, 0, 0,
+    0, 1, 0, -1, 0, 1,
+];
+
+// 1/2 in Fp
+const TWO_INV: Fp = Fp(U256::from_be_hex(
+    "183227397098d014dc2822db40c0ac2ecbc0b548b438e5469e10460b6c3e7ea4",
+));
+
+// (u + 9)^((p - 1) / 3), used to map the Frobenius endomorphism of E onto the twist
+const TWIST_MUL_BY_Q_X: Fp2<Fp> = Fp2::new(
+    Fp(U256::from_be_hex("2fb347984f7911f74c0bec3cf559b143b78cc310c2c3330c99e39557176f553d")),
+    Fp(U256::from_be_hex("16c9e55061ebae204ba4cc8bd75a079432ae2a1d0b7c9dce1665d51c640fcba2")),
+);
+
+// (u + 9)^((p - 1) / 2)
+const TWIST_MUL_BY_Q_Y: Fp2<Fp> = Fp2::new(
+    Fp(U256::from_be_hex("063cf305489af5dcdc5ec698b6e2f9b9dbaae0eda9c95998dc54014671a0135a")),
+    Fp(U256::from_be_hex("07c03cbcac41049a0704b5a7ec796f2b21807dc98fa25bd282d37f632623b0e3")),
+);
+
+// Coefficients of a line function, evaluated at a G1 point by `ell`
+type LineCoeffs = (Fp2<Fp>, Fp2<Fp>, Fp2<Fp>);
+
+// TARGET GROUP
+// ================================================================================================
+
+// Element of the order r subgroup of the multiplicative group of Fp12, written multiplicatively
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Gt(pub Fp12<Fp>);
+
+impl Gt {
+    // returns the identity of the target group
+    pub fn identity() -> Self {
+        Gt(Fp12::one())
+    }
+    // returns e(G1, G2) for the standard generators
+    pub fn generator() -> Self {
+        pairing(&G1Affine::generator(), &G2Affine::generator()).into()
+    }
+    // checks whether the element is the identity
+    pub fn is_identity(&self) -> bool {
+        self.0 == Fp12::one()
+    }
+    // returns self^2 using the cyclotomic squaring of Fp12
+    pub fn double(&self) -> Self {
+        Gt(self.0.cyclotomic_square())
+    }
+}
+
+impl From<Fp12<Fp>> for Gt {
+    fn from(f: Fp12<Fp>) -> Self {
+        Gt(f)
+    }
+}
+
+impl Neg for Gt {
+    type Output = Gt;
+    // Inversion in the cyclotomic subgroup is conjugation
+    fn neg(self) -> Gt {
+        Gt(self.0.cyclotomic_inverse())
+    }
+}
+
+impl Mul for Gt {
+    type Output = Gt;
+    // The group law of Gt is multiplication in Fp12
+    fn mul(self, rhs: Gt) -> Gt {
+        Gt(self.0 * rhs.0)
+    }
+}
+
+impl MulAssign for Gt {
+    fn mul_assign(&mut self, rhs: Gt) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<Scalar> for Gt {
+    type Output = Gt;
+    // Exponentiation by a scalar, squaring with the cyclotomic squaring
+    fn mul(self, rhs: Scalar) -> Gt {
+        let mut res = Fp12::one();
+        for word in rhs.0.to_words().iter().rev() {
+            for i in (0..64).rev() {
+                res = res.cyclotomic_square();
+                if ((word >> i) & 1) == 1 {
+                    res *= self.0;
+                }
+            }
+        }
+        Gt(res)
+    }
+}
+
+// MILLER LOOP
+// ================================================================================================
+
+// Line coefficients of the Miller loop for a fixed G2 point. Preparing a point once lets it be
+// paired with many G1 points without recomputing the G2 doublings and additions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G2Prepared {
+    pub coeffs: Vec<LineCoeffs>,
+    pub infinity: bool,
+}
+
+impl From<G2Affine> for G2Prepared {
+    fn from(q: G2Affine) -> Self {
+        let infinity = q.is_identity();
+        let q = if infinity { G2Affine::generator() } else { q };
+        let neg_q = -q;
+        let mut cur = G2Projective::from(q);
+        let mut coeffs = Vec::with_capacity(102);
+        for i in (1..ATE_LOOP_COUNT.len()).rev() {
+            coeffs.push(doubling_step(&mut cur));
+            match ATE_LOOP_COUNT[i - 1] {
+                1 => coeffs.push(addition_step(&mut cur, &q)),
+                -1 => coeffs.push(addition_step(&mut cur, &neg_q)),
+                _ => {}
+            }
+        }
+        // The two extra lines of the optimal ate pairing, through pi(Q) and -pi^2(Q)
+        let q1 = mul_by_char(&q);
+        let q2 = -mul_by_char(&q1);
+        coeffs.push(addition_step(&mut cur, &q1));
+        coeffs.push(addition_step(&mut cur, &q2));
+        G2Prepared { coeffs, infinity }
+    }
+}
+
+// Returns the image of q under the Frobenius endomorphism of E, expressed on the twist
+fn mul_by_char(q: &G2Affine) -> G2Affine {
+    let mut x = q.x;
+    let mut y = q.y;
+    x.frobenius_map(1);
+    y.frobenius_map(1);
+    G2Affine::new(x * TWIST_MUL_BY_Q_X, y * TWIST_MUL_BY_Q_Y)
+}
+
+// Doubles r in place and returns the tangent line at r, in homogeneous projective coordinates.
+// Equation (11) of https://eprint.iacr.org/2009/615.pdf, for a D-type twist
+fn doubling_step(r: &mut G2Projective) -> LineCoeffs {
+    let a = (r.x * r.y).mul_scalar(TWO_INV);
+    let b = r.y.square();
+    let c = r.z.square();
+    let e = G2Parameters::COEFF_B * c.triple();
+    let f = e.triple();
+    let g = (b + f).mul_scalar(TWO_INV);
+    let h = (r.y + r.z).square() - (b + c);
+    let i = e - b;
+    let j = r.x.square();
+    let e_square = e.square();
+    r.x = a * (b - f);
+    r.y = g.square() - e_square.triple();
+    r.z = b * h;
+    (-h, j.triple(), i)
+}
+
+// Adds q to r in place and returns the line through r and q, in homogeneous projective
+// coordinates. Equation (12) of https://eprint.iacr.org/2009/615.pdf, for a D-type twist
+fn addition_step(r: &mut G2Projective, q: &G2Affine) -> LineCoeffs {
+    let theta = r.y - q.y * r.z;
+    let lambda = r.x - q.x * r.z;
+    let c = theta.square();
+    let d = lambda.square();
+    let e = lambda * d;
+    let f = r.z * c;
+    let g = r.x * d;
+    let h = e + f - g.double();
+    r.x = lambda * h;
+    r.y = theta * (g - h) - e * r.y;
+    r.z *= e;
+    let j = theta * q.x - lambda * q.y;
+    (lambda, -theta, j)
+}
+
+// Multiplies f by the line with the given coefficients evaluated at p
+fn ell(f: Fp12<Fp>, coeffs: &LineCoeffs, p: &G1Affine) -> Fp12<Fp> {
+    let c0 = coeffs.0.mul_scalar(p.y);
+    let c1 = coeffs.1.mul_scalar(p.x);
+    f.mul_by_034(&c0, &c1, &coeffs.2)
+}
+
+// Returns the product of the Miller loops f_{6u + 2, Q}(P) of all pairs, including the two
+// Frobenius lines. The output still has to be raised to (p^12 - 1) / r by `final_exponentiation`.
+// Pairs with a point at infinity contribute 1.
+pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Prepared)]) -> Fp12<Fp> {
+    let terms: Vec<(&G1Affine, &G2Prepared)> = terms
+        .iter()
+        .filter(|(p, q)| !p.is_identity() && !q.infinity)
+        .copied()
+        .collect();
+    let mut f = Fp12::one();
+    let mut idx = 0;
+    for i in (1..ATE_LOOP_COUNT.len()).rev() {
+        if i != ATE_LOOP_COUNT.len() - 1 {
+            f = f.square();
+        }
+        for (p, q) in terms.iter() {
+            f = ell(f, &q.coeffs[idx], p);
+        }
+        idx += 1;
+        if ATE_LOOP_COUNT[i - 1] != 0 {
+            for (p, q) in terms.iter() {
+                f = ell(f, &q.coeffs[idx], p);
+            }
+            idx += 1;
+        }
+    }
+    for _ in 0..2 {
+        for (p, q) in terms.iter() {
+            f = ell(f, &q.coeffs[idx], p);
+        }
+        idx += 1;
+    }
+    f
+}
+
+// Returns the Miller loop f_{6u + 2, Q}(P) of a single pair
+pub fn miller_loop(p: &G1Affine, q: &G2Affine) -> Fp12<Fp> {
+    multi_miller_loop(&[(p, &G2Prepared::from(*q))])
+}
+
+// FINAL EXPONENTIATION
+// ================================================================================================
+
+// Returns f^(-u) for f in the cyclotomic subgroup
+fn exp_by_neg_u(f: Fp12<Fp>) -> Fp12<Fp> {
+    let mut tmp = Fp12::one();
+    let mut found_one = false;
+    for bit in (0..64).rev().map(|b| ((BN_U >> b) & 1) == 1) {
+        if found_one {
+            tmp = tmp.cyclotomic_square();
+        } else {
+            found_one = bit;
+        }
+        if bit {
+            tmp *= f;
+        }
+    }
+    tmp.cyclotomic_inverse()
+}
+
+// Returns f^(p^k), applying the Frobenius map to a copy of f
+fn frobenius(f: Fp12<Fp>, power: usize) -> Fp12<Fp> {
+    let mut res = f;
+    res.frobenius_map(power);
+    res
+}
+
+// Raises the output of the Miller loop to (p^12 - 1) / r. The easy part (p^6 - 1)(p^2 + 1) maps
+// f into the cyclotomic subgroup. The hard part (p^4 - p^2 + 1) / r follows "Faster hashing to G2"
+// by Fuentes-Castaneda, Knapp and Rodriguez-Henriquez and computes the power 2u(6u^2 + 3u + 1) of
+// the reduced pairing, matching the output of arkworks and gnark.
+pub fn final_exponentiation(f: &Fp12<Fp>) -> Fp12<Fp> {
+    let f1 = f.conjugate();
+    let f2 = f.invert().unwrap();
+    let r = f1 * f2;
+    let r = frobenius(r, 2) * r;
+    let y0 = exp_by_neg_u(r);
+    let y1 = y0.cyclotomic_square();
+    let y2 = y1.cyclotomic_square();
+    let y3 = y2 * y1;
+    let y4 = exp_by_neg_u(y3);
+    let y5 = y4.cyclotomic_square();
+    let y6 = exp_by_neg_u(y5).cyclotomic_inverse();
+    let y3 = y3.cyclotomic_inverse();
+    let y7 = y6 * y4;
+    let y8 = y7 * y3;
+    let y9 = y8 * y1;
+    let y10 = y8 * y4;
+    let y11 = y10 * r;
+    let y13 = frobenius(y9, 1) * y11;
+    let y14 = frobenius(y8, 2) * y13;
+    let y15 = frobenius(r.cyclotomic_inverse() * y9, 3);
+    y15 * y14
+}
+
+// PAIRING
+// ================================================================================================
+
+// Returns the optimal ate pairing e(p, q)
+pub fn pairing(p: &G1Affine, q: &G2Affine) -> Fp12<Fp> {
+    final_exponentiation(&miller_loop(p, q))
+}
+
+// Returns the product of the pairings e(p_i, q_i), sharing the Miller loop squarings and a single
+// final exponentiation between all pairs
+pub fn multi_pairing(terms: &[(G1Affine, G2Affine)]) -> Fp12<Fp> {
+    let prepared: Vec<G2Prepared> = terms.iter().map(|(_, q)| G2Prepared::from(*q)).collect();
+    let refs: Vec<(&G1Affine, &G2Prepared)> = terms
+        .iter()
+        .zip(prepared.iter())
+        .map(|((p, _), q)| (p, q))
+        .collect();
+    final_exponentiation(&multi_miller_loop(&refs))
+}
+
+// Checks whether the product of the pairings e(p_i, q_i) is 1, as done by the Ethereum pairing
+// precompile and by Groth16 verification
+pub fn pairing_check(terms: &[(G1Affine, G2Affine)]) -> bool {
+    multi_pairing(terms) == Fp12::one()
+}
+
+// PAIRING ENGINE
+// ================================================================================================
+
+// The optimal ate pairing of BN254, for the protocols of the math crate that are generic over
+// the Pairing trait
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Bn254;
+
+impl Pairing for Bn254 {
+    type ScalarField = Scalar;
+    type G1 = G1Projective;
+    type G2 = G2Projective;
+
+    fn pairing_check(terms: &[(G1Projective, G2Projective)]) -> bool {
+        let terms: Vec<(G1Affine, G2Affine)> =
+            terms.iter().map(|(p, q)| (p.to_affine(), q.to_affine())).collect();
+        pairing_check(&terms)
+    }
+}
@@ -0,0 +1,158 @@
+// Implementations of the alt_bn128 precompiles of Ethereum: ECADD (0x06) and ECMUL (0x07) from
+// EIP-196, and ECPAIRING (0x08) from EIP-197. Inputs and outputs use the same big-endian, 32 byte
+// per coordinate encoding as the EVM, with (0, 0) encoding the point at infinity.
+
+use std::fmt;
+
+use crypto_bigint::{Encoding, U256};
+
+use crate::{
+    fp::{Fp, MODULUS},
+    fp2::Fp2,
+    g1::{G1Affine, G1Projective},
+    g2::G2Affine,
+    pairing::pairing_check,
+};
+
+// Size in bytes of one encoded coordinate
+const WORD_BYTES: usize = 32;
+
+// Size in bytes of one (G1, G2) pair in the input of ECPAIRING
+pub const PAIR_BYTES: usize = 6 * WORD_BYTES;
+
+// Adds the two G1 points encoded in the first 128 bytes of the input
+pub fn ec_add(input: &[u8]) -> Result<[u8; 64], PrecompileError> {
+    let input = pad_input(input, 4 * WORD_BYTES);
+    let a = read_g1(&input[..2 * WORD_BYTES])?;
+    let b = read_g1(&input[2 * WORD_BYTES..])?;
+    Ok(write_g1(&(G1Projective::from(a) + b).to_affine()))
+}
+
+// Multiplies the G1 point encoded in the first 64 bytes of the input by the 256 bit integer in
+// the next 32 bytes. The multiplier is public, so a variable time multiplication is used.
+pub fn ec_mul(input: &[u8]) -> Result<[u8; 64], PrecompileError> {
+    let input = pad_input(input, 3 * WORD_BYTES);
+    let a = read_g1(&input[..2 * WORD_BYTES])?;
+    let s = U256::from_be_slice(&input[2 * WORD_BYTES..]);
+    Ok(write_g1(
+        &G1Projective::from(a).mul_by_words(&s.to_words()).to_affine(),
+    ))
+}
+
+// Returns 1 encoded as a 32 byte word if the product of the pairings of the (G1, G2) pairs in the
+// input is 1, and 0 otherwise. The empty input gives 1.
+pub fn ec_pairing(input: &[u8]) -> Result<[u8; 32], PrecompileError> {
+    if input.len() % PAIR_BYTES != 0 {
+        return Err(PrecompileError::InvalidInputLength(input.len()));
+    }
+    let mut terms = Vec::with_capacity(input.len() / PAIR_BYTES);
+    for pair in input.chunks(PAIR_BYTES) {
+        let p = read_g1(&pair[..2 * WORD_BYTES])?;
+        let q = read_g2(&pair[2 * WORD_BYTES..])?;
+        terms.push((p, q));
+    }
+    let mut out = [0u8; 32];
+    if pairing_check(&terms) {
+        out[WORD_BYTES - 1] = 1;
+    }
+    Ok(out)
+}
+
+// Right pads the input with zeros, or truncates it, to the given length
+fn pad_input(input: &[u8], len: usize) -> Vec<u8> {
+    let mut padded = vec![0u8; len];
+    let n = input.len().min(len);
+    padded[..n].copy_from_slice(&input[..n]);
+    padded
+}
+
+// Reads a base field element, rejecting values that are not reduced modulo p
+fn read_fp(bytes: &[u8]) -> Result<Fp, PrecompileError> {
+    let value = U256::from_be_slice(bytes);
+    if value >= MODULUS {
+        return Err(PrecompileError::CoordinateNotReduced(value));
+    }
+    Ok(Fp(value))
+}
+
+// Reads a G1 point encoded as x || y
+fn read_g1(bytes: &[u8]) -> Result<G1Affine, PrecompileError> {
+    let x = read_fp(&bytes[..WORD_BYTES])?;
+    let y = read_fp(&bytes[WORD_BYTES..2 * WORD_BYTES])?;
+    if x == Fp::ZERO && y == Fp::ZERO {
+        return Ok(G1Affine::identity());
+    }
+    let point = G1Affine::new(x, y);
+    if !point.is_on_curve() {
+        return Err(PrecompileError::G1NotOnCurve);
+    }
+    Ok(point)
+}
+
+// Reads a G2 point encoded as x.c1 || x.c0 || y.c1 || y.c0, with the imaginary parts first as
+// specified by EIP-197
+fn read_g2(bytes: &[u8]) -> Result<G2Affine, PrecompileError> {
+    let x_c1 = read_fp(&bytes[..WORD_BYTES])?;
+    let x_c0 = read_fp(&bytes[WORD_BYTES..2 * WORD_BYTES])?;
+    let y_c1 = read_fp(&bytes[2 * WORD_BYTES..3 * WORD_BYTES])?;
+    let y_c0 = read_fp(&bytes[3 * WORD_BYTES..4 * WORD_BYTES])?;
+    let x = Fp2::new(x_c0, x_c1);
+    let y = Fp2::new(y_c0, y_c1);
+    if x == Fp2::zero() && y == Fp2::zero() {
+        return Ok(G2Affine::identity());
+    }
+    let point = G2Affine::new(x, y);
+    if !point.is_on_curve() {
+        return Err(PrecompileError::G2NotOnCurve);
+    }
+    if !point.is_torsion_free() {
+        return Err(PrecompileError::G2NotInSubgroup);
+    }
+    Ok(point)
+}
+
+// Writes a G1 point as x || y, with the point at infinity written as (0, 0)
+fn write_g1(point: &G1Affine) -> [u8; 64] {
+    let mut out = [0u8; 64];
+    if !point.is_identity() {
+        out[..WORD_BYTES].copy_from_slice(&point.x.0.to_be_bytes());
+        out[WORD_BYTES..].copy_from_slice(&point.y.0.to_be_bytes());
+    }
+    out
+}
+
+// ERRORS
+// ================================================================================================
+
+// Errors which make a precompile call fail
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PrecompileError {
+    // The input of ECPAIRING is not a whole number of (G1, G2) pairs
+    InvalidInputLength(usize),
+    // A coordinate is greater or equal to the field modulus
+    CoordinateNotReduced(U256),
+    // The G1 point is not on the curve
+    G1NotOnCurve,
+    // The G2 point is not on the twist
+    G2NotOnCurve,
+    // The G2 point is on the twist but not in the prime order subgroup
+    G2NotInSubgroup,
+}
+
+impl fmt::Display for PrecompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidInputLength(len) => write!(
+                f,
+                "invalid input length {len}: expected a multiple of {PAIR_BYTES}"
+            ),
+            Self::CoordinateNotReduced(value) => write!(
+                f,
+                "coordinate {value} is greater or equal to the field modulus"
+            ),
+            Self::G1NotOnCurve => write!(f, "G1 point is not on the curve"),
+            Self::G2NotOnCurve => write!(f, "G2 point is not on the curve"),
+            Self::G2NotInSubgroup => write!(f, "G2 point is not in the prime order subgroup"),
+        }
+    }
+}
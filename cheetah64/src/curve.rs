@@ -0,0 +1,238 @@
+use core::DeserializationError;
+use crypto_bigint::U256;
+use hash::hash_to_field::{hash_to_extension_field, ExpandMessage, HashToFieldError};
+use math::{
+    hash_to_curve::HashToCurveParameters,
+    weierstrass::{self, CurveEncoding, CurveParameters},
+};
+use traits::traits::Field;
+
+use crate::{
+    fp::Fp,
+    fp6::Fp6,
+    scalar::{Scalar, SCALAR_MODULUS},
+};
+
+// Cheetah: the curve y^2 = x^3 + x + b with b = u + 395 over Fp6 = Fp[u]/(u^6 - 7), where p is
+// the 64-bit prime 2^64 - 2^32 + 1. Its group of points has order h * q, with q the 255-bit
+// modulus of Scalar and h a 130-bit cofactor. Coordinates are Fp6 elements, so that arithmetic
+// on the curve is cheap to prove in a STARK over Fp.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CheetahParameters;
+
+impl CurveParameters for CheetahParameters {
+    type BaseField = Fp6<Fp>;
+
+    type ScalarField = Scalar;
+
+    const COEFF_A: Fp6<Fp> = Fp6 {
+        c0: Fp(1),
+        c1: Fp(0),
+        c2: Fp(0),
+        c3: Fp(0),
+        c4: Fp(0),
+        c5: Fp(0),
+    };
+
+    // b = u + 395
+    const COEFF_B: Fp6<Fp> = Fp6 {
+        c0: Fp(395),
+        c1: Fp(1),
+        c2: Fp(0),
+        c3: Fp(0),
+        c4: Fp(0),
+        c5: Fp(0),
+    };
+
+    const COEFF_B3: Fp6<Fp> = Fp6 {
+        c0: Fp(1185),
+        c1: Fp(3),
+        c2: Fp(0),
+        c3: Fp(0),
+        c4: Fp(0),
+        c5: Fp(0),
+    };
+
+    // The generator is [h](3, y), where x = 3 is the smallest integer abscissa of a point and y is
+    // the square root of 3^3 + 3 + b which is not lexicographically largest
+    const GENERATOR_X: Fp6<Fp> = Fp6 {
+        c0: Fp(0xfc6ca01ec24e3032),
+        c1: Fp(0xd0150beaf96b537c),
+        c2: Fp(0xc4d39531a706ff56),
+        c3: Fp(0x5bafdfd0e6f2f036),
+        c4: Fp(0x8874e07b0817fd9f),
+        c5: Fp(0x61cf42de359571ce),
+    };
+    const GENERATOR_Y: Fp6<Fp> = Fp6 {
+        c0: Fp(0xf2932a2a697141d7),
+        c1: Fp(0xf5d65139720b503e),
+        c2: Fp(0xa05ecf438712ab9a),
+        c3: Fp(0xef4ccf76cedc1762),
+        c4: Fp(0xa25a19742cbb12c2),
+        c5: Fp(0x32e1adf62722caf6),
+    };
+
+    const SUBGROUP_ORDER: U256 = U256::from_words(SCALAR_MODULUS);
+}
+
+pub type AffinePoint = weierstrass::AffinePoint<CheetahParameters>;
+pub type ProjectivePoint = weierstrass::ProjectivePoint<CheetahParameters>;
+
+// Cofactor h = 0x2150b48e071ef610049bc3f5d54304e4a of the curve, as little-endian u64 words
+pub const COFACTOR: [u64; 3] = [0x49bc3f5d54304e4a, 0x150b48e071ef6100, 0x2];
+
+// Returns [h]P, which lies in the prime order subgroup
+pub fn clear_cofactor(p: &ProjectivePoint) -> ProjectivePoint {
+    p.mul_by_words(&COFACTOR)
+}
+
+// Multiplies the point by a scalar with a width-5 NAF of the scalar. This runs in variable time
+// and is meant for public scalars, such as in signature verification.
+pub fn mul_vartime(p: &ProjectivePoint, scalar: &Scalar) -> ProjectivePoint {
+    let naf = Scalar::bytes_to_wnaf_vartime(&scalar.to_bytes(), 5);
+    // table[i] = (2 * i + 1) * p
+    let double = p.double();
+    let mut table = [*p; 8];
+    for i in 1..8 {
+        table[i] = table[i - 1] + double;
+    }
+
+    let mut res = ProjectivePoint::identity();
+    for &digit in naf.iter().rev() {
+        res = res.double();
+        if digit > 0 {
+            res += table[(digit / 2) as usize];
+        } else if digit < 0 {
+            res -= table[(-digit / 2) as usize];
+        }
+    }
+    res
+}
+
+// COMPRESSION
+// ================================================================================================
+
+// Size in bytes of an encoded point
+pub const POINT_BYTES: usize = 49;
+
+// Flags stored in the last byte of an encoded point
+const FLAG_Y_LARGEST: u8 = 0x01;
+const FLAG_INFINITY: u8 = 0x02;
+
+// The first 48 bytes hold x in little-endian; the last byte holds the flags: the least significant
+// bit is set when y is lexicographically largest, and the next bit is set for the point at
+// infinity, whose other bytes are all zero
+impl CurveEncoding for CheetahParameters {
+    const ENCODED_BYTES: usize = POINT_BYTES;
+
+    type Encoding = [u8; POINT_BYTES];
+
+    fn encode(point: &AffinePoint) -> [u8; POINT_BYTES] {
+        let mut bytes = [0u8; POINT_BYTES];
+        if point.infinity {
+            bytes[POINT_BYTES - 1] = FLAG_INFINITY;
+            return bytes;
+        }
+        bytes[..POINT_BYTES - 1].copy_from_slice(&point.x.to_bytes());
+        if bool::from(Fp6::<Fp>::lexicographically_largest(point.y)) {
+            bytes[POINT_BYTES - 1] = FLAG_Y_LARGEST;
+        }
+        bytes
+    }
+
+    // Non canonical encodings are rejected: x must be reduced, unknown flags must not be set and
+    // the point at infinity must be encoded with zero bytes
+    fn decode(bytes: &[u8; POINT_BYTES]) -> Result<AffinePoint, DeserializationError> {
+        let flags = bytes[POINT_BYTES - 1];
+        if flags & !(FLAG_Y_LARGEST | FLAG_INFINITY) != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid point: unknown flags {flags:#04x}"
+            )));
+        }
+        let mut x_bytes = [0u8; POINT_BYTES - 1];
+        x_bytes.copy_from_slice(&bytes[..POINT_BYTES - 1]);
+        if flags & FLAG_INFINITY != 0 {
+            if flags != FLAG_INFINITY || x_bytes.iter().any(|&byte| byte != 0) {
+                return Err(DeserializationError::InvalidValue(
+                    "invalid point: non canonical encoding of the point at infinity".to_string(),
+                ));
+            }
+            return Ok(AffinePoint::identity());
+        }
+
+        let x = Fp6::<Fp>::from_bytes(&x_bytes);
+        if bool::from(x.is_none()) {
+            return Err(DeserializationError::InvalidValue(
+                "invalid point: x coordinate is not a canonical field element".to_string(),
+            ));
+        }
+        let x = x.unwrap();
+        let y = curve_rhs(x).sqrt();
+        if bool::from(y.is_none()) {
+            return Err(DeserializationError::InvalidValue(
+                "invalid point: x coordinate does not belong to a point of the curve".to_string(),
+            ));
+        }
+        let y = y.unwrap();
+        let y_largest = flags & FLAG_Y_LARGEST != 0;
+        if y_largest && y.is_zero() {
+            return Err(DeserializationError::InvalidValue(
+                "invalid point: sign flag set for y = 0".to_string(),
+            ));
+        }
+        let y = if bool::from(Fp6::<Fp>::lexicographically_largest(y)) == y_largest {
+            y
+        } else {
+            -y
+        };
+        Ok(AffinePoint::new(x, y))
+    }
+}
+
+// HASH TO CURVE
+// ================================================================================================
+// Parameters of the suite of RFC 9380 for math::hash_to_curve, with expand_message_xmd with
+// SHA-256, the simplified SWU map on the curve itself and cofactor clearing by multiplication by h
+
+impl HashToCurveParameters for CheetahParameters {
+    // Z = -10 - u is the first element of the form k + u or k - u, for k = 0, 1, -1, 2, -2, ...,
+    // satisfying the criteria of RFC 9380, section H.2
+    const SSWU_Z: Fp6<Fp> = Fp6 {
+        c0: Fp(0xfffffffefffffff7),
+        c1: Fp(0xffffffff00000000),
+        c2: Fp(0),
+        c3: Fp(0),
+        c4: Fp(0),
+        c5: Fp(0),
+    };
+
+    const H_EFF: &'static [u64] = &COFACTOR;
+
+    // Every coefficient is the reduction of 24 bytes of expand_message_xmd with SHA-256, read in
+    // big-endian
+    fn hash_to_field(
+        msg: &[u8],
+        dst: &[u8],
+        count: usize,
+    ) -> Result<Vec<Fp6<Fp>>, HashToFieldError> {
+        hash_to_extension_field::<Fp, Fp6<Fp>>(msg, dst, count, ExpandMessage::XmdSha256)
+    }
+
+    // Parity of the first non-zero coefficient, as defined for extension fields
+    fn sgn0(a: &Fp6<Fp>) -> bool {
+        let coeffs = [a.c0, a.c1, a.c2, a.c3, a.c4, a.c5];
+        coeffs
+            .iter()
+            .map(|coeff| coeff.make_canonical().0)
+            .find(|&coeff| coeff != 0)
+            .is_some_and(|coeff| coeff & 1 == 1)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+// Returns x^3 + x + b
+fn curve_rhs(x: Fp6<Fp>) -> Fp6<Fp> {
+    weierstrass::curve_rhs::<CheetahParameters>(x)
+}
@@ -457,9 +457,17 @@ impl Extensible<3> for Fp{
     }
 
     ///Returns the inverse of a 3 degree extension field as an array wrapped in CtOption.
-    ///unimplemented.
-    fn invert(_a: [Self; 3])->CtOption<[Self; 3]> {
-        unimplemented!()
+    fn invert(a: [Self; 3])->CtOption<[Self; 3]> {
+        // With x^3 = BETA, the inverse of a0 + a1 x + a2 x^2 is (c0 + c1 x + c2 x^2) / n, where
+        // n = a0 c0 + BETA (a2 c1 + a1 c2) is the norm of the element
+        let beta = Fp::from(BETA as u64);
+        let c0 = a[0].square() - beta * a[1] * a[2];
+        let c1 = beta * a[2].square() - a[0] * a[1];
+        let c2 = a[1].square() - a[0] * a[2];
+        let norm = a[0] * c0 + beta * (a[2] * c1 + a[1] * c2);
+        let norm_inv = norm.invert();
+        let n = norm_inv.unwrap_or(Fp::ZERO);
+        CtOption::new([c0 * n, c1 * n, c2 * n], norm_inv.is_some())
     }
 }
 
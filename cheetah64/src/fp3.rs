@@ -5,7 +5,7 @@ use std::{fmt::{self,Display, Formatter},slice};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use crypto_bigint::U256;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
-use traits::traits::{Field, Extensible, PrimeField};
+use traits::traits::{Field, Extensible, ExtensionOf, PrimeField};
 use core::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, AsBytes, Randomizable, SliceReader};
 
 pub const BETA: u128 = crate::fp::GENERATOR.0 as u128;
@@ -74,11 +74,14 @@ impl <B: Extensible<3>> Field for Fp3<B>{
         Fp3{a0: z[0], a1: z[1], a2: z[2]}
     }
 
-    ///Unsupproted
-    // Computes the multiplicative inverse of Fp3 element,
-    // panics if the element is zero. Used to define Common field trait  for Fp
+    // Computes the multiplicative inverse of Fp3 element.
     fn invert(self)->CtOption<Self> {
-        unimplemented!()
+        let z= <B as Extensible<3>>::invert([self.a0, self.a1, self.a2]);
+        if bool::from(z.is_some()) {
+            CtOption::new(Fp3::new(z.unwrap()), z.is_some())
+        } else {
+            CtOption::new(Fp3::zero(), z.is_some())
+        }
     }
 
     /// Computes the square root of this element, if it exists.
@@ -271,6 +274,29 @@ impl <B: Extensible<3>> From<&B> for Fp3<B>{
     }
 }
 
+impl From<Fp> for Fp3<Fp> {
+    fn from(value: Fp) -> Self {
+        Fp3::from(&value)
+    }
+}
+
+// Fp3 is a cubic extension of its coefficient field, with basis {1, x, x^2}.
+impl <B: Extensible<3>> ExtensionOf<B> for Fp3<B> where Fp3<B>: From<B> {
+    const DEGREE: usize = 3;
+
+    fn mul_base(self, other: B) -> Self {
+        Fp3::new(<B as Extensible<3>>::mul_base([self.a0, self.a1, self.a2], other))
+    }
+
+    fn to_base_elements(self) -> Vec<B> {
+        vec![self.a0, self.a1, self.a2]
+    }
+
+    fn from_base_elements(elements: &[B]) -> Self {
+        Fp3::new([elements[0], elements[1], elements[2]])
+    }
+}
+
 ///======Trait bounds=======
 
 impl <B: Extensible<3>> Display for Fp3<B> {
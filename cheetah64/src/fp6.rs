@@ -13,7 +13,7 @@ use serde::de::Visitor;
 #[cfg(feature = "serialize")]
 use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
-use traits::traits::{Field, Extensible, PrimeField};
+use traits::traits::{Field, Extensible, ExtensionOf, PrimeField};
 use core::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader, AsBytes, Randomizable
 };
@@ -90,12 +90,18 @@ impl <B: Extensible<6>> Fp6<B> {
         // are zero and the c0 coefficient is lexicographically largest,
         // then this element is lexicographically largest.
 
+        let c5_zero = Choice::from(element.c5.is_zero() as u8);
+        let c4_zero = c5_zero & Choice::from(element.c4.is_zero() as u8);
+        let c3_zero = c4_zero & Choice::from(element.c3.is_zero() as u8);
+        let c2_zero = c3_zero & Choice::from(element.c2.is_zero() as u8);
+        let c1_zero = c2_zero & Choice::from(element.c1.is_zero() as u8);
+
         element.c5.lexicographically_largest()
-            | (Choice::from(element.c5.is_zero() as u8) & element.c4.lexicographically_largest())
-            | (Choice::from(element.c4.is_zero() as u8) & element.c3.lexicographically_largest())
-            | (Choice::from(element.c3.is_zero() as u8) & element.c2.lexicographically_largest())
-            | (Choice::from(element.c2.is_zero() as u8)& element.c1.lexicographically_largest())
-            | (Choice::from(element.c1.is_zero() as u8) & element.c0.lexicographically_largest())
+            | (c5_zero & element.c4.lexicographically_largest())
+            | (c4_zero & element.c3.lexicographically_largest())
+            | (c3_zero & element.c2.lexicographically_largest())
+            | (c2_zero & element.c1.lexicographically_largest())
+            | (c1_zero & element.c0.lexicographically_largest())
     }
 
     /// Computes the multiplication of an Fp6 element with an Fp element
@@ -341,6 +347,29 @@ impl <B: Extensible<6>> From<&B> for Fp6<B> {
     }
 }
 
+impl From<Fp> for Fp6<Fp> {
+    fn from(f: Fp) -> Self {
+        Fp6::from(&f)
+    }
+}
+
+// Fp6 is a sextic extension of its coefficient field, with basis {1, u, u^2, u^3, u^4, u^5}.
+impl <B: Extensible<6>> ExtensionOf<B> for Fp6<B> where Fp6<B>: From<B> {
+    const DEGREE: usize = 6;
+
+    fn mul_base(self, other: B) -> Self {
+        self.mul_by_fp(&other)
+    }
+
+    fn to_base_elements(self) -> Vec<B> {
+        vec![self.c0, self.c1, self.c2, self.c3, self.c4, self.c5]
+    }
+
+    fn from_base_elements(elements: &[B]) -> Self {
+        Fp6::new([elements[0], elements[1], elements[2], elements[3], elements[4], elements[5]])
+    }
+}
+
 //Creates a Fp6 element from Fp3 element.
 impl <B: Extensible<6>+Extensible<3>> From<Fp3<B>> for Fp6<B> {
     fn from(f: Fp3<B>) -> Self {
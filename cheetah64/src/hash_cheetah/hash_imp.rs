@@ -0,0 +1,28 @@
+use super::rescue_cheetah::{rescue_ark, rescue_inv_mds, rescue_mds};
+use crate::fp::Fp;
+use crypto_bigint::U256;
+use traits::traits::{Field, PrimeField, RescueParameter};
+
+// Implementation of RescueParameter trait for the 64-bit field
+impl<F: Field + PrimeField> RescueParameter<F> for Fp {
+    const RESCUE_STATE_WIDTH: usize = 4;
+
+    const RESCUE_ALPHA: u64 = 7;
+
+    const RESCUE_RATE: usize = 2;
+    const RESCUE_NO_OF_ROUNDS: usize = 9;
+    const RESCUE_INV_ALPHA: U256 =
+        U256::from_be_hex("00000000000000000000000000000000000000000000000092492491b6db6db7");
+
+    fn rescue_mds() -> Vec<F> {
+        rescue_mds::<F>().to_vec()
+    }
+
+    fn rescue_inv_mds() -> Vec<F> {
+        rescue_inv_mds::<F>().to_vec()
+    }
+
+    fn rescue_ark() -> Vec<Vec<F>> {
+        rescue_ark::<F>().to_vec()
+    }
+}
@@ -0,0 +1,154 @@
+use traits::traits::{Field, PrimeField};
+
+// Rescue-Prime parameters for the 64-bit field: a state of 4 elements with a capacity of 2,
+// alpha = 7 and 9 rounds, the number the Rescue-Prime paper gives for 128 bits of security. The
+// MDS matrix and the round constants are derived as in the reference implementation.
+
+// Stores the matrix values
+pub fn rescue_mds<F: Field + PrimeField>() -> [F; 16] {
+    [
+        F::from(0xfffffffefffe3470u64),
+        F::from(0x00000000000217f0u64),
+        F::from(0xfffffffeffffb213u64),
+        F::from(0x0000000000000190u64),
+        F::from(0xfffffffefd31ed71u64),
+        F::from(0x0000000003439b6fu64),
+        F::from(0xfffffffeff885411u64),
+        F::from(0x0000000000022312u64),
+        F::from(0xfffffffb29e8dccfu64),
+        F::from(0x00000004767d6c50u64),
+        F::from(0xfffffffe5cba96b4u64),
+        F::from(0x0000000002df2030u64),
+        F::from(0xfffffad750e8b4d1u64),
+        F::from(0x000005ff275b59ceu64),
+        F::from(0xffffff23ae5f0fb1u64),
+        F::from(0x00000003d95ce1b3u64),
+    ]
+}
+
+// Stores the inverse matrix values
+pub fn rescue_inv_mds<F: Field + PrimeField>() -> [F; 16] {
+    [
+        F::from(0x69f4c0ef10122a86u64),
+        F::from(0xc4fc210341beaa46u64),
+        F::from(0x848898d6d3f7a927u64),
+        F::from(0x4c868534da378210u64),
+        F::from(0x76f8406de05d2e51u64),
+        F::from(0x4873b6baa4eae951u64),
+        F::from(0x29db4cf2b00506b1u64),
+        F::from(0x16b8bbe3cab2e1afu64),
+        F::from(0xe87bfec076098facu64),
+        F::from(0xd4d1a228bdb54bd0u64),
+        F::from(0x98317e1164bb7c8au64),
+        F::from(0xaa80e1026785a7feu64),
+        F::from(0x59900bf0b918a138u64),
+        F::from(0x178c5c4dd5697ad8u64),
+        F::from(0x8d5284e24bebeb93u64),
+        F::from(0x019112de2591f85fu64),
+    ]
+}
+
+// Stores the constant values, 8 per round
+pub fn rescue_ark<F: Field + PrimeField>() -> [Vec<F>; 9] {
+    [
+        [
+            F::from(0x753b7fcb6b9099e6u64),
+            F::from(0xef19de7372b0d18bu64),
+            F::from(0xf520fe906d88c009u64),
+            F::from(0x516e401e3c72effdu64),
+            F::from(0x750499a70dd05062u64),
+            F::from(0xb67d3c2356d22cabu64),
+            F::from(0xf8dcbf741d153173u64),
+            F::from(0x9730cfe7c2fc5267u64),
+        ]
+        .to_vec(),
+        [
+            F::from(0x7846cc6a1a679a15u64),
+            F::from(0xebb8054dfe98a773u64),
+            F::from(0xdb2ee7510958a307u64),
+            F::from(0x35db2b26748e5a33u64),
+            F::from(0x82d88d529fe6e6c7u64),
+            F::from(0x902103c4ca3a5e94u64),
+            F::from(0x2fba01bb86a61157u64),
+            F::from(0x1bcd192c07fc3946u64),
+        ]
+        .to_vec(),
+        [
+            F::from(0x782526cae20c3716u64),
+            F::from(0x6d9b579820cc1a98u64),
+            F::from(0x234d82aa4dcde701u64),
+            F::from(0xbf77f17556bf7903u64),
+            F::from(0x8d935ca984d01c1fu64),
+            F::from(0xf46d6e366d6cb86du64),
+            F::from(0xed4c288bea9f628fu64),
+            F::from(0xe8508df22f708a0eu64),
+        ]
+        .to_vec(),
+        [
+            F::from(0x9835bcdc6d27b9efu64),
+            F::from(0xfa0bd51e710b6152u64),
+            F::from(0x803272f11ea7fa8fu64),
+            F::from(0xd2b227d98aceaef9u64),
+            F::from(0x8f90d03dbd637600u64),
+            F::from(0x74105469922d7526u64),
+            F::from(0xac4f10668b944ea3u64),
+            F::from(0xbce997d804fa9ef5u64),
+        ]
+        .to_vec(),
+        [
+            F::from(0x07d95838256b97eau64),
+            F::from(0xc94f707f90166b25u64),
+            F::from(0x6197a1277c6a4cdcu64),
+            F::from(0x07f1a108fbb5a970u64),
+            F::from(0xf636053923496657u64),
+            F::from(0x0720b25c94195a8fu64),
+            F::from(0xd1d0c0ecd86ddb48u64),
+            F::from(0x4d8dc1611d79eb97u64),
+        ]
+        .to_vec(),
+        [
+            F::from(0xc2f2a4fea3334ca0u64),
+            F::from(0x21135ad41d642487u64),
+            F::from(0x2462a790eaede701u64),
+            F::from(0xae91349288ffc68eu64),
+            F::from(0xa74bfa5d48934b07u64),
+            F::from(0x7ea50a6c7c6fe026u64),
+            F::from(0x3d254c180746da15u64),
+            F::from(0x5bb18eb999f4f52du64),
+        ]
+        .to_vec(),
+        [
+            F::from(0x36eae44da04eb120u64),
+            F::from(0x778a6ef21d333ec8u64),
+            F::from(0xc1f879f25aaa48c3u64),
+            F::from(0x7dec5b8ef2016048u64),
+            F::from(0xdd98dd7e74699f28u64),
+            F::from(0xab90d083dce865aeu64),
+            F::from(0xcdcd8cfca6e3f17eu64),
+            F::from(0xb779d1a3cd206527u64),
+        ]
+        .to_vec(),
+        [
+            F::from(0xe3895ca95d36b4f3u64),
+            F::from(0x71a72a43e768b14du64),
+            F::from(0x93c9e86bb5cafa1eu64),
+            F::from(0x0abdcfd57c1728e7u64),
+            F::from(0x96fce5c145720a15u64),
+            F::from(0x315adf1339b5c942u64),
+            F::from(0x60960ea77a6880c2u64),
+            F::from(0x4f0f23f3a5f6bad9u64),
+        ]
+        .to_vec(),
+        [
+            F::from(0x33dac92714100878u64),
+            F::from(0x6837cf342b505bb1u64),
+            F::from(0x042287157242641fu64),
+            F::from(0xcd6ec5ad6b89b221u64),
+            F::from(0x0edda26775d672d8u64),
+            F::from(0x8c00c01db3dc1fa7u64),
+            F::from(0x13d872a25127f7b9u64),
+            F::from(0xc3e05e93e15dd19du64),
+        ]
+        .to_vec(),
+    ]
+}
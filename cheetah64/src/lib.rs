@@ -1,5 +1,7 @@
+pub mod curve;
 pub mod fp;
 pub mod fp3;
 pub mod fp6;
+pub mod hash_cheetah;
 pub mod scalar;
 pub mod test;
\ No newline at end of file
@@ -283,7 +283,7 @@ impl Scalar {
 
     /// Converts a `Scalar` element into a byte representation in
     /// little-endian byte order.
-    fn to_bytes(&self) -> [u8; 32] {
+    pub fn to_bytes(&self) -> [u8; 32] {
         // Turn into canonical form by computing
         // (a.R) / R = a
         let tmp = Scalar::montgomery_reduce(self.0[0], self.0[1], self.0[2], self.0[3], 0, 0, 0, 0);
@@ -583,7 +583,8 @@ impl Field for Scalar{
 
     fn get_windows(&self, window_bits : usize)->Vec<usize> {
 
-        let int = U256::from_words(self.0);
+        // the windows are taken from the canonical value, not from the Montgomery form
+        let int = U256::from_words(self.output_reduced_limbs());
 
         let window_marker = 1usize<<window_bits;
 
@@ -617,6 +618,10 @@ impl PrimeField for Scalar{
         TWO_ADIC_ROOT_OF_UNITY
     }
 
+    fn to_canonical_words(&self) -> Vec<u64> {
+        self.output_reduced_limbs().to_vec()
+    }
+
     const MODULUS:&'static str= "7AF2599B3B3F22D0563FBF0F990A37B5327AA72330157722D443623EAED4ACCF";
     const NUM_BITS:u32= 255;
 
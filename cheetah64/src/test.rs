@@ -614,3 +614,237 @@ mod tests {
     }
 
 }
+
+#[cfg(test)]
+mod msmtest {
+    use traits::traits::Field;
+
+    use crate::scalar::Scalar;
+
+    #[test]
+    fn test_msm_windows() {
+        // the windows are read from the canonical value of the scalar, not its Montgomery form
+        let windows = Scalar::from(0x5a3u64).get_windows(4);
+        assert_eq!(windows[..4], [3, 10, 5, 0]);
+        assert!(windows[4..].iter().all(|&window| window == 0));
+    }
+}
+
+#[cfg(test)]
+mod curvetest {
+    use core::{Deserializable, Serializable, SliceReader};
+    use hash::hash_to_field::HashToFieldError;
+    use math::hash_to_curve::{hash_to_curve, map_to_curve, HashToCurveParameters};
+    use traits::traits::Field;
+
+    use crate::{
+        curve::{mul_vartime, AffinePoint, CheetahParameters, ProjectivePoint, POINT_BYTES},
+        fp::Fp,
+        fp6::Fp6,
+        scalar::{Scalar, SCALAR_MODULUS},
+    };
+
+    const DST: &[u8] = b"QUUX-V01-CS02-with-cheetah_XMD:SHA-256_SSWU_RO_";
+
+    fn fp6(coeffs: [u64; 6]) -> Fp6<Fp> {
+        Fp6::new(coeffs.map(Fp))
+    }
+
+    #[test]
+    fn test_generator() {
+        let g = ProjectivePoint::generator();
+        assert!(g.is_on_curve());
+        assert!(g.is_torsion_free());
+        assert!(g.to_affine().is_on_curve());
+        assert!(!g.is_identity());
+        assert!(g.mul_by_words(&SCALAR_MODULUS).is_identity());
+        assert!(ProjectivePoint::identity().is_on_curve());
+        assert!(AffinePoint::identity().is_on_curve());
+    }
+
+    #[test]
+    fn test_addition_and_doubling() {
+        let g = ProjectivePoint::generator();
+        let identity = ProjectivePoint::identity();
+        assert_eq!(g + identity, g);
+        assert_eq!(identity + g, g);
+        assert_eq!(identity.double(), identity);
+        assert_eq!(g + g, g.double());
+        assert_eq!(g - g, identity);
+        assert_eq!(g + -g, identity);
+
+        let p = g * Scalar::random();
+        let q = g * Scalar::random();
+        let r = g * Scalar::random();
+        assert_eq!(p + q, q + p);
+        assert_eq!((p + q) + r, p + (q + r));
+        assert_eq!(p.double().double(), p + p + p + p);
+        assert_eq!(p + q.to_affine(), p + q);
+        assert_eq!(p - q.to_affine(), p - q);
+        assert!((p + q).is_on_curve());
+        assert!(p.double().is_on_curve());
+        assert_eq!(p.to_affine().to_projective(), p);
+    }
+
+    #[test]
+    fn test_scalar_multiplication() {
+        let g = ProjectivePoint::generator();
+        assert!((g * Scalar::zero()).is_identity());
+        assert_eq!(g * Scalar::one(), g);
+        assert_eq!(g * -Scalar::one(), -g);
+        assert_eq!(g * Scalar::from(5u64), g + g + g + g + g);
+        assert_eq!(mul_vartime(&g, &Scalar::from(5u64)), g + g + g + g + g);
+        assert!(mul_vartime(&g, &Scalar::zero()).is_identity());
+
+        for _ in 0..5 {
+            let a = Scalar::random();
+            let b = Scalar::random();
+            let expected = g.mul_by_words(&a.output_reduced_limbs());
+            assert_eq!(g * a, expected);
+            assert_eq!(mul_vartime(&g, &a), expected);
+            assert_eq!(g.to_affine() * a, expected);
+            assert_eq!((g * a) * b, g * (a * b));
+            assert_eq!(g * a + g * b, g * (a + b));
+        }
+    }
+
+    #[test]
+    fn test_compression() {
+        let identity = AffinePoint::identity();
+        assert_eq!(AffinePoint::from_compressed(&identity.to_compressed()).unwrap(), identity);
+
+        for _ in 0..5 {
+            let p = (ProjectivePoint::generator() * Scalar::random()).to_affine();
+            let bytes = p.to_compressed();
+            assert_eq!(AffinePoint::from_compressed(&bytes).unwrap(), p);
+            assert_eq!(AffinePoint::from_compressed(&(-p).to_compressed()).unwrap(), -p);
+
+            let mut encoded = Vec::new();
+            p.write_into(&mut encoded);
+            assert_eq!(encoded.len(), POINT_BYTES);
+            let mut reader = SliceReader::new(&encoded);
+            assert_eq!(AffinePoint::read_from(&mut reader).unwrap(), p);
+        }
+
+        // unknown flags
+        let mut bytes = AffinePoint::generator().to_compressed();
+        bytes[POINT_BYTES - 1] |= 0x04;
+        assert!(AffinePoint::from_compressed(&bytes).is_err());
+        // non canonical point at infinity
+        let mut bytes = identity.to_compressed();
+        bytes[0] = 1;
+        assert!(AffinePoint::from_compressed(&bytes).is_err());
+        let mut bytes = identity.to_compressed();
+        bytes[POINT_BYTES - 1] |= 0x01;
+        assert!(AffinePoint::from_compressed(&bytes).is_err());
+        // non canonical x coordinate
+        let mut bytes = AffinePoint::generator().to_compressed();
+        bytes[..8].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(AffinePoint::from_compressed(&bytes).is_err());
+        // x = 0 is not the abscissa of a point, since b is not a square
+        let bytes = [0u8; POINT_BYTES];
+        assert!(AffinePoint::from_compressed(&bytes).is_err());
+
+        // points outside of the prime order subgroup only decode without the subgroup check
+        let p = map_to_curve::<CheetahParameters>(&Fp6::from(3u64));
+        assert!(!p.is_torsion_free());
+        let bytes = p.to_compressed();
+        assert_eq!(AffinePoint::from_compressed_unchecked(&bytes).unwrap(), p);
+        assert!(AffinePoint::from_compressed(&bytes).is_err());
+        let mut reader = SliceReader::new(&bytes);
+        assert!(AffinePoint::read_from(&mut reader).is_err());
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        let vectors = [
+            (
+                b"".as_slice(),
+                [0xf692a3e4f31a9891, 0x1504f5f41cbf91b, 0x928f42d255f34766, 0xa279388da3a84c8d, 0xc8065ff483c3d67e, 0x45a51cdfe402189d],
+                [0x903f3dab55e33372, 0xe3df9556e1cd72df, 0x6fff3734e4ebf95e, 0x932311887c56172b, 0x3704a13ff85ddcd0, 0xa175244bdf30c],
+                [0x864183107352d308, 0x4a06e98fb218174a, 0x7c33ef92c78d7a2d, 0x6339f9e3dbddd872, 0x235f2e7d8cc57e4e, 0x307c69a2f573d976],
+                [0x728e3409a6d651dc, 0xaa9507d6cafd6488, 0x6675caf812886a21, 0x353d0d5cd372edb9, 0x3b276cba365fc0b7, 0xf187c1f8350b1ec3],
+            ),
+            (
+                b"abc".as_slice(),
+                [0x1601ebbf160c27be, 0xdd3e2069f0f56c53, 0x1e904b751dde4047, 0xe61a629ebb3a7f86, 0xb4983502d2013a64, 0x41b210f0a953298e],
+                [0xf0ecc9627fead46e, 0x61480c3fc949245c, 0x2697c8108643251f, 0x83e451617a7389f7, 0xb9c2684ccb68b818, 0xfa7de371ef730fe3],
+                [0x5117c349dd5cb5b0, 0xe2115325ade4193, 0x3f479bb738249ac2, 0xedb6528984c62698, 0x646f7ab4db7c663d, 0x5f45231719ae49c6],
+                [0xed5b916c9982dc6e, 0x39445020bded3cb3, 0x1cfe2d28c239ab3d, 0x39dca16bb6f6b0c1, 0xa5ca2a52c05d6bb5, 0xdeb1d7fa013b4210],
+            ),
+        ];
+        for (msg, u0, q0_x, p_x, p_y) in vectors {
+            let u = CheetahParameters::hash_to_field(msg, DST, 2).unwrap();
+            assert_eq!(u[0], fp6(u0));
+            let q0 = map_to_curve::<CheetahParameters>(&u[0]);
+            assert!(q0.is_on_curve());
+            assert_eq!(q0.x, fp6(q0_x));
+
+            let p = hash_to_curve::<CheetahParameters>(msg, DST).unwrap();
+            assert!(p.is_torsion_free());
+            assert_eq!(p.to_affine(), AffinePoint::new(fp6(p_x), fp6(p_y)));
+        }
+        assert_ne!(
+            hash_to_curve::<CheetahParameters>(b"abc", DST),
+            hash_to_curve::<CheetahParameters>(b"abc", b"another DST")
+        );
+        assert_eq!(
+            hash_to_curve::<CheetahParameters>(b"abc", b""),
+            Err(HashToFieldError::EmptyDst)
+        );
+        assert_eq!(
+            CheetahParameters::hash_to_field(b"abc", b"", 2),
+            Err(HashToFieldError::EmptyDst)
+        );
+    }
+}
+
+#[cfg(test)]
+mod rescuetest {
+    use hash::rescue::{apply_inv_mds, apply_inv_sbox, apply_mds, apply_sbox, Rescue};
+    use traits::traits::{Field, Hasher, RescueParameter};
+
+    use crate::fp::Fp;
+
+    #[test]
+    fn rescue_inverse_check() {
+        let v: Vec<Fp> = (0..4).map(|_| Fp::random()).collect();
+        let mut c = v.clone();
+        apply_sbox(&mut c);
+        apply_inv_sbox(&mut c);
+        apply_mds(&mut c);
+        apply_inv_mds(&mut c);
+        assert_eq!(v, c);
+    }
+
+    #[test]
+    fn rescue_parameters_check() {
+        // inverse MDS times MDS is the identity
+        let mds = <Fp as RescueParameter<Fp>>::rescue_mds();
+        let inv_mds = <Fp as RescueParameter<Fp>>::rescue_inv_mds();
+        for i in 0..4 {
+            for j in 0..4 {
+                let entry =
+                    (0..4).fold(Fp::ZERO, |acc, k| acc + mds[4 * i + k] * inv_mds[4 * k + j]);
+                assert_eq!(entry, if i == j { Fp::ONE } else { Fp::ZERO });
+            }
+        }
+        let ark = <Fp as RescueParameter<Fp>>::rescue_ark();
+        assert!(ark.iter().all(|round| round.len() == 8));
+        // x^7 and x^(1/7) are inverse permutations
+        let x = Fp::random();
+        let inv_alpha = <Fp as RescueParameter<Fp>>::RESCUE_INV_ALPHA;
+        assert_eq!(x.power_by([7]).power_by(inv_alpha.to_words()), x);
+    }
+
+    #[test]
+    fn rescue_hash_vectors() {
+        // outputs of the reference implementation of Rescue-Prime with the same parameters
+        let mut v = vec![Fp::from(1u8), Fp::from(2u8), Fp::from(3u8), Fp::from(4u8)];
+        assert_eq!(<Rescue<Fp>>::hash(&mut v), Fp::from(0xb30f03136a8c5c39u64));
+        let mut v = vec![Fp::ZERO, Fp::ZERO];
+        assert_eq!(<Rescue<Fp>>::hash(&mut v), Fp::from(0x4a0cea89fd14618au64));
+        let mut v = vec![Fp::from(1u8), Fp::from(2u8), Fp::from(3u8)];
+        assert_eq!(<Rescue<Fp>>::hash(&mut v), Fp::from(0xd8e7afaa9423fadfu64));
+    }
+}
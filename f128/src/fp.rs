@@ -192,7 +192,7 @@ impl Field for Fp {
         self.as_bytes()
     }
     fn to_words(&self) -> Vec<u64> {
-        unimplemented!()
+        vec![self.0 as u64, (self.0 >> 64) as u64]
     }
     const ELEMENT_BYTES: usize = ELEMENT_BYTES;
 
@@ -200,8 +200,8 @@ impl Field for Fp {
      unimplemented!()
     }
 
-    fn from_words( _a: &Vec<u64>) -> Self {
-     unimplemented!()
+    fn from_words( a: &Vec<u64>) -> Self {
+        Fp::new(a[0] as u128 | (a[1] as u128) << 64)
     }
 
     fn get_windows(&self, _exp: usize)->Vec<usize> {
@@ -100,9 +100,19 @@ impl<B:Extensible<2>> Field for Fp2<B> {
 
 /// A field is always an extension of itself.
 impl<B:Extensible<2>> ExtensionOf<B> for Fp2<B> where Fp2<B>: From<B> {
+    const DEGREE: usize = 2;
+
     fn mul_base(self, other: B) -> Self {
         B::mul_base([self.c0, self.c1], other).into()
     }
+
+    fn to_base_elements(self) -> Vec<B> {
+        vec![self.c0, self.c1]
+    }
+
+    fn from_base_elements(elements: &[B]) -> Self {
+        Fp2::new(elements[0], elements[1])
+    }
 }
 //Quadratic extension for 128-bit field with respect to irreducible polynomial x^2 - x - 1. Basis considered is {1, w}.
 
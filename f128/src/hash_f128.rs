@@ -0,0 +1,2 @@
+pub mod hash_imp;
+pub mod rescue_f128;
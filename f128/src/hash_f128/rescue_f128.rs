@@ -0,0 +1,209 @@
+use traits::traits::{Field, PrimeField};
+
+// Rescue-Prime parameters for the 128-bit field: a state of 4 elements with a capacity of 2,
+// alpha = 3 and 14 rounds, the number the Rescue-Prime paper gives for 128 bits of security. The
+// MDS matrix and the round constants are derived as in the reference implementation.
+
+// Stores the matrix values
+pub fn rescue_mds<F: Field + PrimeField>() -> [F; 16] {
+    [
+        F::from(0xffffffffffffffffffffd2fffffffd28u128),
+        F::from(0x00000000000000000000000000000438u128),
+        F::from(0xffffffffffffffffffffd2fffffffe7bu128),
+        F::from(0x00000000000000000000000000000028u128),
+        F::from(0xffffffffffffffffffffd2ffffff8e19u128),
+        F::from(0x0000000000000000000000000000a5e7u128),
+        F::from(0xffffffffffffffffffffd2ffffffc749u128),
+        F::from(0x000000000000000000000000000004bau128),
+        F::from(0xffffffffffffffffffffd2fffff28a57u128),
+        F::from(0x00000000000000000000000000137ec8u128),
+        F::from(0xffffffffffffffffffffd2fffff9728cu128),
+        F::from(0x00000000000000000000000000008458u128),
+        F::from(0xffffffffffffffffffffd2fffe872169u128),
+        F::from(0x0000000000000000000000000220dd96u128),
+        F::from(0xffffffffffffffffffffd2ffff49e0b9u128),
+        F::from(0x000000000000000000000000000e204bu128),
+    ]
+}
+
+// Stores the inverse matrix values
+pub fn rescue_inv_mds<F: Field + PrimeField>() -> [F; 16] {
+    [
+        F::from(0x0d8ea625f2b5ab3d4a8df87a207368ebu128),
+        F::from(0x59ac5e9f20e8f576209288abd35ae001u128),
+        F::from(0xae17cd316a4d5644424048114c36de46u128),
+        F::from(0xeaad2e0982140908529edcc8bffad8d1u128),
+        F::from(0xb8d7e6ec94f2474cb927af554eac9774u128),
+        F::from(0x1828de42b73bc659d96f6d640ab616c5u128),
+        F::from(0xd5d83c22f66332c8424f40d75b349301u128),
+        F::from(0x5926feadbd6ebf912b19486f4b68bec9u128),
+        F::from(0x1ff4c33f8fa07b9c44d4151242b8b69du128),
+        F::from(0xd55a53e3c02a73ba8c135cdb709175f4u128),
+        F::from(0x46c0e1942083ef3230d32bd729237117u128),
+        F::from(0xc3f007488fb12176fe45083b2392625bu128),
+        F::from(0x097b425ed097b425ed097997b425ed0bu128),
+        F::from(0xbc937d5dc2e5a99cf8a000905447a34bu128),
+        F::from(0x55af3b58d85178732eb470c33f8fa07cu128),
+        F::from(0xe44204ea943129c9eba1bb14b802cf31u128),
+    ]
+}
+
+// Stores the constant values, 8 per round
+pub fn rescue_ark<F: Field + PrimeField>() -> [Vec<F>; 14] {
+    [
+        [
+            F::from(0xbe00468a0ce85c78dd4cedf680307849u128),
+            F::from(0xf57d9ecec4a7f95f5ca1f992b18e0193u128),
+            F::from(0xd53bd0029a4f5edea13fbb68220f5285u128),
+            F::from(0x6fa6ab1261e1c8fb5a839c454fe52b1du128),
+            F::from(0x61a39c7933102817cc4bb29384d65bb8u128),
+            F::from(0xfbd0fe460e2d4788af09fd1f73be1c6au128),
+            F::from(0xc1465ad3cd6ee21fb2085908b1df4d63u128),
+            F::from(0x5edea3ad5a9642c22477b3250739ba6du128),
+        ]
+        .to_vec(),
+        [
+            F::from(0xb6ceaa4dcbc641c9d616e45499b99c4au128),
+            F::from(0xf315155363d8357ac871d8fa414b0f8cu128),
+            F::from(0x7e478dd192296195eae71620a25675a0u128),
+            F::from(0x31d97cc95a32be570eb11d86e7819a10u128),
+            F::from(0xfa4d2267f6ebf04bf1c4e829effd8b91u128),
+            F::from(0xe8ca6d31d1f56eac4a869ff64bee8af3u128),
+            F::from(0x9736bcb2a5d1c1a91fbf721467b489fdu128),
+            F::from(0xa1a2d356120497bc3f4b243c4dbe2cbau128),
+        ]
+        .to_vec(),
+        [
+            F::from(0xbf49401eace85e4d53589bbce7200ce3u128),
+            F::from(0xb80a0ab38feb685229198ea838cf3750u128),
+            F::from(0xe4d134b3fb305248cefd619bfdef3b76u128),
+            F::from(0x156ba60b3afa8ef1edb966d6df2aa6bdu128),
+            F::from(0x5a18203485086a5c1aba2a6fce0a9ab2u128),
+            F::from(0xd3c832d74eeb3c19600f4c87ea7b8ac4u128),
+            F::from(0xe2160666acf1e59f35b99b475b6f0483u128),
+            F::from(0xa6ebf23037167afe141a604c687419f6u128),
+        ]
+        .to_vec(),
+        [
+            F::from(0x6581aa5a3a5e079b0a58cc686fe4edd4u128),
+            F::from(0x6badb56779a49aad9b3cde23e2084f0au128),
+            F::from(0xc0c2e18578633bcde49167574fefb103u128),
+            F::from(0x176ebadf5c393f400193fbe6beb0546bu128),
+            F::from(0x4cd8d8c3c3e25e98134330d6a0fdf886u128),
+            F::from(0xaa4afc6b1478cbf89c28f4d564d8d34du128),
+            F::from(0xd0fb6f865ffc7f70498b1eb560e5f91bu128),
+            F::from(0x38485dd429c50affcfcd61ba9dc2283bu128),
+        ]
+        .to_vec(),
+        [
+            F::from(0xd9838aaa7548f36192d48f1aa0ac27dbu128),
+            F::from(0x71222e02680267847bf9d4a65cb884f2u128),
+            F::from(0x54beca5dbcdb13289e1944906dbb27e7u128),
+            F::from(0x0acb9925635e6c36be71f4f1f04604f5u128),
+            F::from(0xc7b0a011174f1af76e16b4a4b19379a2u128),
+            F::from(0x1a0f3fac047525bf3831ecc4d43eb610u128),
+            F::from(0xce35b5ddeb9fdbbc2c77f7d498962d2eu128),
+            F::from(0x0b624ed91d44f74681d033df30387debu128),
+        ]
+        .to_vec(),
+        [
+            F::from(0x42cf711e42a12f021a39e6905bd4e4e4u128),
+            F::from(0xe53b315c49369c781cd75db8a1f6183au128),
+            F::from(0xe5c098acb458f72830b500ab3a604405u128),
+            F::from(0x794d14f4e5c0084caeeef5832f696115u128),
+            F::from(0x08beafb76baee1aeb9c7cd669d588463u128),
+            F::from(0x5f02b4797fbc80df13f469672b00a8ccu128),
+            F::from(0x7b9f42adf994b51e5b2f015b55e99e4au128),
+            F::from(0x55f5b6576d02be8c3e2932f5d91b97fbu128),
+        ]
+        .to_vec(),
+        [
+            F::from(0xddfe14d1c4d4cf29266f14c78c68b873u128),
+            F::from(0xe9635a25e58b505fad8301946c1f61b0u128),
+            F::from(0xaccd7b7600d566a1bf1987820bdf9a78u128),
+            F::from(0x048844e6b24f997c0130594eeec55fbdu128),
+            F::from(0x83a24a1ac808725388cc7f46f317bc5fu128),
+            F::from(0x8abfe6d12a7bc7fb269b2644ddbc8573u128),
+            F::from(0x817a6f58f4bc1896926f64da6863c941u128),
+            F::from(0xcbe2e7293ccf6f2bc95265ced457c560u128),
+        ]
+        .to_vec(),
+        [
+            F::from(0x9fc24536702b3d04d7f6fd362e14fb64u128),
+            F::from(0xfbebf241f00896b0dbc98cf2e17e2608u128),
+            F::from(0x5681be470a35afadaa159a627baf6fa9u128),
+            F::from(0xe1340d8170fe38b343ec8cf10060de31u128),
+            F::from(0x364800b94a584dbab51b01b1cbe9ed58u128),
+            F::from(0x6f65327d6a8582796679192cb82a3123u128),
+            F::from(0xef790f88ebb88a87d1f0445f9e5ad06cu128),
+            F::from(0x205e7c61cf825f02310da0098bcea896u128),
+        ]
+        .to_vec(),
+        [
+            F::from(0xb97904dc2f156bcd18f6e6de81b1ba01u128),
+            F::from(0x548462771e6fd9ad69402c840b80ba04u128),
+            F::from(0x9bae67e90bc62a38fb4157f6bb9dc802u128),
+            F::from(0xfc4116173694edb5b14c07fd26686c17u128),
+            F::from(0xfbf8618c953ae9ee082661328f4f76a1u128),
+            F::from(0x518059038eb23862ac6d75fb81f4eadbu128),
+            F::from(0xb1a5ea17ed104f9947dd75a108aa24b4u128),
+            F::from(0xdfc6a5070de4f53f7875c3b32ad97d77u128),
+        ]
+        .to_vec(),
+        [
+            F::from(0x16daecb4378f2db61649a8b352c73b7du128),
+            F::from(0xc0fefc5f5577e8745209362a4fc51c56u128),
+            F::from(0x0a460674b71a69e214468160f8a64026u128),
+            F::from(0xbe9c2052390307db46937f85daafa55cu128),
+            F::from(0xbbdd3528fce6818ad8735873d7882328u128),
+            F::from(0xb258bf005aeafbb83b87fcfead4805fcu128),
+            F::from(0x959a70676f961a48e8d5718ebb0677d6u128),
+            F::from(0x1cecf24fea3cde8e58c00720093f0111u128),
+        ]
+        .to_vec(),
+        [
+            F::from(0x8bf907da7f279cf0cf2fcccee0b368a6u128),
+            F::from(0xdb4ed57070ff8de9539e83e707088580u128),
+            F::from(0x75cee2eb2b3a4f9096bb0a56b3ab6517u128),
+            F::from(0x575dbcc77434341b82c27b0b1824cabdu128),
+            F::from(0xa4c3fe85e780fac44cba1c5794c35ffeu128),
+            F::from(0xdccd385d0e4e5456ff86fa26bc4c3dc8u128),
+            F::from(0x14b18c61fda2f11e3a316cf422c0f50bu128),
+            F::from(0x7a7b8a9c02c089042db96fad30e216ebu128),
+        ]
+        .to_vec(),
+        [
+            F::from(0x4b8b3273a329d721d0ebd4641e794587u128),
+            F::from(0xd501fd353cc765fbc1110ecd9d5cc5d5u128),
+            F::from(0xf5a26505fc39d3086071da37fbfe6903u128),
+            F::from(0x7d5ee89c89972f9f580b971a1722cc5du128),
+            F::from(0xfce785583a31100a2be37ad57deedb52u128),
+            F::from(0x663d897985fd429b6360f1f98f8c5476u128),
+            F::from(0xba87cb20c3a29aa55ac7965fc72de13bu128),
+            F::from(0x6675b4aef8a66a0f3b06dcfbaf5343efu128),
+        ]
+        .to_vec(),
+        [
+            F::from(0x3d1621c55adfd126486ff06a49088857u128),
+            F::from(0x828a333b07d6419996d494a9842c559au128),
+            F::from(0xe645dab22b3aeebda945510b7b1767e4u128),
+            F::from(0x1ea17713d3ee05113650d3ebe0764191u128),
+            F::from(0x1e616abd72a0265b10760d08b4360915u128),
+            F::from(0x2ce139c4a58060011e01902e6fb818f4u128),
+            F::from(0xd16ea5e85f96335a3b3676b4787326edu128),
+            F::from(0x603b9252c52e5c61522c8b321777335du128),
+        ]
+        .to_vec(),
+        [
+            F::from(0x174233f41e20256d4620a9fd0fbecfffu128),
+            F::from(0x8e448c0bde3dd0cfa7d4ca7262b61afeu128),
+            F::from(0x0fdd8176cdcf48b347f42d9ae1058ec0u128),
+            F::from(0x66675124578cf02c02c3d84304137926u128),
+            F::from(0xfee9f815c77a2ad4310164271e699042u128),
+            F::from(0xaf7493fb7dbec21834352f9f89653041u128),
+            F::from(0xd5a3618792fb58ba684b912c211c0430u128),
+            F::from(0xf52922da7d9ec4ec95d8577ed3d3f64bu128),
+        ]
+        .to_vec(),
+    ]
+}
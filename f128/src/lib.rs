@@ -1,4 +1,5 @@
 pub mod fp;
 pub mod fp2;
+pub mod hash_f128;
 #[cfg(test)]
 pub mod tests;
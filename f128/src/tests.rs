@@ -1,7 +1,8 @@
 use core::{AsBytes, Serializable, Deserializable, SliceReader};
-use traits::traits::{Field, PrimeField};
+use traits::traits::{Field, Hasher, PrimeField, RescueParameter};
 use crate::fp::Fp;
 use crate::fp2::Fp2;
+use hash::rescue::Rescue;
 //======================= BASE FIELD TESTS =========================
 
 #[test]
@@ -254,3 +255,35 @@ fn power_by(){
     let b = a.power_by(&[2u64]);
     println!("{:?}", b)
 }
+
+//======================= RESCUE TESTS =========================
+
+#[test]
+fn rescue_parameters_check() {
+    // inverse MDS times MDS is the identity
+    let mds = <Fp as RescueParameter<Fp>>::rescue_mds();
+    let inv_mds = <Fp as RescueParameter<Fp>>::rescue_inv_mds();
+    for i in 0..4 {
+        for j in 0..4 {
+            let entry = (0..4).fold(Fp::ZERO, |acc, k| acc + mds[4 * i + k] * inv_mds[4 * k + j]);
+            assert_eq!(entry, if i == j { Fp::ONE } else { Fp::ZERO });
+        }
+    }
+    let ark = <Fp as RescueParameter<Fp>>::rescue_ark();
+    assert!(ark.iter().all(|round| round.len() == 8));
+    // x^3 and x^(1/3) are inverse permutations
+    let x = Fp::random();
+    let inv_alpha = <Fp as RescueParameter<Fp>>::RESCUE_INV_ALPHA;
+    assert_eq!(x.power_by([3]).power_by(inv_alpha.to_words()), x);
+}
+
+#[test]
+fn rescue_hash_vectors() {
+    // outputs of the reference implementation of Rescue-Prime with the same parameters
+    let mut v = vec![Fp::from(1u8), Fp::from(2u8), Fp::from(3u8), Fp::from(4u8)];
+    assert_eq!(<Rescue<Fp>>::hash(&mut v), Fp::from(0x3e030b9f7a261193e277b36df299b3b3u128));
+    let mut v = vec![Fp::ZERO, Fp::ZERO];
+    assert_eq!(<Rescue<Fp>>::hash(&mut v), Fp::from(0x5bd33e2e63b389122900538acdc0de34u128));
+    let mut v = vec![Fp::from(1u8), Fp::from(2u8), Fp::from(3u8)];
+    assert_eq!(<Rescue<Fp>>::hash(&mut v), Fp::from(0x4ba73749ac9011e7bd04759f1925ec92u128));
+}
@@ -11,46 +11,133 @@ pub struct GMIMCHASH<F: Field + PrimeField + GMIMCParameter<F>> {
 impl<F: Field + PrimeField + GMIMCParameter<F>> Hasher for GMIMCHASH<F> {
     type FieldElement = F;
     fn hash(value: &mut Vec<Self::FieldElement>) -> Self::FieldElement {
-        Self::gmimic(value.to_vec())
+        Self::gmimc_hash(value)
     }
 
+    // Compute the gmimc hash and also store intermediate states
     fn hash_and_store_states(
-        _value: &mut [Self::FieldElement],
-        _result: &mut [Self::FieldElement],
-        _hash_data: &mut Vec<Vec<Self::FieldElement>>,
+        value: &mut [Self::FieldElement],
+        result: &mut [Self::FieldElement],
+        hash_data: &mut Vec<Vec<Self::FieldElement>>,
     ) {
-        unimplemented!()
+        Self::gmimc_hash_account(value, result, hash_data);
     }
 }
 
+// Way the input is split into blocks of GMIMC_RATE elements before being absorbed
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GmimcMode {
+    // Input of any length. The first capacity element of the state is set to the length of the
+    // input, which is padded with a one followed by zeros up to a multiple of the rate.
+    Sponge,
+    // Input of exactly NO_OF_COLUMNS elements, absorbed as by earlier versions of this crate so that
+    // existing digests can be reproduced. No padding and no domain separation.
+    Legacy,
+}
+
 // Implementation of Gmimc hash algorithm
 impl<F: Field + PrimeField + GMIMCParameter<F>> GMIMCHASH<F> {
+    // Hashes an input of any length with the GMiMC sponge
+    pub fn gmimc_hash(value: &[F]) -> F {
+        Self::hash_with_mode(value, GmimcMode::Sponge)
+    }
+
+    // Hashes an input of NO_OF_COLUMNS elements the way earlier versions of GMIMCHASH did
+    pub fn gmimic_legacy(trace_value: &[F]) -> F {
+        Self::hash_with_mode(trace_value, GmimcMode::Legacy)
+    }
+
+    // Fixed width hash of earlier versions of GMIMCHASH, kept for existing callers
+    #[deprecated(note = "use gmimic_legacy, or gmimc_hash for inputs of any length")]
     pub fn gmimic(trace_value: Vec<F>) -> F {
+        Self::gmimic_legacy(&trace_value)
+    }
+
+    // Hashes the input, split into blocks as specified by the mode
+    pub fn hash_with_mode(value: &[F], mode: GmimcMode) -> F {
+        Self::absorb(value, mode, None)[0]
+    }
+
+    // Compute the gmimc hash and also store intermediate states. As for Rescue, the state is
+    // recorded after each absorption and after every round of the permutation that follows it.
+    pub fn gmimc_hash_account(value: &[F], result: &mut [F], hash_data: &mut Vec<Vec<F>>) {
+        let state = Self::absorb(value, GmimcMode::Sponge, Some(hash_data));
+        result.copy_from_slice(&state[..1]);
+    }
+
+    // Same as gmimc_hash_account for the legacy fixed width input
+    pub fn gmimc_legacy_hash_account(
+        trace_value: &[F],
+        result: &mut [F],
+        hash_data: &mut Vec<Vec<F>>,
+    ) {
+        let state = Self::absorb(trace_value, GmimcMode::Legacy, Some(hash_data));
+        result.copy_from_slice(&state[..1]);
+    }
+
+    // Absorbs the input block by block, permuting the state after each block, and returns the
+    // final state
+    fn absorb(value: &[F], mode: GmimcMode, mut hash_data: Option<&mut Vec<Vec<F>>>) -> Vec<F> {
         let mut state = vec![F::ZERO; F::GMIMC_STATE_WIDTH];
-        for j in 0..(F::NO_OF_COLUMNS / F::GMIMC_RATE) {
-            for i in 0..(F::GMIMC_RATE) {
-                state[i] += trace_value[i + (j * F::GMIMC_RATE)];
+        let blocks = match mode {
+            GmimcMode::Sponge => {
+                state[F::GMIMC_RATE] = F::from(value.len() as u64);
+                let mut padded = value.to_vec();
+                padded.push(F::ONE);
+                padded.resize(padded.len().div_ceil(F::GMIMC_RATE) * F::GMIMC_RATE, F::ZERO);
+                padded.chunks(F::GMIMC_RATE).map(|block| block.to_vec()).collect()
             }
-            for k in 0..F::GMIMC_NO_OF_ROUNDS {
-                gmimc_apply_round::<F>(&mut state, k);
+            GmimcMode::Legacy => {
+                assert_eq!(
+                    value.len(),
+                    F::NO_OF_COLUMNS,
+                    "the legacy mode of GMiMC hashes exactly NO_OF_COLUMNS elements"
+                );
+                let full_blocks = F::NO_OF_COLUMNS / F::GMIMC_RATE;
+                let mut blocks: Vec<Vec<F>> = value[..full_blocks * F::GMIMC_RATE]
+                    .chunks(F::GMIMC_RATE)
+                    .map(|block| block.to_vec())
+                    .collect();
+                // The last block was read from index NO_OF_COLUMNS - GMIMC_RATE + 1, which is the
+                // start of the remaining elements only when NO_OF_COLUMNS % GMIMC_RATE is
+                // GMIMC_RATE - 1. It is kept as is to reproduce the earlier digests.
+                let start = F::NO_OF_COLUMNS - F::GMIMC_RATE + 1;
+                blocks.push(value[start..start + F::NO_OF_COLUMNS % F::GMIMC_RATE].to_vec());
+                blocks
             }
+        };
+        for block in blocks {
+            for (element, input) in state.iter_mut().zip(block) {
+                *element += input;
+            }
+            gmimc_permutation(&mut state, hash_data.as_deref_mut());
         }
-
-        for i in 0..(F::NO_OF_COLUMNS % F::GMIMC_RATE) {
-            state[i] += trace_value[i + F::NO_OF_COLUMNS - F::GMIMC_RATE + 1];
-        }
-        for k in 0..F::GMIMC_NO_OF_ROUNDS {
-            gmimc_apply_round::<F>(&mut state, k);
-        }
-
-        state[0]
+        state
     }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
+
+// Applies all the rounds to the state. If hash_data is given, the state before the first round and
+// after each round is stored in it.
+pub fn gmimc_permutation<F: Field + PrimeField + GMIMCParameter<F>>(
+    state: &mut [F],
+    mut hash_data: Option<&mut Vec<Vec<F>>>,
+) {
+    if let Some(data) = hash_data.as_deref_mut() {
+        data.push(state.to_vec());
+    }
+    for k in 0..F::GMIMC_NO_OF_ROUNDS {
+        gmimc_apply_round::<F>(state, k);
+        if let Some(data) = hash_data.as_deref_mut() {
+            data.push(state.to_vec());
+        }
+    }
+}
+
 pub fn gmimc_apply_round<F: Field + PrimeField + GMIMCParameter<F>>(
-    state: &mut Vec<F>,
+    state: &mut [F],
     round_number: usize,
 ) {
     let stored_value = state[0];
@@ -0,0 +1,251 @@
+use std::fmt;
+
+use sha2::{Digest, Sha256};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake128, Shake256,
+};
+use traits::traits::{ExtensionOf, PrimeField};
+
+use crate::packing::modulus_bits;
+
+// Security level k in bits of hash_to_field: every element of the prime field is reduced from
+// k more bits than the modulus, so that its distance from the uniform distribution is 2^-k
+pub const SECURITY_BITS: usize = 128;
+
+// Prefix of the hash of domain separation tags longer than 255 bytes
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+// Size in bytes of the output and of the input block of SHA-256
+const SHA256_OUTPUT_BYTES: usize = 32;
+const SHA256_BLOCK_BYTES: usize = 64;
+
+// HASH TO FIELD
+// ================================================================================================
+// hash_to_field of RFC 9380, section 5.2, which deterministically maps a message to elements of
+// a field which are indistinguishable from uniform ones. The message is expanded into uniformly
+// random bytes, which are cut into chunks of field_bytes_len() bytes, and each chunk, read as a
+// big-endian integer, is reduced modulo p. An element of an extension of degree m is made of m
+// consecutive chunks, from its constant coordinate up.
+
+// Functions expanding a message into uniformly random bytes, as specified by RFC 9380,
+// section 5.3
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExpandMessage {
+    // expand_message_xmd with SHA-256
+    XmdSha256,
+    // expand_message_xof with SHAKE128
+    XofShake128,
+    // expand_message_xof with SHAKE256
+    XofShake256,
+}
+
+impl ExpandMessage {
+    // Returns len_in_bytes uniformly random bytes derived from the message and the domain
+    // separation tag
+    pub fn expand(
+        &self,
+        msg: &[u8],
+        dst: &[u8],
+        len_in_bytes: usize,
+    ) -> Result<Vec<u8>, HashToFieldError> {
+        match self {
+            Self::XmdSha256 => expand_message_xmd(msg, dst, len_in_bytes),
+            Self::XofShake128 => expand_message_xof::<Shake128>(msg, dst, len_in_bytes),
+            Self::XofShake256 => expand_message_xof::<Shake256>(msg, dst, len_in_bytes),
+        }
+    }
+}
+
+// Hashes a message to count elements of the prime field F. The domain separation tag must be
+// unique to the application.
+pub fn hash_to_field<F: PrimeField>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+    expander: ExpandMessage,
+) -> Result<Vec<F>, HashToFieldError> {
+    hash_to_extension_field::<F, F>(msg, dst, count, expander)
+}
+
+// Hashes a message to count elements of the extension E of the prime field B
+pub fn hash_to_extension_field<B, E>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+    expander: ExpandMessage,
+) -> Result<Vec<E>, HashToFieldError>
+where
+    B: PrimeField,
+    E: ExtensionOf<B>,
+{
+    let len = field_bytes_len::<B>();
+    let element_len = E::DEGREE * len;
+    let uniform_bytes = expander.expand(msg, dst, count * element_len)?;
+    Ok(uniform_bytes
+        .chunks(element_len)
+        .map(|element| {
+            let coordinates: Vec<B> = element.chunks(len).map(from_be_bytes_reduced).collect();
+            E::from_base_elements(&coordinates)
+        })
+        .collect())
+}
+
+// Returns the number of bytes L each element of the prime field F is reduced from:
+// ceil((ceil(log2(p)) + k) / 8)
+pub fn field_bytes_len<F: PrimeField>() -> usize {
+    (modulus_bits::<F>() + SECURITY_BITS).div_ceil(8)
+}
+
+// Returns the big-endian integer of any length reduced modulo p. The reduction is exact, so
+// integers of field_bytes_len() uniformly random bytes give elements with a negligible bias.
+pub fn from_be_bytes_reduced<F: PrimeField>(bytes: &[u8]) -> F {
+    let two_pow_64 = F::from(1u128 << 64);
+    let (head, tail) = bytes.split_at(bytes.len() % 8);
+    // the words are converted with From<u128>, which every field maps to its internal
+    // representation
+    tail.chunks(8)
+        .fold(F::from(be_u64(head) as u128), |acc, chunk| {
+            acc * two_pow_64 + F::from(be_u64(chunk) as u128)
+        })
+}
+
+// MESSAGE EXPANSION
+// ================================================================================================
+
+// Extendable-output functions for expand_message_xof, with the security level k in bits which
+// sets the length of the hash of a long domain separation tag
+pub trait Xof: Default + Update + ExtendableOutput {
+    const SECURITY_BITS: usize;
+}
+
+impl Xof for Shake128 {
+    const SECURITY_BITS: usize = 128;
+}
+
+impl Xof for Shake256 {
+    const SECURITY_BITS: usize = 256;
+}
+
+// expand_message_xmd of RFC 9380, section 5.3.1, with SHA-256. Domain separation tags longer
+// than 255 bytes are first hashed as specified in section 5.3.3.
+pub fn expand_message_xmd(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, HashToFieldError> {
+    let ell = len_in_bytes.div_ceil(SHA256_OUTPUT_BYTES);
+    if ell > 255 || len_in_bytes > u16::MAX as usize {
+        return Err(HashToFieldError::OutputTooLong(len_in_bytes));
+    }
+    if dst.is_empty() {
+        return Err(HashToFieldError::EmptyDst);
+    }
+    let long_dst;
+    let dst = if dst.len() > 255 {
+        long_dst = Sha256::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(dst)
+            .finalize();
+        &long_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let b0 = Sha256::new()
+        .chain_update([0u8; SHA256_BLOCK_BYTES])
+        .chain_update(msg)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+    let mut bi = Sha256::new()
+        .chain_update(b0)
+        .chain_update([1u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * SHA256_OUTPUT_BYTES);
+    uniform_bytes.extend_from_slice(&bi);
+    for i in 2..=ell {
+        // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+        let xored: Vec<u8> = b0.iter().zip(bi.iter()).map(|(x, y)| x ^ y).collect();
+        bi = Sha256::new()
+            .chain_update(xored)
+            .chain_update([i as u8])
+            .chain_update(dst)
+            .chain_update(dst_len)
+            .finalize();
+        uniform_bytes.extend_from_slice(&bi);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    Ok(uniform_bytes)
+}
+
+// expand_message_xof of RFC 9380, section 5.3.2. Domain separation tags longer than 255 bytes
+// are first hashed to ceil(2 * k / 8) bytes as specified in section 5.3.3.
+pub fn expand_message_xof<X: Xof>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, HashToFieldError> {
+    if len_in_bytes > u16::MAX as usize {
+        return Err(HashToFieldError::OutputTooLong(len_in_bytes));
+    }
+    if dst.is_empty() {
+        return Err(HashToFieldError::EmptyDst);
+    }
+    let long_dst;
+    let dst = if dst.len() > 255 {
+        let mut hasher = X::default();
+        hasher.update(OVERSIZE_DST_PREFIX);
+        hasher.update(dst);
+        long_dst = hasher.finalize_boxed((2 * X::SECURITY_BITS).div_ceil(8));
+        &long_dst[..]
+    } else {
+        dst
+    };
+
+    let mut hasher = X::default();
+    hasher.update(msg);
+    hasher.update(&(len_in_bytes as u16).to_be_bytes());
+    hasher.update(dst);
+    hasher.update(&[dst.len() as u8]);
+    let mut uniform_bytes = vec![0u8; len_in_bytes];
+    hasher.finalize_xof().read(&mut uniform_bytes);
+    Ok(uniform_bytes)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+// Returns the big-endian integer of at most 8 bytes
+fn be_u64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |acc, &byte| (acc << 8) | byte as u64)
+}
+
+// ERRORS
+// ================================================================================================
+
+// Errors which can occur when expanding a message
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HashToFieldError {
+    // The number of bytes requested exceeds what the expander can output
+    OutputTooLong(usize),
+    // The domain separation tag is empty
+    EmptyDst,
+}
+
+impl fmt::Display for HashToFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutputTooLong(len) => {
+                write!(f, "cannot expand a message to {len} bytes")
+            }
+            Self::EmptyDst => write!(f, "the domain separation tag must not be empty"),
+        }
+    }
+}
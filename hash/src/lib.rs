@@ -2,4 +2,10 @@ pub mod rescue;
 pub mod poseidon_6_5;
 pub mod poseidon_4_2;
 pub mod poseidon_impl;
-pub mod gmimc;
\ No newline at end of file
+pub mod gmimc;
+pub mod sponge;
+pub mod transcript;
+pub mod merkle;
+pub mod sparse_merkle;
+pub mod packing;
+pub mod hash_to_field;
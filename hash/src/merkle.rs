@@ -0,0 +1,392 @@
+use std::{collections::BTreeMap, fmt, marker::PhantomData};
+
+#[cfg(feature = "concurrent")]
+use rayon::prelude::*;
+use traits::traits::Hasher;
+
+use crate::sponge::{Permutation, Sponge};
+
+// MERKLE HASHERS
+// ================================================================================================
+
+// Hash function of the nodes of a Merkle tree. Every Hasher is one, with digests of a single field
+// element; WideHasher gives digests of several elements for fields too small for one element to
+// resist collisions.
+pub trait MerkleHasher {
+    type Digest: Copy + fmt::Debug + Eq + Send + Sync;
+    // Returns the node of the bottom level above the leaf
+    fn hash_leaf(leaf: &Self::Digest) -> Self::Digest;
+    // Returns the parent of the ARITY children
+    fn hash_children(children: &[Self::Digest]) -> Self::Digest;
+}
+
+impl<H: Hasher> MerkleHasher for H {
+    type Digest = H::FieldElement;
+
+    fn hash_leaf(leaf: &H::FieldElement) -> H::FieldElement {
+        H::hash(&mut vec![H::FieldElement::from(TAG_LEAF), *leaf])
+    }
+
+    fn hash_children(children: &[H::FieldElement]) -> H::FieldElement {
+        H::hash(&mut children.to_vec())
+    }
+}
+
+// Merkle hasher whose digests are N elements squeezed from a sponge over the permutation P. A
+// digest of N elements of a field of b bits resists collisions up to about 2^(N * b / 2) hashes,
+// so 4 elements of a 64-bit field or 2 of a 128-bit field match a single element of a 256-bit one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WideHasher<P, const N: usize>(PhantomData<P>);
+
+impl<P: Permutation, const N: usize> WideHasher<P, N> {
+    // Returns the digest of the elements
+    pub fn hash(elements: &[P::Field]) -> [P::Field; N] {
+        let mut sponge = Sponge::<P>::new();
+        sponge.absorb(elements);
+        sponge
+            .squeeze(N)
+            .try_into()
+            .expect("the sponge squeezes N elements")
+    }
+}
+
+impl<P: Permutation, const N: usize> MerkleHasher for WideHasher<P, N> {
+    type Digest = [P::Field; N];
+
+    fn hash_leaf(leaf: &[P::Field; N]) -> [P::Field; N] {
+        let mut elements = vec![P::Field::from(TAG_LEAF)];
+        elements.extend_from_slice(leaf);
+        Self::hash(&elements)
+    }
+
+    fn hash_children(children: &[[P::Field; N]]) -> [P::Field; N] {
+        Self::hash(&children.concat())
+    }
+}
+
+// MERKLE TREE
+// ================================================================================================
+
+// Merkle tree over the digests of H in which every node is the hash of its ARITY children. The
+// number of leaves must be a power of ARITY. Only arities 2 and 4 are supported; with arity 4 the
+// children of a node fill the state of the width 4 instance of Poseidon. The nodes of the bottom
+// level are the hashes of the leaves prefixed with TAG_LEAF, so that the value of an internal node
+// can't be opened as a leaf.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerkleTree<H: MerkleHasher, const ARITY: usize = 2> {
+    leaves: Vec<H::Digest>,
+    // levels[0] holds the hashes of the leaves and the last level holds the root
+    levels: Vec<Vec<H::Digest>>,
+    _hasher: PhantomData<H>,
+}
+
+pub type BinaryMerkleTree<H> = MerkleTree<H, 2>;
+pub type QuaternaryMerkleTree<H> = MerkleTree<H, 4>;
+
+// Tag hashed before every leaf. The other nodes are hashes of ARITY hashes, none of which can be
+// made equal to the tag, so the hashes of leaves and of internal nodes can't be confused.
+pub(crate) const TAG_LEAF: u64 = 1;
+
+// Authentication path of a leaf: for every level from the leaves up to the children of the root,
+// the ARITY - 1 siblings of the node on the path, in order of position
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerklePath<F> {
+    pub siblings: Vec<Vec<F>>,
+}
+
+// Proof opening several leaves at once. For every level from the leaves up to the children of the
+// root, nodes holds the values that can't be computed from the opened leaves, in order of position.
+// The depth is checked against the one of the tree the verifier expects.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchMerkleProof<F> {
+    pub depth: usize,
+    pub nodes: Vec<Vec<F>>,
+}
+
+impl<H: MerkleHasher, const ARITY: usize> MerkleTree<H, ARITY> {
+    // Builds the tree over the leaves. Under the concurrent feature the nodes of each level are
+    // hashed in parallel.
+    pub fn new(leaves: Vec<H::Digest>) -> Result<Self, MerkleTreeError> {
+        check_arity::<ARITY>()?;
+        if leaves.len() < ARITY {
+            return Err(MerkleTreeError::TooFewLeaves(ARITY, leaves.len()));
+        }
+        if exact_log(leaves.len(), ARITY).is_none() {
+            return Err(MerkleTreeError::NumberOfLeavesNotPowerOfArity(ARITY, leaves.len()));
+        }
+
+        #[cfg(feature = "concurrent")]
+        let hashes: Vec<H::Digest> = leaves.par_iter().map(H::hash_leaf).collect();
+        #[cfg(not(feature = "concurrent"))]
+        let hashes: Vec<H::Digest> = leaves.iter().map(H::hash_leaf).collect();
+        let mut levels = vec![hashes];
+        while levels[levels.len() - 1].len() > 1 {
+            let level = &levels[levels.len() - 1];
+            #[cfg(feature = "concurrent")]
+            let parents = level.par_chunks(ARITY).map(H::hash_children).collect();
+            #[cfg(not(feature = "concurrent"))]
+            let parents = level.chunks(ARITY).map(H::hash_children).collect();
+            levels.push(parents);
+        }
+        Ok(Self {
+            leaves,
+            levels,
+            _hasher: PhantomData,
+        })
+    }
+
+    // Returns the root of the tree
+    pub fn root(&self) -> H::Digest {
+        self.levels[self.depth()][0]
+    }
+
+    // Returns the number of levels below the root
+    pub fn depth(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    // Returns the leaves of the tree
+    pub fn leaves(&self) -> &[H::Digest] {
+        &self.leaves
+    }
+
+    // Returns the authentication path of the leaf at the index
+    pub fn prove(&self, index: usize) -> Result<MerklePath<H::Digest>, MerkleTreeError> {
+        if index >= self.leaves().len() {
+            return Err(MerkleTreeError::LeafIndexOutOfBounds(self.leaves().len(), index));
+        }
+        let mut position = index;
+        let mut siblings = Vec::with_capacity(self.depth());
+        for level in &self.levels[..self.depth()] {
+            let first = position - position % ARITY;
+            siblings.push(
+                (first..first + ARITY)
+                    .filter(|&i| i != position)
+                    .map(|i| level[i])
+                    .collect(),
+            );
+            position /= ARITY;
+        }
+        Ok(MerklePath { siblings })
+    }
+
+    // Checks that the path authenticates the leaf at the index against the root of a tree of
+    // num_leaves leaves
+    pub fn verify(
+        root: H::Digest,
+        num_leaves: usize,
+        index: usize,
+        leaf: H::Digest,
+        path: &MerklePath<H::Digest>,
+    ) -> Result<(), MerkleTreeError> {
+        let depth = tree_depth::<ARITY>(num_leaves)?;
+        if index >= num_leaves {
+            return Err(MerkleTreeError::LeafIndexOutOfBounds(num_leaves, index));
+        }
+        if path.siblings.len() != depth {
+            return Err(MerkleTreeError::InvalidProof);
+        }
+        let mut position = index;
+        let mut node = H::hash_leaf(&leaf);
+        for siblings in &path.siblings {
+            if siblings.len() != ARITY - 1 {
+                return Err(MerkleTreeError::InvalidProof);
+            }
+            let mut children = siblings.clone();
+            children.insert(position % ARITY, node);
+            node = H::hash_children(&children);
+            position /= ARITY;
+        }
+        if node != root {
+            return Err(MerkleTreeError::InvalidProof);
+        }
+        Ok(())
+    }
+
+    // Returns a proof opening the leaves at the indexes, in which the nodes shared by several paths
+    // appear once and the nodes computable from the opened leaves don't appear
+    pub fn prove_batch(
+        &self,
+        indexes: &[usize],
+    ) -> Result<BatchMerkleProof<H::Digest>, MerkleTreeError> {
+        if indexes.is_empty() {
+            return Err(MerkleTreeError::TooFewLeafIndexes);
+        }
+        let mut positions: Vec<usize> = indexes.to_vec();
+        if let Some(&index) = positions.iter().find(|&&i| i >= self.leaves().len()) {
+            return Err(MerkleTreeError::LeafIndexOutOfBounds(self.leaves().len(), index));
+        }
+        positions.sort_unstable();
+        positions.dedup();
+
+        let mut nodes = Vec::with_capacity(self.depth());
+        for level in &self.levels[..self.depth()] {
+            let mut level_nodes = Vec::new();
+            let mut parents = Vec::new();
+            for &position in &positions {
+                let parent = position / ARITY;
+                if parents.last() == Some(&parent) {
+                    continue;
+                }
+                parents.push(parent);
+                for (i, &node) in level.iter().enumerate().skip(parent * ARITY).take(ARITY) {
+                    if positions.binary_search(&i).is_err() {
+                        level_nodes.push(node);
+                    }
+                }
+            }
+            nodes.push(level_nodes);
+            positions = parents;
+        }
+        Ok(BatchMerkleProof {
+            depth: self.depth(),
+            nodes,
+        })
+    }
+
+    // Checks that the proof opens the leaves at the indexes against the root of a tree of
+    // num_leaves leaves. leaves[i] is the value of the leaf at indexes[i].
+    pub fn verify_batch(
+        root: H::Digest,
+        num_leaves: usize,
+        indexes: &[usize],
+        leaves: &[H::Digest],
+        proof: &BatchMerkleProof<H::Digest>,
+    ) -> Result<(), MerkleTreeError> {
+        let depth = tree_depth::<ARITY>(num_leaves)?;
+        if indexes.is_empty() {
+            return Err(MerkleTreeError::TooFewLeafIndexes);
+        }
+        if indexes.len() != leaves.len() {
+            return Err(MerkleTreeError::NumberOfLeavesMismatch(indexes.len(), leaves.len()));
+        }
+        if proof.depth != depth || proof.nodes.len() != depth {
+            return Err(MerkleTreeError::InvalidProof);
+        }
+        let mut known = BTreeMap::new();
+        for (&index, &leaf) in indexes.iter().zip(leaves) {
+            if index >= num_leaves {
+                return Err(MerkleTreeError::LeafIndexOutOfBounds(num_leaves, index));
+            }
+            // the same leaf may be opened several times, but always with the same value
+            if *known.entry(index).or_insert(leaf) != leaf {
+                return Err(MerkleTreeError::InvalidProof);
+            }
+        }
+        let mut known: BTreeMap<usize, H::Digest> = known
+            .into_iter()
+            .map(|(index, leaf)| (index, H::hash_leaf(&leaf)))
+            .collect();
+
+        for level_nodes in &proof.nodes {
+            let mut level_nodes = level_nodes.iter();
+            let mut parents = BTreeMap::new();
+            for &position in known.keys() {
+                let parent = position / ARITY;
+                if parents.contains_key(&parent) {
+                    continue;
+                }
+                let mut children = Vec::with_capacity(ARITY);
+                for i in parent * ARITY..(parent + 1) * ARITY {
+                    match known.get(&i) {
+                        Some(&node) => children.push(node),
+                        None => match level_nodes.next() {
+                            Some(&node) => children.push(node),
+                            None => return Err(MerkleTreeError::InvalidProof),
+                        },
+                    }
+                }
+                parents.insert(parent, H::hash_children(&children));
+            }
+            if level_nodes.next().is_some() {
+                return Err(MerkleTreeError::InvalidProof);
+            }
+            known = parents;
+        }
+        if known.get(&0) != Some(&root) {
+            return Err(MerkleTreeError::InvalidProof);
+        }
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn check_arity<const ARITY: usize>() -> Result<(), MerkleTreeError> {
+    if ARITY != 2 && ARITY != 4 {
+        return Err(MerkleTreeError::UnsupportedArity(ARITY));
+    }
+    Ok(())
+}
+
+// Returns the depth of a tree of num_leaves leaves, which must be a power of ARITY larger than one
+fn tree_depth<const ARITY: usize>(num_leaves: usize) -> Result<usize, MerkleTreeError> {
+    check_arity::<ARITY>()?;
+    if num_leaves < ARITY {
+        return Err(MerkleTreeError::TooFewLeaves(ARITY, num_leaves));
+    }
+    exact_log(num_leaves, ARITY)
+        .ok_or(MerkleTreeError::NumberOfLeavesNotPowerOfArity(ARITY, num_leaves))
+}
+
+// Returns k such that n = base^k, if there is one
+fn exact_log(n: usize, base: usize) -> Option<usize> {
+    let mut power = 1usize;
+    let mut k = 0;
+    while power < n {
+        power = power.checked_mul(base)?;
+        k += 1;
+    }
+    (power == n).then_some(k)
+}
+
+// ERRORS
+// ================================================================================================
+
+// Errors which can occur when building a Merkle tree or checking its proofs
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MerkleTreeError {
+    // The arity is neither 2 nor 4
+    UnsupportedArity(usize),
+    // The tree needs at least ARITY leaves
+    TooFewLeaves(usize, usize),
+    // The number of leaves is not a power of the arity
+    NumberOfLeavesNotPowerOfArity(usize, usize),
+    // A leaf index is not smaller than the number of leaves
+    LeafIndexOutOfBounds(usize, usize),
+    // A batch proof needs at least one leaf index
+    TooFewLeafIndexes,
+    // The number of leaf indexes differs from the number of leaf values
+    NumberOfLeavesMismatch(usize, usize),
+    // The proof does not open the leaves against the root
+    InvalidProof,
+}
+
+impl fmt::Display for MerkleTreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedArity(arity) => {
+                write!(f, "Merkle trees of arity {arity} are not supported, the arity must be 2 or 4")
+            }
+            Self::TooFewLeaves(expected, actual) => {
+                write!(f, "a Merkle tree must have at least {expected} leaves, but {actual} were provided")
+            }
+            Self::NumberOfLeavesNotPowerOfArity(arity, actual) => {
+                write!(f, "the number of leaves must be a power of {arity}, but {actual} were provided")
+            }
+            Self::LeafIndexOutOfBounds(expected, actual) => {
+                write!(f, "a leaf index cannot exceed {}, but was {actual}", expected - 1)
+            }
+            Self::TooFewLeafIndexes => {
+                write!(f, "at least one leaf index must be provided")
+            }
+            Self::NumberOfLeavesMismatch(expected, actual) => {
+                write!(f, "{expected} leaf indexes were provided with {actual} leaf values")
+            }
+            Self::InvalidProof => {
+                write!(f, "Merkle proof is invalid")
+            }
+        }
+    }
+}
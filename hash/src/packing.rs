@@ -0,0 +1,187 @@
+use std::{fmt, marker::PhantomData};
+
+use crypto_bigint::U256;
+use traits::traits::{Field, Hasher, PrimeField};
+
+// PACKING LAYOUT
+// ================================================================================================
+
+// Layout packing records of small values into as few field elements as possible. Each value of
+// the record is declared with a bit width; the values are placed in order from the least
+// significant bits of an element, and a new element is started when the next value does not fit
+// in the capacity_bits() bits of the current one. A value never straddles two elements, so every
+// value must have at most capacity_bits() bits and at most 256 bits.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PackingLayout<F: Field + PrimeField> {
+    widths: Vec<usize>,
+    // for each value, the index of the element it is packed in and its offset in that element
+    positions: Vec<(usize, usize)>,
+    num_elements: usize,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field + PrimeField> PackingLayout<F> {
+    // Returns the layout of records made of values of the given bit widths
+    pub fn new(widths: &[usize]) -> Result<Self, PackingError> {
+        let capacity = capacity_bits::<F>();
+        let mut positions = Vec::with_capacity(widths.len());
+        let (mut element, mut offset) = (0, 0);
+        for &width in widths {
+            if width == 0 || width > capacity || width > 256 {
+                return Err(PackingError::InvalidWidth(width));
+            }
+            if offset + width > capacity {
+                element += 1;
+                offset = 0;
+            }
+            positions.push((element, offset));
+            offset += width;
+        }
+        Ok(Self {
+            widths: widths.to_vec(),
+            positions,
+            num_elements: if widths.is_empty() { 0 } else { element + 1 },
+            _field: PhantomData,
+        })
+    }
+
+    pub fn widths(&self) -> &[usize] {
+        &self.widths
+    }
+
+    // Returns the number of field elements a record is packed into
+    pub fn num_elements(&self) -> usize {
+        self.num_elements
+    }
+
+    // Packs the values of a record. Each value must fit in its width.
+    pub fn pack(&self, values: &[F]) -> Result<Vec<F>, PackingError> {
+        if values.len() != self.widths.len() {
+            return Err(PackingError::WrongNumberOfValues(self.widths.len(), values.len()));
+        }
+        // 2 is built from ONE as from(u64) is not a Montgomery conversion in every field
+        let two = F::ONE + F::ONE;
+        let mut elements = vec![F::ZERO; self.num_elements];
+        for (i, (&value, &(element, offset))) in values.iter().zip(&self.positions).enumerate() {
+            if bit_length(&value.to_canonical_words()) > self.widths[i] {
+                return Err(PackingError::ValueOutOfRange(i, self.widths[i]));
+            }
+            elements[element] += value * two.power_by([offset as u64]);
+        }
+        Ok(elements)
+    }
+
+    // Keeps the bits of each value which fit in its width, so that the record can be packed
+    pub fn truncate(&self, values: &[F]) -> Vec<F> {
+        values
+            .iter()
+            .zip(&self.widths)
+            .map(|(value, &width)| F::from(extract_bits(&value.to_canonical_words(), 0, width)))
+            .collect()
+    }
+
+    // Recovers the values of a record from its packed elements. Elements with bits set outside of
+    // the values are rejected, so that every record has a single packed form.
+    pub fn unpack(&self, elements: &[F]) -> Result<Vec<F>, PackingError> {
+        if elements.len() != self.num_elements {
+            return Err(PackingError::WrongNumberOfElements(self.num_elements, elements.len()));
+        }
+        let words: Vec<Vec<u64>> =
+            elements.iter().map(|element| element.to_canonical_words()).collect();
+        let mut used_bits = vec![0; self.num_elements];
+        let mut values = Vec::with_capacity(self.widths.len());
+        for (&width, &(element, offset)) in self.widths.iter().zip(&self.positions) {
+            values.push(F::from(extract_bits(&words[element], offset, width)));
+            used_bits[element] = offset + width;
+        }
+        for (i, words) in words.iter().enumerate() {
+            if bit_length(words) > used_bits[i] {
+                return Err(PackingError::InvalidPackedElement(i));
+            }
+        }
+        Ok(values)
+    }
+
+    // Packs the record and hashes the packed elements
+    pub fn hash<H: Hasher<FieldElement = F>>(&self, values: &[F]) -> Result<F, PackingError> {
+        Ok(H::hash(&mut self.pack(values)?))
+    }
+}
+
+// Returns the number of bits of the modulus of F, read from its hexadecimal representation
+pub fn modulus_bits<F: PrimeField>() -> usize {
+    let hex = F::MODULUS.trim_start_matches("0x").trim_start_matches('0');
+    match hex.chars().next().and_then(|digit| digit.to_digit(16)) {
+        Some(digit) => 4 * (hex.len() - 1) + (32 - digit.leading_zeros()) as usize,
+        None => 0,
+    }
+}
+
+// Returns the number of bits of the largest integers which are all smaller than the modulus of F.
+// NUM_BITS can't be used for this, as it is the size in bits of the representation of F.
+pub fn capacity_bits<F: PrimeField>() -> usize {
+    modulus_bits::<F>() - 1
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+// Returns the number of bits of the little-endian integer
+fn bit_length(words: &[u64]) -> usize {
+    match words.iter().rposition(|&word| word != 0) {
+        Some(i) => 64 * i + 64 - words[i].leading_zeros() as usize,
+        None => 0,
+    }
+}
+
+// Returns the width bits of the little-endian integer starting at the offset
+fn extract_bits(words: &[u64], offset: usize, width: usize) -> U256 {
+    let mut limbs = [0u64; 4];
+    for i in 0..width {
+        let bit = offset + i;
+        if bit / 64 < words.len() && (words[bit / 64] >> (bit % 64)) & 1 == 1 {
+            limbs[i / 64] |= 1 << (i % 64);
+        }
+    }
+    U256::from_words(limbs)
+}
+
+// ERRORS
+// ================================================================================================
+
+// Errors which can occur when packing or unpacking a record
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PackingError {
+    // A width is 0 or larger than the bits available in an element
+    InvalidWidth(usize),
+    // The number of values differs from the number of widths of the layout
+    WrongNumberOfValues(usize, usize),
+    // The value at the index does not fit in its width
+    ValueOutOfRange(usize, usize),
+    // The number of packed elements differs from the one of the layout
+    WrongNumberOfElements(usize, usize),
+    // The packed element at the index has bits set outside of the values
+    InvalidPackedElement(usize),
+}
+
+impl fmt::Display for PackingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidWidth(width) => {
+                write!(f, "a width of {width} bits cannot be packed in a field element")
+            }
+            Self::WrongNumberOfValues(expected, actual) => {
+                write!(f, "expected a record of {expected} values, but {actual} were provided")
+            }
+            Self::ValueOutOfRange(index, width) => {
+                write!(f, "value {index} of the record does not fit in {width} bits")
+            }
+            Self::WrongNumberOfElements(expected, actual) => {
+                write!(f, "expected {expected} packed elements, but {actual} were provided")
+            }
+            Self::InvalidPackedElement(index) => {
+                write!(f, "packed element {index} has bits set outside of the values")
+            }
+        }
+    }
+}
@@ -10,43 +10,48 @@ pub fn poseidon2<F: Field + PrimeField+ PoseidonParameter<F>>(value: &mut Vec<F>
         for j in 0..F::RATE_4_2 {
             state[j] += value[i + j];
         }
-        // Linear layer at beginning
-        apply_mds(&mut state);
-        for round in 0..F::ROUNDS_F_BEGINNING {
-            add_constants(&mut state, round * F::STATE_WIDTH_4_2);
-            // full round
-            apply_sbox(&mut state);
-            //  MDS for full round
-            apply_mds(&mut state);
-        }
-        for round in F::ROUNDS_F_BEGINNING..F::ROUNDS_F_BEGINNING + F::PARTIAL_ROUNDS_4_2 {
-            add_constants(&mut state, round * F::STATE_WIDTH_4_2);
-            // partial round
+        permutation(&mut state);
+        i += F::RATE_4_2;
+    }
+    state[0]
+}
 
-            if F::ALPHA == 3 {
-                state[F::STATE_WIDTH_4_2 - 1] =
-                    state[F::STATE_WIDTH_4_2 - 1].square() * state[F::STATE_WIDTH_4_2 - 1];
-            } else if F::ALPHA == 5 {
-                state[F::STATE_WIDTH_4_2 - 1] =
-                    state[F::STATE_WIDTH_4_2 - 1].square().square() * state[F::STATE_WIDTH_4_2 - 1];
-            } else {
-                state[F::STATE_WIDTH_4_2 - 1] =
-                    state[F::STATE_WIDTH_4_2 - 1].power_by([F::ALPHA, 0, 0, 0]);
-            }
-            // MDS for partial round
-            apply_internal_mds(&mut state);
-        }
+// Applies the Poseidon permutation to the state
+pub fn permutation<F: Field + PrimeField + PoseidonParameter<F>>(state: &mut [F]) {
+    // Linear layer at beginning
+    apply_mds(state);
+    for round in 0..F::ROUNDS_F_BEGINNING {
+        add_constants(state, round * F::STATE_WIDTH_4_2);
+        // full round
+        apply_sbox(state);
+        //  MDS for full round
+        apply_mds(state);
+    }
+    for round in F::ROUNDS_F_BEGINNING..F::ROUNDS_F_BEGINNING + F::PARTIAL_ROUNDS_4_2 {
+        add_constants(state, round * F::STATE_WIDTH_4_2);
+        // partial round
 
-        for round in F::ROUNDS_F_BEGINNING + F::PARTIAL_ROUNDS_4_2..F::TOTAL_NUM_ROUNDS_4_2 {
-            add_constants(&mut state, round * F::STATE_WIDTH_4_2);
-            // full round
-            apply_sbox(&mut state);
-            //  MDS for full round
-            apply_mds(&mut state);
+        if F::ALPHA == 3 {
+            state[F::STATE_WIDTH_4_2 - 1] =
+                state[F::STATE_WIDTH_4_2 - 1].square() * state[F::STATE_WIDTH_4_2 - 1];
+        } else if F::ALPHA == 5 {
+            state[F::STATE_WIDTH_4_2 - 1] =
+                state[F::STATE_WIDTH_4_2 - 1].square().square() * state[F::STATE_WIDTH_4_2 - 1];
+        } else {
+            state[F::STATE_WIDTH_4_2 - 1] =
+                state[F::STATE_WIDTH_4_2 - 1].power_by([F::ALPHA, 0, 0, 0]);
         }
-        i += F::RATE_4_2;
+        // MDS for partial round
+        apply_internal_mds(state);
+    }
+
+    for round in F::ROUNDS_F_BEGINNING + F::PARTIAL_ROUNDS_4_2..F::TOTAL_NUM_ROUNDS_4_2 {
+        add_constants(state, round * F::STATE_WIDTH_4_2);
+        // full round
+        apply_sbox(state);
+        //  MDS for full round
+        apply_mds(state);
     }
-    state[0]
 }
 
 // HELPER FUNCTIONS
@@ -13,44 +13,49 @@ pub fn poseidon2<F: Field + PrimeField+ PoseidonParameter<F>>(value: &mut Vec<F>
         for j in 0..F::RATE_6_5 {
             state[j] += value[i + j];
         }
-        // Linear layer at beginning
-        apply_mds(&mut state);
-        for round in 0..F::ROUNDS_F_BEGINNING {
-            add_constants(&mut state, round * F::STATE_WIDTH_6_5);
-            // full round
-            apply_sbox(&mut state);
-            //  MDS for full round
-            apply_mds(&mut state);
-        }
-        for round in F::ROUNDS_F_BEGINNING..F::ROUNDS_F_BEGINNING + F::PARTIAL_ROUNDS_6_5 {
-            add_constants(&mut state, round * F::STATE_WIDTH_6_5);
-            // partial round
-            if F::ALPHA == 3 {
-                state[F::STATE_WIDTH_6_5 - 1] =
-                    state[F::STATE_WIDTH_6_5 - 1].square() * state[F::STATE_WIDTH_6_5 - 1];
-            } else if F::ALPHA == 5 {
-                state[F::STATE_WIDTH_6_5 - 1] =
-                    state[F::STATE_WIDTH_6_5 - 1].square().square() * state[F::STATE_WIDTH_6_5 - 1];
-            } else {
-                state[F::STATE_WIDTH_6_5 - 1] =
-                    state[F::STATE_WIDTH_6_5 - 1].power_by([F::ALPHA, 0, 0, 0]);
-            }
-            // MDS for partial round
-            apply_internal_mds(&mut state);
-        }
-
-        for round in F::ROUNDS_F_BEGINNING + F::PARTIAL_ROUNDS_6_5..F::TOTAL_NUM_ROUNDS_6_5 {
-            add_constants(&mut state, round * F::STATE_WIDTH_6_5);
-            // full round
-            apply_sbox(&mut state);
-            //  MDS for full round
-            apply_mds(&mut state);
-        }
+        permutation(&mut state);
         i += F::RATE_6_5;
     }
     state[0]
 }
 
+// Applies the Poseidon permutation to the state
+pub fn permutation<F: Field + PrimeField + PoseidonParameter<F>>(state: &mut [F]) {
+    // Linear layer at beginning
+    apply_mds(state);
+    for round in 0..F::ROUNDS_F_BEGINNING {
+        add_constants(state, round * F::STATE_WIDTH_6_5);
+        // full round
+        apply_sbox(state);
+        //  MDS for full round
+        apply_mds(state);
+    }
+    for round in F::ROUNDS_F_BEGINNING..F::ROUNDS_F_BEGINNING + F::PARTIAL_ROUNDS_6_5 {
+        add_constants(state, round * F::STATE_WIDTH_6_5);
+        // partial round
+        if F::ALPHA == 3 {
+            state[F::STATE_WIDTH_6_5 - 1] =
+                state[F::STATE_WIDTH_6_5 - 1].square() * state[F::STATE_WIDTH_6_5 - 1];
+        } else if F::ALPHA == 5 {
+            state[F::STATE_WIDTH_6_5 - 1] =
+                state[F::STATE_WIDTH_6_5 - 1].square().square() * state[F::STATE_WIDTH_6_5 - 1];
+        } else {
+            state[F::STATE_WIDTH_6_5 - 1] =
+                state[F::STATE_WIDTH_6_5 - 1].power_by([F::ALPHA, 0, 0, 0]);
+        }
+        // MDS for partial round
+        apply_internal_mds(state);
+    }
+
+    for round in F::ROUNDS_F_BEGINNING + F::PARTIAL_ROUNDS_6_5..F::TOTAL_NUM_ROUNDS_6_5 {
+        add_constants(state, round * F::STATE_WIDTH_6_5);
+        // full round
+        apply_sbox(state);
+        //  MDS for full round
+        apply_mds(state);
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 #[inline(always)]
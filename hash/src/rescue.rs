@@ -1,6 +1,7 @@
-use crypto_bigint::U256;
 use traits::traits::{Field, Hasher, PrimeField, RescueParameter};
 
+use crate::packing::PackingLayout;
+
 // HASH FUNCTION
 // ================================================================================================
 
@@ -43,9 +44,7 @@ impl<F: Field + PrimeField + RescueParameter<F>> Rescue<F> {
             for j in 0..F::RESCUE_RATE {
                 state[j] += value[i + j];
             }
-            for k in 0..F::RESCUE_NO_OF_ROUNDS {
-                apply_round::<F>(&mut state, k);
-            }
+            permutation::<F>(&mut state);
             i += F::RESCUE_RATE;
         }
         state[0]
@@ -74,40 +73,17 @@ impl<F: Field + PrimeField + RescueParameter<F>> Rescue<F> {
         result.copy_from_slice(&state[..1]);
     }
 
-    // Compute the rescue hash and also store intermediate states.
+    // Compute the rescue hash of an account and also store intermediate states. value holds the
+    // address, the balance and the nonce; the balance and the nonce are truncated to 100 bits and
+    // packed in one element, the nonce in the least significant bits.
     pub fn rescue_modified_hash(value: Vec<F>, result: &mut [F], hash_data: &mut Vec<Vec<F>>) {
         let mut state = vec![F::ZERO; F::RESCUE_STATE_WIDTH];
-        let amount = value[1];
-        //default nonce
-        let nonce = value[2];
-        //trim 100bits of the balance
-        let mut amount_bits = Vec::new();
-        for i in 0..100 {
-            // amount_bits.push(amount.0.bit(i));
-            let a = amount.to_words();
-            amount_bits.push(U256::from_words([a[0], a[1], a[2], a[3]]).bit(i));
-        }
-        //trim 100 bit of the nonce.
-        let mut nonce_bits = Vec::new();
-        for i in 0..100 {
-            let n = nonce.to_words();
-            nonce_bits.push(U256::from_words([n[0], n[1], n[2], n[3]]).bit(i));
-        }
-        //Concatenates the bits of the balance and nonce
-        // where nonce bits are LSB and balance bits are MSB
-        let mut concatenated_bits = [0_u64; 200];
-        for i in 0..100 {
-            concatenated_bits[i] = nonce_bits[i];
-            concatenated_bits[i + 100] = amount_bits[i];
-        }
-        // Convert bits into limbs.
-        let mut limbs = [0_u64; 4];
-        for i in 0..200 {
-            limbs[i / 64] =
-                limbs[i / 64] + (2_u64.pow((i % 64).try_into().unwrap()) * concatenated_bits[i]);
-        }
+        let layout = PackingLayout::<F>::new(&[100, 100]).expect("100 bits fit in a field element");
+        let packed = layout
+            .pack(&layout.truncate(&[value[2], value[1]]))
+            .expect("the values were truncated to their widths");
         state[0] = value[0];
-        state[1] = F::from(U256::from_words(limbs));
+        state[1] = packed[0];
         for k in 0..F::RESCUE_NO_OF_ROUNDS {
             apply_round::<F>(&mut state, k);
             hash_data.push(state.clone());
@@ -118,6 +94,13 @@ impl<F: Field + PrimeField + RescueParameter<F>> Rescue<F> {
 
 // TRACE
 // ================================================================================================
+// Applies all the rounds of Rescue to the state
+pub fn permutation<F: Field + PrimeField + RescueParameter<F>>(state: &mut [F]) {
+    for k in 0..F::RESCUE_NO_OF_ROUNDS {
+        apply_round::<F>(state, k);
+    }
+}
+
 pub fn apply_round<F: Field + PrimeField + RescueParameter<F>>(state: &mut [F], step: usize) {
     // determine which round constants to use
     let ark = &F::rescue_ark()[step];
@@ -140,14 +123,14 @@ fn add_constants<F: Field + PrimeField + RescueParameter<F>>(
     ark: &[F],
     offset: usize,
 ) {
-    state.par_iter_mut().enumerate().for_each(|(i, state_element)| {
+    state.iter_mut().enumerate().for_each(|(i, state_element)| {
         *state_element += ark[offset + i];
     });
 }
 #[inline(always)]
 #[allow(clippy::needless_range_loop)]
 pub fn apply_sbox<F: Field + PrimeField + RescueParameter<F>>(state: &mut [F]) {
-    state.par_iter_mut().for_each(|element| {
+    state.iter_mut().for_each(|element| {
         if F::RESCUE_ALPHA == 3 {
             *element = element.square() * *element;
         } else if F::RESCUE_ALPHA == 5 {
@@ -160,18 +143,17 @@ pub fn apply_sbox<F: Field + PrimeField + RescueParameter<F>>(state: &mut [F]) {
 #[inline(always)]
 #[allow(clippy::needless_range_loop)]
 pub fn apply_inv_sbox<F: Field + PrimeField + RescueParameter<F>>(state: &mut [F]) {
-    state.par_iter_mut().for_each(|element| {
+    state.iter_mut().for_each(|element| {
         *element = element.power_by(&F::RESCUE_INV_ALPHA.to_words());
     });
 }
 
-use rayon::prelude::*;
 #[inline(always)]
 #[allow(clippy::needless_range_loop)]
 pub fn apply_mds<F: Field + PrimeField + RescueParameter<F>>(state: &mut [F]) {
     let mut result = vec![F::ZERO; F::RESCUE_STATE_WIDTH];
 
-    result.par_iter_mut().enumerate().for_each(|(i, res)| {
+    result.iter_mut().enumerate().for_each(|(i, res)| {
         let start_index = i * F::RESCUE_STATE_WIDTH;
         *res = (0..F::RESCUE_STATE_WIDTH).fold(F::ZERO, |acc, j| {
             acc + F::rescue_mds()[start_index + j] * state[j]
@@ -185,7 +167,7 @@ pub fn apply_mds<F: Field + PrimeField + RescueParameter<F>>(state: &mut [F]) {
 pub fn apply_inv_mds<F: Field + PrimeField + RescueParameter<F>>(state: &mut [F]) {
     let mut result = vec![F::ZERO; F::RESCUE_STATE_WIDTH];
 
-    result.par_iter_mut().enumerate().for_each(|(i, res)| {
+    result.iter_mut().enumerate().for_each(|(i, res)| {
         let start_index = i * F::RESCUE_STATE_WIDTH;
         *res = (0..F::RESCUE_STATE_WIDTH).fold(F::ZERO, |acc, j| {
             acc + F::rescue_inv_mds()[start_index + j] * state[j]
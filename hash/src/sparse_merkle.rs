@@ -0,0 +1,299 @@
+use std::{collections::BTreeMap, fmt, marker::PhantomData};
+
+use traits::traits::{Field, Hasher, PrimeField};
+
+use crate::merkle::TAG_LEAF;
+
+// Largest supported depth: keys are read as integers of at most 256 bits
+pub const MAX_DEPTH: usize = 256;
+
+// Position of a node within its level, as a little-endian integer of 256 bits
+type NodeIndex = [u64; 4];
+
+// SPARSE MERKLE TREE
+// ================================================================================================
+
+// Sparse Merkle tree of the given depth, mapping keys to values. The leaf of a key is at the
+// position given by the integer value of the key, which must be smaller than 2^depth. An empty
+// leaf is ZERO and the leaf of a key set to a value is the hash of TAG_LEAF, the key and the value,
+// so that any value, ZERO included, can be stored and no leaf can be opened as an internal node.
+// Empty subtrees are never stored: the hashes of the empty subtrees of every height are computed
+// once when the tree is created.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SparseMerkleTree<H: Hasher> {
+    depth: usize,
+    // empty_hashes[h] is the root of an empty subtree of height h
+    empty_hashes: Vec<H::FieldElement>,
+    // values stored in the tree, by position of their leaf
+    values: BTreeMap<NodeIndex, H::FieldElement>,
+    // nodes which differ from the root of the empty subtree of their height, by height and position
+    nodes: BTreeMap<(usize, NodeIndex), H::FieldElement>,
+    _hasher: PhantomData<H>,
+}
+
+// Siblings of the nodes on the path from a leaf to the root, starting at the leaf level
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SparseMerkleProof<F> {
+    pub siblings: Vec<F>,
+}
+
+impl<H: Hasher> SparseMerkleTree<H> {
+    // Returns an empty tree of the given depth
+    pub fn new(depth: usize) -> Result<Self, SparseMerkleTreeError> {
+        if depth == 0 || depth > MAX_DEPTH {
+            return Err(SparseMerkleTreeError::InvalidDepth(depth));
+        }
+        Ok(Self {
+            depth,
+            empty_hashes: empty_hashes::<H>(depth),
+            values: BTreeMap::new(),
+            nodes: BTreeMap::new(),
+            _hasher: PhantomData,
+        })
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn root(&self) -> H::FieldElement {
+        self.node(self.depth, [0; 4])
+    }
+
+    // Returns the number of keys stored in the tree
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    // Returns the value stored for the key, if any
+    pub fn get(&self, key: H::FieldElement) -> Result<Option<H::FieldElement>, SparseMerkleTreeError> {
+        let index = leaf_index(key, self.depth)?;
+        Ok(self.values.get(&index).copied())
+    }
+
+    // Stores the value for a key which is not in the tree yet
+    pub fn insert(
+        &mut self,
+        key: H::FieldElement,
+        value: H::FieldElement,
+    ) -> Result<(), SparseMerkleTreeError> {
+        if self.get(key)?.is_some() {
+            return Err(SparseMerkleTreeError::KeyAlreadyExists);
+        }
+        self.set_leaf(key, Some(value));
+        Ok(())
+    }
+
+    // Replaces the value stored for a key and returns the previous one
+    pub fn update(
+        &mut self,
+        key: H::FieldElement,
+        value: H::FieldElement,
+    ) -> Result<H::FieldElement, SparseMerkleTreeError> {
+        let old_value = self.get(key)?.ok_or(SparseMerkleTreeError::KeyNotFound)?;
+        self.set_leaf(key, Some(value));
+        Ok(old_value)
+    }
+
+    // Removes a key from the tree and returns its value
+    pub fn delete(&mut self, key: H::FieldElement) -> Result<H::FieldElement, SparseMerkleTreeError> {
+        let old_value = self.get(key)?.ok_or(SparseMerkleTreeError::KeyNotFound)?;
+        self.set_leaf(key, None);
+        Ok(old_value)
+    }
+
+    // Returns the proof of the leaf of the key. It proves the membership of the key with its value
+    // if the key is in the tree, and the non-membership of the key otherwise.
+    pub fn prove(
+        &self,
+        key: H::FieldElement,
+    ) -> Result<SparseMerkleProof<H::FieldElement>, SparseMerkleTreeError> {
+        let mut index = leaf_index(key, self.depth)?;
+        let mut siblings = Vec::with_capacity(self.depth);
+        for height in 0..self.depth {
+            let mut sibling = index;
+            sibling[0] ^= 1;
+            siblings.push(self.node(height, sibling));
+            index = parent_index(index);
+        }
+        Ok(SparseMerkleProof { siblings })
+    }
+
+    // Checks that the proof shows the key is in the tree of the given depth with the value
+    pub fn verify_membership(
+        root: H::FieldElement,
+        depth: usize,
+        key: H::FieldElement,
+        value: H::FieldElement,
+        proof: &SparseMerkleProof<H::FieldElement>,
+    ) -> Result<(), SparseMerkleTreeError> {
+        Self::verify_leaf(root, depth, key, leaf_hash::<H>(key, value), proof)
+    }
+
+    // Checks that the proof shows the key is not in the tree of the given depth
+    pub fn verify_non_membership(
+        root: H::FieldElement,
+        depth: usize,
+        key: H::FieldElement,
+        proof: &SparseMerkleProof<H::FieldElement>,
+    ) -> Result<(), SparseMerkleTreeError> {
+        Self::verify_leaf(root, depth, key, H::FieldElement::ZERO, proof)
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn node(&self, height: usize, index: NodeIndex) -> H::FieldElement {
+        match self.nodes.get(&(height, index)) {
+            Some(&node) => node,
+            None => self.empty_hashes[height],
+        }
+    }
+
+    // Sets the leaf of the key, which must be valid, and updates the nodes on its path
+    fn set_leaf(&mut self, key: H::FieldElement, value: Option<H::FieldElement>) {
+        let mut index = leaf_index(key, self.depth).expect("the key was checked by the caller");
+        let mut node = match value {
+            Some(value) => {
+                self.values.insert(index, value);
+                leaf_hash::<H>(key, value)
+            }
+            None => {
+                self.values.remove(&index);
+                self.empty_hashes[0]
+            }
+        };
+        for height in 0..=self.depth {
+            if node == self.empty_hashes[height] {
+                self.nodes.remove(&(height, index));
+            } else {
+                self.nodes.insert((height, index), node);
+            }
+            if height == self.depth {
+                break;
+            }
+            let mut sibling_index = index;
+            sibling_index[0] ^= 1;
+            let sibling = self.node(height, sibling_index);
+            node = if index[0] & 1 == 0 {
+                H::hash(&mut vec![node, sibling])
+            } else {
+                H::hash(&mut vec![sibling, node])
+            };
+            index = parent_index(index);
+        }
+    }
+
+    // Checks the path from the leaf of the key to the root. The depth is the one the verifier
+    // expects, as a proof for a smaller depth would place the key in another leaf.
+    fn verify_leaf(
+        root: H::FieldElement,
+        depth: usize,
+        key: H::FieldElement,
+        leaf: H::FieldElement,
+        proof: &SparseMerkleProof<H::FieldElement>,
+    ) -> Result<(), SparseMerkleTreeError> {
+        if depth == 0 || depth > MAX_DEPTH {
+            return Err(SparseMerkleTreeError::InvalidDepth(depth));
+        }
+        if proof.siblings.len() != depth {
+            return Err(SparseMerkleTreeError::InvalidProof);
+        }
+        let mut index = leaf_index(key, depth)?;
+        let mut node = leaf;
+        for &sibling in &proof.siblings {
+            node = if index[0] & 1 == 0 {
+                H::hash(&mut vec![node, sibling])
+            } else {
+                H::hash(&mut vec![sibling, node])
+            };
+            index = parent_index(index);
+        }
+        if node != root {
+            return Err(SparseMerkleTreeError::InvalidProof);
+        }
+        Ok(())
+    }
+}
+
+fn empty_hashes<H: Hasher>(depth: usize) -> Vec<H::FieldElement> {
+    let mut hashes = vec![H::FieldElement::ZERO];
+    for height in 0..depth {
+        let node = hashes[height];
+        hashes.push(H::hash(&mut vec![node, node]));
+    }
+    hashes
+}
+
+fn leaf_hash<H: Hasher>(key: H::FieldElement, value: H::FieldElement) -> H::FieldElement {
+    H::hash(&mut vec![H::FieldElement::from(TAG_LEAF), key, value])
+}
+
+// Returns the position of the leaf of the key, which must be smaller than 2^depth
+fn leaf_index<F: PrimeField>(key: F, depth: usize) -> Result<NodeIndex, SparseMerkleTreeError> {
+    let words = key.to_canonical_words();
+    let mut index = [0; 4];
+    for (i, &word) in words.iter().enumerate() {
+        let bits = depth.saturating_sub(64 * i).min(64);
+        if bits < 64 && word >> bits != 0 {
+            return Err(SparseMerkleTreeError::KeyOutOfRange(depth));
+        }
+        if i < 4 {
+            index[i] = word;
+        }
+    }
+    Ok(index)
+}
+
+fn parent_index(index: NodeIndex) -> NodeIndex {
+    [
+        (index[0] >> 1) | (index[1] << 63),
+        (index[1] >> 1) | (index[2] << 63),
+        (index[2] >> 1) | (index[3] << 63),
+        index[3] >> 1,
+    ]
+}
+
+// ERRORS
+// ================================================================================================
+
+// Errors which can occur when updating a sparse Merkle tree or checking its proofs
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SparseMerkleTreeError {
+    // The depth is 0 or greater than MAX_DEPTH
+    InvalidDepth(usize),
+    // The key is not smaller than 2^depth
+    KeyOutOfRange(usize),
+    // The key to insert is already in the tree
+    KeyAlreadyExists,
+    // The key to update or delete is not in the tree
+    KeyNotFound,
+    // The proof does not match the root
+    InvalidProof,
+}
+
+impl fmt::Display for SparseMerkleTreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDepth(depth) => {
+                write!(f, "the depth must be between 1 and {MAX_DEPTH}, but was {depth}")
+            }
+            Self::KeyOutOfRange(depth) => {
+                write!(f, "a key of a tree of depth {depth} must be smaller than 2^{depth}")
+            }
+            Self::KeyAlreadyExists => {
+                write!(f, "the key is already in the tree")
+            }
+            Self::KeyNotFound => {
+                write!(f, "the key is not in the tree")
+            }
+            Self::InvalidProof => {
+                write!(f, "sparse Merkle proof is invalid")
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use traits::traits::{Field, GMIMCParameter, PoseidonParameter, PrimeField, RescueParameter};
+
+use crate::{gmimc::GMIMCHASH, poseidon_6_5, poseidon_impl::PoseidonHash, rescue::Rescue};
+
+// PERMUTATIONS
+// ================================================================================================
+
+// Permutation of a state of STATE_WIDTH field elements. The first RATE elements of the state are
+// the ones the sponge absorbs into and squeezes from, the others form the capacity.
+pub trait Permutation {
+    type Field: Field + PrimeField;
+    const STATE_WIDTH: usize;
+    const RATE: usize;
+    fn permute(state: &mut [Self::Field]);
+}
+
+impl<F: Field + PrimeField + RescueParameter<F>> Permutation for Rescue<F> {
+    type Field = F;
+    const STATE_WIDTH: usize = F::RESCUE_STATE_WIDTH;
+    const RATE: usize = F::RESCUE_RATE;
+    fn permute(state: &mut [F]) {
+        crate::rescue::permutation::<F>(state);
+    }
+}
+
+// The sponge uses the width 6, rate 5 instance of Poseidon
+impl<F: Field + PrimeField + PoseidonParameter<F>> Permutation for PoseidonHash<F> {
+    type Field = F;
+    const STATE_WIDTH: usize = F::STATE_WIDTH_6_5;
+    const RATE: usize = F::RATE_6_5;
+    fn permute(state: &mut [F]) {
+        poseidon_6_5::permutation::<F>(state);
+    }
+}
+
+impl<F: Field + PrimeField + GMIMCParameter<F>> Permutation for GMIMCHASH<F> {
+    type Field = F;
+    const STATE_WIDTH: usize = F::GMIMC_STATE_WIDTH;
+    const RATE: usize = F::GMIMC_RATE;
+    fn permute(state: &mut [F]) {
+        crate::gmimc::gmimc_permutation::<F>(state, None);
+    }
+}
+
+// SPONGE
+// ================================================================================================
+
+// Whether the last operation on the sponge was absorbing or squeezing, with the position in the
+// rate part of the state where the next element is absorbed or squeezed
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum SpongeMode {
+    Absorbing(usize),
+    Squeezing(usize),
+}
+
+// Incremental sponge over a permutation. Elements are absorbed by adding them to the rate part of
+// the state, which is permuted each time it is full. When switching to squeezing, a one is added
+// after the last absorbed element (10* padding) and the state is permuted, so inputs of different
+// lengths give different outputs. Absorbing after squeezing starts a new absorbing phase on the
+// current state.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sponge<P: Permutation> {
+    state: Vec<P::Field>,
+    mode: SpongeMode,
+}
+
+pub type RescueSponge<F> = Sponge<Rescue<F>>;
+pub type PoseidonSponge<F> = Sponge<PoseidonHash<F>>;
+pub type GmimcSponge<F> = Sponge<GMIMCHASH<F>>;
+
+impl<P: Permutation> Sponge<P> {
+    // returns a sponge with an all zero state
+    pub fn new() -> Self {
+        Self {
+            state: vec![P::Field::ZERO; P::STATE_WIDTH],
+            mode: SpongeMode::Absorbing(0),
+        }
+    }
+
+    // Absorbs the elements in the sponge. The input is not modified.
+    pub fn absorb(&mut self, input: &[P::Field]) {
+        let mut position = match self.mode {
+            SpongeMode::Absorbing(position) => position,
+            SpongeMode::Squeezing(_) => {
+                P::permute(&mut self.state);
+                0
+            }
+        };
+        for element in input {
+            if position == P::RATE {
+                P::permute(&mut self.state);
+                position = 0;
+            }
+            self.state[position] += *element;
+            position += 1;
+        }
+        self.mode = SpongeMode::Absorbing(position);
+    }
+
+    // Squeezes n elements out of the sponge
+    pub fn squeeze(&mut self, n: usize) -> Vec<P::Field> {
+        let mut position = match self.mode {
+            SpongeMode::Squeezing(position) => position,
+            SpongeMode::Absorbing(mut position) => {
+                if position == P::RATE {
+                    P::permute(&mut self.state);
+                    position = 0;
+                }
+                self.state[position] += P::Field::ONE;
+                P::permute(&mut self.state);
+                0
+            }
+        };
+        let mut output = Vec::with_capacity(n);
+        for _ in 0..n {
+            if position == P::RATE {
+                P::permute(&mut self.state);
+                position = 0;
+            }
+            output.push(self.state[position]);
+            position += 1;
+        }
+        self.mode = SpongeMode::Squeezing(position);
+        output
+    }
+
+    // Brings the sponge back to its initial state
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<P: Permutation> Default for Sponge<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
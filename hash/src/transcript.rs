@@ -0,0 +1,109 @@
+use traits::traits::{Field, PrimeField};
+
+use crate::{
+    packing::capacity_bits,
+    sponge::{Permutation, Sponge},
+};
+
+// Tags absorbed before each operation, so that the different operations can't be confused with
+// one another
+const TAG_INIT: u64 = 0;
+const TAG_FIELD: u64 = 1;
+const TAG_BYTES: u64 = 2;
+const TAG_CHALLENGE_SCALAR: u64 = 3;
+const TAG_CHALLENGE_BITS: u64 = 4;
+
+// FIAT-SHAMIR TRANSCRIPT
+// ================================================================================================
+
+// Fiat-Shamir transcript built on a duplex sponge. The prover and the verifier append the same
+// labeled messages in the same order and get the same challenges. Every operation first absorbs
+// its tag, the length of its label and the label, and then the length and the content of its
+// message, so that distinct sequences of operations never absorb the same field elements.
+#[derive(Clone, Debug)]
+pub struct Transcript<P: Permutation> {
+    sponge: Sponge<P>,
+}
+
+impl<P: Permutation> Transcript<P> {
+    // Starts a transcript for the protocol identified by the label
+    pub fn new(label: &[u8]) -> Self {
+        let mut transcript = Self {
+            sponge: Sponge::new(),
+        };
+        transcript.append_label(TAG_INIT, label);
+        transcript
+    }
+
+    // Appends field elements to the transcript
+    pub fn append_field(&mut self, label: &[u8], elements: &[P::Field]) {
+        self.append_label(TAG_FIELD, label);
+        self.sponge.absorb(&[P::Field::from(elements.len() as u64)]);
+        self.sponge.absorb(elements);
+    }
+
+    // Appends bytes to the transcript. The bytes are packed into field elements of
+    // bytes_per_element() bytes each.
+    pub fn append_bytes(&mut self, label: &[u8], bytes: &[u8]) {
+        self.append_label(TAG_BYTES, label);
+        self.absorb_bytes(bytes);
+    }
+
+    // Returns a challenge field element
+    pub fn challenge_scalar(&mut self, label: &[u8]) -> P::Field {
+        self.append_label(TAG_CHALLENGE_SCALAR, label);
+        self.sponge.squeeze(1)[0]
+    }
+
+    // Returns num_bits challenge bits. The bits are read from the least significant end of
+    // squeezed field elements, at most bits_per_element() of them per element, so that they are
+    // close to uniform.
+    pub fn challenge_bits(&mut self, label: &[u8], num_bits: usize) -> Vec<bool> {
+        self.append_label(TAG_CHALLENGE_BITS, label);
+        self.sponge.absorb(&[P::Field::from(num_bits as u64)]);
+        let bits_per_element = Self::bits_per_element();
+        let mut bits = Vec::with_capacity(num_bits);
+        while bits.len() < num_bits {
+            let words = self.sponge.squeeze(1)[0].to_canonical_words();
+            let count = bits_per_element.min(num_bits - bits.len());
+            bits.extend((0..count).map(|i| (words[i / 64] >> (i % 64)) & 1 == 1));
+        }
+        bits
+    }
+
+    // Number of challenge bits read from a squeezed field element: every integer of that many
+    // bits is smaller than the modulus
+    pub fn bits_per_element() -> usize {
+        capacity_bits::<P::Field>()
+    }
+
+    // Number of bytes packed in a field element: every integer of that many bytes is smaller
+    // than the modulus
+    pub fn bytes_per_element() -> usize {
+        Self::bits_per_element() / 8
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn append_label(&mut self, tag: u64, label: &[u8]) {
+        self.sponge.absorb(&[P::Field::from(tag)]);
+        self.absorb_bytes(label);
+    }
+
+    // Absorbs the number of bytes followed by the bytes, packed into field elements in big-endian
+    // order
+    fn absorb_bytes(&mut self, bytes: &[u8]) {
+        let base = P::Field::from(256u64);
+        let elements: Vec<P::Field> = bytes
+            .chunks(Self::bytes_per_element())
+            .map(|chunk| {
+                chunk.iter().fold(P::Field::ZERO, |acc, byte| {
+                    acc * base + P::Field::from(*byte as u64)
+                })
+            })
+            .collect();
+        self.sponge.absorb(&[P::Field::from(bytes.len() as u64)]);
+        self.sponge.absorb(&elements);
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(feature = "concurrent")]
+use rayon::prelude::*;
+use traits::traits::Field;
+
+// BATCH INVERSION
+// ================================================================================================
+
+// Replaces every value with its inverse using Montgomery's trick: a single field inversion and
+// 3 (n - 1) multiplications for n values. Zeros have no inverse; they are skipped and left as
+// zeros, so that the other values are still inverted.
+pub fn batch_invert<F: Field>(values: &mut [F]) {
+    // prefix_products[i] is the product of the non-zero values before index i
+    let mut prefix_products = Vec::with_capacity(values.len());
+    let mut product = F::ONE;
+    for &value in values.iter() {
+        prefix_products.push(product);
+        if !value.is_zero() {
+            product *= value;
+        }
+    }
+
+    // the product holds no zero factor, so it is invertible
+    let mut inverse = product.invert().unwrap();
+    for (value, &prefix_product) in values.iter_mut().zip(&prefix_products).rev() {
+        if value.is_zero() {
+            continue;
+        }
+        // inverse is the inverse of the product of the non-zero values up to this one
+        let value_inverse = inverse * prefix_product;
+        inverse *= *value;
+        *value = value_inverse;
+    }
+}
+
+// Returns the inverses of the values, with zeros mapped to zeros
+pub fn batch_inverse<F: Field>(values: &[F]) -> Vec<F> {
+    let mut result = values.to_vec();
+    batch_invert(&mut result);
+    result
+}
+
+// Returns the inverses of the values, with zeros mapped to zeros, by splitting the values into
+// chunks of chunk_size values which are inverted independently. This costs one field inversion
+// per chunk; under the concurrent feature the chunks are inverted in parallel.
+pub fn batch_inverse_chunked<F: Field>(values: &[F], chunk_size: usize) -> Vec<F> {
+    assert!(chunk_size > 0, "the chunk size must be positive");
+    let mut result = values.to_vec();
+    #[cfg(feature = "concurrent")]
+    result.par_chunks_mut(chunk_size).for_each(batch_invert);
+    #[cfg(not(feature = "concurrent"))]
+    result.chunks_mut(chunk_size).for_each(batch_invert);
+    result
+}
@@ -0,0 +1,495 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use core::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use crypto_bigint::{
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
+    U256,
+};
+use traits::traits::{CurveGroup, Field};
+
+// Parameters of a twisted Edwards curve a * x^2 + y^2 = 1 + d * x^2 * y^2 defined over the base
+// field, whose group of points is a small cofactor times a prime order subgroup.
+pub trait EdwardsParameters: Copy + Clone + Debug + Default + PartialEq + Eq + Send + Sync {
+    // Field over which the curve is defined
+    type BaseField: Field + ConditionallySelectable;
+
+    // Field of integers modulo the order of the prime order subgroup
+    type ScalarField: Field;
+
+    // Coefficients a and d of the curve equation
+    const COEFF_A: Self::BaseField;
+    const COEFF_D: Self::BaseField;
+
+    // Affine coordinates of a generator of the prime order subgroup
+    const GENERATOR_X: Self::BaseField;
+    const GENERATOR_Y: Self::BaseField;
+
+    // Order of the prime order subgroup
+    const SUBGROUP_ORDER: U256;
+
+    // Number of points of the curve divided by the order of the prime order subgroup
+    const COFACTOR: u64;
+}
+
+// Compressed encoding of the points of a curve, from which the points get the to_compressed and
+// from_compressed methods and their serialization
+pub trait EdwardsEncoding: EdwardsParameters {
+    // Size in bytes of an encoded point
+    const ENCODED_BYTES: usize;
+
+    // Byte array of ENCODED_BYTES bytes holding an encoded point
+    type Encoding: AsRef<[u8]> + for<'a> TryFrom<&'a [u8]>;
+
+    // Returns the encoding of the point
+    fn encode(point: &EdwardsAffine<Self>) -> Self::Encoding;
+
+    // Decodes a point of the curve, rejecting non canonical encodings. The point may have a small
+    // order component.
+    fn decode(bytes: &Self::Encoding) -> Result<EdwardsAffine<Self>, DeserializationError>;
+}
+
+// AFFINE POINT
+// ================================================================================================
+
+// A point (x, y) on the curve. The identity is (0, 1), so every point has affine coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EdwardsAffine<C: EdwardsParameters> {
+    pub x: C::BaseField,
+    pub y: C::BaseField,
+}
+
+impl<C: EdwardsParameters> EdwardsAffine<C> {
+    // returns the identity (0, 1)
+    pub fn identity() -> Self {
+        Self {
+            x: C::BaseField::ZERO,
+            y: C::BaseField::ONE,
+        }
+    }
+    // returns the generator of the prime order subgroup
+    pub fn generator() -> Self {
+        Self {
+            x: C::GENERATOR_X,
+            y: C::GENERATOR_Y,
+        }
+    }
+    // returns the affine point (x, y). The caller is responsible for (x, y) being on the curve.
+    pub fn new(x: C::BaseField, y: C::BaseField) -> Self {
+        Self { x, y }
+    }
+    // checks whether the point is the identity
+    pub fn is_identity(&self) -> bool {
+        self.x.is_zero() && self.y == C::BaseField::ONE
+    }
+    // checks whether the point satisfies a * x^2 + y^2 = 1 + d * x^2 * y^2
+    pub fn is_on_curve(&self) -> bool {
+        let x2 = self.x.square();
+        let y2 = self.y.square();
+        C::COEFF_A * x2 + y2 == C::BaseField::ONE + C::COEFF_D * x2 * y2
+    }
+    // checks whether the point lies in the prime order subgroup
+    pub fn is_torsion_free(&self) -> bool {
+        self.to_extended().is_torsion_free()
+    }
+    // checks whether the point has an order dividing the cofactor
+    pub fn is_small_order(&self) -> bool {
+        self.to_extended().is_small_order()
+    }
+    // returns the extended representation of the point
+    pub fn to_extended(&self) -> EdwardsExtended<C> {
+        EdwardsExtended::from(*self)
+    }
+    // Returns an x coordinate of the point with the given y coordinate, if there is one. The
+    // other candidate is its negation. x^2 = (1 - y^2) / (a - d * y^2)
+    pub fn recover_x(y: &C::BaseField) -> CtOption<C::BaseField> {
+        let y2 = y.square();
+        let den = C::COEFF_A - C::COEFF_D * y2;
+        let inv = den.invert();
+        let is_some = inv.is_some();
+        let x2 = (C::BaseField::ONE - y2) * inv.unwrap_or(C::BaseField::ZERO);
+        let x = x2.sqrt();
+        let is_some = is_some & x.is_some();
+        CtOption::new(x.unwrap_or(C::BaseField::ZERO), is_some)
+    }
+}
+
+impl<C: EdwardsEncoding> EdwardsAffine<C> {
+    // returns the compressed encoding of the point
+    pub fn to_compressed(&self) -> C::Encoding {
+        C::encode(self)
+    }
+
+    // Decodes a point of the prime order subgroup, rejecting non canonical encodings and points
+    // with a small order component
+    pub fn from_compressed(bytes: &C::Encoding) -> Result<Self, DeserializationError> {
+        let point = C::decode(bytes)?;
+        if !point.is_torsion_free() {
+            return Err(DeserializationError::InvalidValue(
+                "invalid point: not in the prime order subgroup".to_string(),
+            ));
+        }
+        Ok(point)
+    }
+
+    // Decodes a point of the curve, rejecting non canonical encodings. The point may have a small
+    // order component.
+    pub fn from_compressed_unchecked(bytes: &C::Encoding) -> Result<Self, DeserializationError> {
+        C::decode(bytes)
+    }
+}
+
+impl<C: EdwardsParameters> Default for EdwardsAffine<C> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<C: EdwardsParameters> ConditionallySelectable for EdwardsAffine<C> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: C::BaseField::conditional_select(&a.x, &b.x, choice),
+            y: C::BaseField::conditional_select(&a.y, &b.y, choice),
+        }
+    }
+}
+
+impl<C: EdwardsParameters> Neg for EdwardsAffine<C> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl<C: EdwardsParameters> Mul<C::ScalarField> for EdwardsAffine<C> {
+    type Output = EdwardsExtended<C>;
+    fn mul(self, rhs: C::ScalarField) -> EdwardsExtended<C> {
+        EdwardsExtended::from(self) * rhs
+    }
+}
+
+impl<C: EdwardsParameters> From<EdwardsExtended<C>> for EdwardsAffine<C> {
+    fn from(p: EdwardsExtended<C>) -> Self {
+        let zinv = p.z.invert().unwrap();
+        Self::new(p.x * zinv, p.y * zinv)
+    }
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+// Serialization of the prime order subgroup: deserialization rejects points with a small order
+// component
+impl<C: EdwardsEncoding> Serializable for EdwardsAffine<C> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8_slice(self.to_compressed().as_ref());
+    }
+}
+
+impl<C: EdwardsEncoding> Deserializable for EdwardsAffine<C> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let bytes = source.read_u8_vec(C::ENCODED_BYTES)?;
+        let Ok(bytes) = C::Encoding::try_from(bytes.as_slice()) else {
+            return Err(DeserializationError::UnexpectedEOF);
+        };
+        Self::from_compressed(&bytes)
+    }
+}
+
+// Decodes a point encoded as y in little-endian, with the most significant bit of the last byte
+// set when x is the larger of x and -x. y_from_le_bytes returns None when y is not smaller than
+// the modulus. Non canonical encodings are rejected: y must be reduced and the sign bit must not
+// be set when x = 0.
+pub fn decode_y_with_sign<C: EdwardsParameters, const N: usize>(
+    bytes: &[u8; N],
+    y_from_le_bytes: impl FnOnce([u8; N]) -> Option<C::BaseField>,
+) -> Result<EdwardsAffine<C>, DeserializationError>
+where
+    C::BaseField: PartialOrd,
+{
+    let mut bytes = *bytes;
+    let x_is_larger = (bytes[N - 1] >> 7) == 1;
+    bytes[N - 1] &= 0x7f;
+    let Some(y) = y_from_le_bytes(bytes) else {
+        return Err(DeserializationError::InvalidValue(
+            "invalid point: y coordinate is greater than or equal to the field modulus".to_string(),
+        ));
+    };
+    let x = EdwardsAffine::<C>::recover_x(&y);
+    if bool::from(x.is_none()) {
+        return Err(DeserializationError::InvalidValue(
+            "invalid point: y coordinate does not belong to a point of the curve".to_string(),
+        ));
+    }
+    let x = x.unwrap();
+    if x_is_larger && x.is_zero() {
+        return Err(DeserializationError::InvalidValue(
+            "invalid point: sign flag set for x = 0".to_string(),
+        ));
+    }
+    let x = if (x > -x) == x_is_larger { x } else { -x };
+    Ok(EdwardsAffine::new(x, y))
+}
+
+// EXTENDED POINT
+// ================================================================================================
+
+// A point in extended twisted Edwards coordinates (X : Y : Z : T) representing the affine point
+// (X/Z, Y/Z) with T = XY/Z. Addition and doubling use the unified formulas add-2008-hwcd and
+// dbl-2008-hwcd of https://eprint.iacr.org/2008/522.pdf, which have no exceptional cases on the
+// prime order subgroup.
+#[derive(Clone, Copy, Debug, Eq)]
+pub struct EdwardsExtended<C: EdwardsParameters> {
+    pub x: C::BaseField,
+    pub y: C::BaseField,
+    pub z: C::BaseField,
+    pub t: C::BaseField,
+}
+
+impl<C: EdwardsParameters> EdwardsExtended<C> {
+    // returns the identity (0 : 1 : 1 : 0)
+    pub fn identity() -> Self {
+        Self {
+            x: C::BaseField::ZERO,
+            y: C::BaseField::ONE,
+            z: C::BaseField::ONE,
+            t: C::BaseField::ZERO,
+        }
+    }
+    // returns the generator of the prime order subgroup
+    pub fn generator() -> Self {
+        Self::from(EdwardsAffine::<C>::generator())
+    }
+    // checks whether the point is the identity
+    pub fn is_identity(&self) -> bool {
+        self.x.is_zero() && self.y == self.z && !self.z.is_zero()
+    }
+    // checks whether the point satisfies (a * X^2 + Y^2) * Z^2 = Z^4 + d * X^2 * Y^2 and X * Y = Z * T
+    pub fn is_on_curve(&self) -> bool {
+        let x2 = self.x.square();
+        let y2 = self.y.square();
+        let z2 = self.z.square();
+        !self.z.is_zero()
+            && (C::COEFF_A * x2 + y2) * z2 == z2.square() + C::COEFF_D * x2 * y2
+            && self.x * self.y == self.z * self.t
+    }
+    // checks whether the point lies in the prime order subgroup, i.e. [n]P = O
+    pub fn is_torsion_free(&self) -> bool {
+        self.mul_by_words(&C::SUBGROUP_ORDER.to_words()).is_identity()
+    }
+    // checks whether the point has an order dividing the cofactor, i.e. [h]P = O
+    pub fn is_small_order(&self) -> bool {
+        self.mul_by_cofactor().is_identity()
+    }
+    // returns [h]P, which lies in the prime order subgroup
+    pub fn mul_by_cofactor(&self) -> Self {
+        self.mul_by_words(&[C::COFACTOR])
+    }
+    // returns the affine representation of the point
+    pub fn to_affine(&self) -> EdwardsAffine<C> {
+        EdwardsAffine::from(*self)
+    }
+
+    // returns 2 * self using dbl-2008-hwcd
+    pub fn double(&self) -> Self {
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = self.z.square().double();
+        let d = C::COEFF_A * a;
+        let e = (self.x + self.y).square() - a - b;
+        let g = d + b;
+        let f = g - c;
+        let h = d - b;
+        Self {
+            x: e * f,
+            y: g * h,
+            z: f * g,
+            t: e * h,
+        }
+    }
+
+    // Multiplies the point by an integer given as little-endian u64 words. This runs in variable
+    // time and is meant for public multipliers such as the subgroup order or the cofactor.
+    pub fn mul_by_words(&self, by: &[u64]) -> Self {
+        let mut res = Self::identity();
+        for word in by.iter().rev() {
+            for i in (0..64).rev() {
+                res = res.double();
+                if ((word >> i) & 1) == 1 {
+                    res += *self;
+                }
+            }
+        }
+        res
+    }
+}
+
+impl<C: EdwardsParameters> Default for EdwardsExtended<C> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<C: EdwardsParameters> PartialEq for EdwardsExtended<C> {
+    // (X1 : Y1 : Z1) == (X2 : Y2 : Z2) iff X1 * Z2 == X2 * Z1 and Y1 * Z2 == Y2 * Z1
+    fn eq(&self, other: &Self) -> bool {
+        self.x * other.z == other.x * self.z && self.y * other.z == other.y * self.z
+    }
+}
+
+impl<C: EdwardsParameters> ConditionallySelectable for EdwardsExtended<C> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: C::BaseField::conditional_select(&a.x, &b.x, choice),
+            y: C::BaseField::conditional_select(&a.y, &b.y, choice),
+            z: C::BaseField::conditional_select(&a.z, &b.z, choice),
+            t: C::BaseField::conditional_select(&a.t, &b.t, choice),
+        }
+    }
+}
+
+impl<C: EdwardsParameters> From<EdwardsAffine<C>> for EdwardsExtended<C> {
+    fn from(p: EdwardsAffine<C>) -> Self {
+        Self {
+            x: p.x,
+            y: p.y,
+            z: C::BaseField::ONE,
+            t: p.x * p.y,
+        }
+    }
+}
+
+impl<C: EdwardsParameters> Neg for EdwardsExtended<C> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: self.y,
+            z: self.z,
+            t: -self.t,
+        }
+    }
+}
+
+impl<C: EdwardsParameters> Add for EdwardsExtended<C> {
+    type Output = Self;
+    // add-2008-hwcd
+    fn add(self, rhs: Self) -> Self {
+        let a = self.x * rhs.x;
+        let b = self.y * rhs.y;
+        let c = C::COEFF_D * self.t * rhs.t;
+        let d = self.z * rhs.z;
+        let e = (self.x + self.y) * (rhs.x + rhs.y) - a - b;
+        let f = d - c;
+        let g = d + c;
+        let h = b - C::COEFF_A * a;
+        Self {
+            x: e * f,
+            y: g * h,
+            z: f * g,
+            t: e * h,
+        }
+    }
+}
+
+impl<C: EdwardsParameters> Add<EdwardsAffine<C>> for EdwardsExtended<C> {
+    type Output = Self;
+    fn add(self, rhs: EdwardsAffine<C>) -> Self {
+        self + Self::from(rhs)
+    }
+}
+
+impl<C: EdwardsParameters> Sub for EdwardsExtended<C> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<C: EdwardsParameters> Sub<EdwardsAffine<C>> for EdwardsExtended<C> {
+    type Output = Self;
+    fn sub(self, rhs: EdwardsAffine<C>) -> Self {
+        self + Self::from(-rhs)
+    }
+}
+
+impl<C: EdwardsParameters> AddAssign for EdwardsExtended<C> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<C: EdwardsParameters> AddAssign<EdwardsAffine<C>> for EdwardsExtended<C> {
+    fn add_assign(&mut self, rhs: EdwardsAffine<C>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<C: EdwardsParameters> SubAssign for EdwardsExtended<C> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<C: EdwardsParameters> SubAssign<EdwardsAffine<C>> for EdwardsExtended<C> {
+    fn sub_assign(&mut self, rhs: EdwardsAffine<C>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<C: EdwardsParameters> Mul<C::ScalarField> for EdwardsExtended<C> {
+    type Output = Self;
+    // Fixed window scalar multiplication with 4 bit windows. The table entry for each window is
+    // read with constant time selects, so the sequence of group operations does not depend on the
+    // scalar.
+    fn mul(self, rhs: C::ScalarField) -> Self {
+        let mut table = [Self::identity(); 16];
+        for i in 1..16 {
+            table[i] = table[i - 1] + self;
+        }
+        let mut res = Self::identity();
+        for word in rhs.to_words().iter().rev() {
+            for i in (0..16).rev() {
+                res = res.double().double().double().double();
+                let window = ((word >> (4 * i)) & 0xf) as u8;
+                let mut addend = Self::identity();
+                for (j, entry) in table.iter().enumerate() {
+                    addend = Self::conditional_select(&addend, entry, (j as u8).ct_eq(&window));
+                }
+                res += addend;
+            }
+        }
+        res
+    }
+}
+
+impl<C: EdwardsParameters> MulAssign<C::ScalarField> for EdwardsExtended<C> {
+    fn mul_assign(&mut self, rhs: C::ScalarField) {
+        *self = *self * rhs;
+    }
+}
+
+impl<C: EdwardsParameters> CurveGroup for EdwardsExtended<C> {
+    type ScalarField = C::ScalarField;
+
+    fn identity() -> Self {
+        Self::identity()
+    }
+
+    fn generator() -> Self {
+        Self::generator()
+    }
+
+    fn is_identity(&self) -> bool {
+        self.is_identity()
+    }
+
+    fn double(&self) -> Self {
+        self.double()
+    }
+}
@@ -0,0 +1,243 @@
+use std::fmt;
+
+#[cfg(feature = "concurrent")]
+use rayon::prelude::*;
+use traits::traits::{Field, PrimeField};
+
+// Half-size from which the butterflies of a single block are split between threads
+#[cfg(feature = "concurrent")]
+const MIN_PARALLEL_HALF_SIZE: usize = 1024;
+
+// EVALUATION DOMAIN
+// ================================================================================================
+
+// Multiplicative subgroup of size 2^log_size of a two-adic field, generated by the primitive root
+// of unity given by get_root_of_unity(log_size). The domain holds the twiddle factors of the
+// radix-2 NTT of this size: the first size / 2 powers of the generator and of its inverse.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EvaluationDomain<F: Field + PrimeField> {
+    size: usize,
+    log_size: u32,
+    generator: F,
+    generator_inv: F,
+    size_inv: F,
+    twiddles: Vec<F>,
+    inv_twiddles: Vec<F>,
+}
+
+impl<F: Field + PrimeField> EvaluationDomain<F> {
+    // Returns the domain of the given size, which must be a power of two not larger than
+    // 2^TWO_ADDICITY
+    pub fn new(size: usize) -> Result<Self, FftError> {
+        if !size.is_power_of_two() {
+            return Err(FftError::SizeNotPowerOfTwo(size));
+        }
+        let log_size = size.trailing_zeros();
+        if log_size > F::TWO_ADDICITY {
+            return Err(FftError::SizeTooLarge(size, F::TWO_ADDICITY));
+        }
+        // some fields don't accept 0 in get_root_of_unity
+        let generator = if log_size == 0 { F::ONE } else { F::get_root_of_unity(log_size) };
+        let generator_inv = generator.invert().unwrap();
+        // 1 / size is a power of 1 / 2, as from(u64) is not a Montgomery conversion in every field
+        let two_inv = (F::ONE + F::ONE).invert().unwrap();
+        Ok(Self {
+            size,
+            log_size,
+            generator,
+            generator_inv,
+            size_inv: two_inv.power_by([log_size as u64]),
+            twiddles: powers(generator, size / 2),
+            inv_twiddles: powers(generator_inv, size / 2),
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    // Returns the generator of the domain
+    pub fn generator(&self) -> F {
+        self.generator
+    }
+
+    // Returns the elements of the domain in natural order: 1, g, g^2, ...
+    pub fn elements(&self) -> Vec<F> {
+        powers(self.generator, self.size)
+    }
+
+    // Replaces the coefficients of a polynomial of degree less than the size of the domain with
+    // its evaluations at the elements of the domain, in natural order. There must be as many
+    // values as elements in the domain.
+    pub fn fft(&self, values: &mut [F]) -> Result<(), FftError> {
+        self.check_len(values)?;
+        ntt(values, &self.twiddles);
+        Ok(())
+    }
+
+    // Replaces the evaluations at the elements of the domain with the coefficients of the
+    // polynomial interpolating them
+    pub fn ifft(&self, values: &mut [F]) -> Result<(), FftError> {
+        self.check_len(values)?;
+        ntt(values, &self.inv_twiddles);
+        scale(values, self.size_inv);
+        Ok(())
+    }
+
+    // Replaces the coefficients of a polynomial with its evaluations over the coset
+    // offset * domain, in natural order. The offset must not be zero.
+    pub fn coset_fft(&self, values: &mut [F], offset: F) -> Result<(), FftError> {
+        self.check_len(values)?;
+        check_offset(offset)?;
+        multiply_by_powers(values, offset);
+        ntt(values, &self.twiddles);
+        Ok(())
+    }
+
+    // Replaces the evaluations over the coset offset * domain with the coefficients of the
+    // polynomial interpolating them
+    pub fn coset_ifft(&self, values: &mut [F], offset: F) -> Result<(), FftError> {
+        check_offset(offset)?;
+        self.ifft(values)?;
+        multiply_by_powers(values, offset.invert().unwrap());
+        Ok(())
+    }
+
+    fn check_len(&self, values: &[F]) -> Result<(), FftError> {
+        if values.len() != self.size {
+            return Err(FftError::NumberOfValuesMismatch(self.size, values.len()));
+        }
+        Ok(())
+    }
+}
+
+// BIT REVERSAL
+// ================================================================================================
+
+// Swaps every element with the one at the bit-reversed index. The length must be a power of two.
+pub fn bit_reverse_permutation<T>(values: &mut [T]) {
+    let n = values.len();
+    assert!(n.is_power_of_two(), "the length must be a power of two, but was {n}");
+    if n <= 2 {
+        return;
+    }
+    let shift = usize::BITS - n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> shift;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn check_offset<F: Field>(offset: F) -> Result<(), FftError> {
+    if offset.is_zero() {
+        return Err(FftError::ZeroOffset);
+    }
+    Ok(())
+}
+
+// Iterative Cooley-Tukey NTT: the input is put in bit-reversed order and the butterflies are
+// applied from the smallest blocks to the largest. twiddles holds the first n / 2 powers of the
+// root of unity of order n.
+fn ntt<F: Field>(values: &mut [F], twiddles: &[F]) {
+    let n = values.len();
+    bit_reverse_permutation(values);
+    let mut half_size = 1;
+    while half_size < n {
+        let stride = n / (2 * half_size);
+        #[cfg(feature = "concurrent")]
+        values
+            .par_chunks_mut(2 * half_size)
+            .for_each(|block| butterflies(block, twiddles, stride));
+        #[cfg(not(feature = "concurrent"))]
+        values
+            .chunks_mut(2 * half_size)
+            .for_each(|block| butterflies(block, twiddles, stride));
+        half_size *= 2;
+    }
+}
+
+fn butterflies<F: Field>(block: &mut [F], twiddles: &[F], stride: usize) {
+    let half_size = block.len() / 2;
+    let (low, high) = block.split_at_mut(half_size);
+    let butterfly = |(j, (a, b)): (usize, (&mut F, &mut F))| {
+        let t = *b * twiddles[j * stride];
+        *b = *a - t;
+        *a += t;
+    };
+    #[cfg(feature = "concurrent")]
+    if half_size >= MIN_PARALLEL_HALF_SIZE {
+        low.par_iter_mut().zip(high.par_iter_mut()).enumerate().for_each(butterfly);
+        return;
+    }
+    low.iter_mut().zip(high.iter_mut()).enumerate().for_each(butterfly);
+}
+
+// Returns 1, x, ..., x^(n - 1)
+fn powers<F: Field>(x: F, n: usize) -> Vec<F> {
+    let mut result = Vec::with_capacity(n);
+    let mut power = F::ONE;
+    for _ in 0..n {
+        result.push(power);
+        power *= x;
+    }
+    result
+}
+
+fn multiply_by_powers<F: Field>(values: &mut [F], x: F) {
+    let mut power = F::ONE;
+    for value in values.iter_mut() {
+        *value *= power;
+        power *= x;
+    }
+}
+
+fn scale<F: Field>(values: &mut [F], factor: F) {
+    #[cfg(feature = "concurrent")]
+    values.par_iter_mut().for_each(|value| *value *= factor);
+    #[cfg(not(feature = "concurrent"))]
+    values.iter_mut().for_each(|value| *value *= factor);
+}
+
+// ERRORS
+// ================================================================================================
+
+// Errors which can occur when creating an evaluation domain or transforming values over it
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FftError {
+    // The size of the domain is not a power of two
+    SizeNotPowerOfTwo(usize),
+    // The size of the domain is larger than 2^TWO_ADDICITY
+    SizeTooLarge(usize, u32),
+    // The number of values differs from the size of the domain
+    NumberOfValuesMismatch(usize, usize),
+    // The offset of a coset is zero
+    ZeroOffset,
+}
+
+impl fmt::Display for FftError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SizeNotPowerOfTwo(size) => {
+                write!(f, "the size of the domain must be a power of two, but was {size}")
+            }
+            Self::SizeTooLarge(size, two_adicity) => {
+                write!(f, "the size of the domain cannot exceed 2^{two_adicity}, but was {size}")
+            }
+            Self::NumberOfValuesMismatch(expected, actual) => {
+                write!(f, "expected {expected} values, but {actual} were provided")
+            }
+            Self::ZeroOffset => {
+                write!(f, "the offset of a coset cannot be zero")
+            }
+        }
+    }
+}
@@ -0,0 +1,744 @@
+use std::{fmt, marker::PhantomData};
+
+use hash::{
+    merkle::{BatchMerkleProof, BinaryMerkleTree, WideHasher},
+    packing::modulus_bits,
+    sponge::Permutation,
+    transcript::Transcript,
+};
+#[cfg(feature = "concurrent")]
+use rayon::prelude::*;
+use traits::traits::{ExtensionOf, Field, PrimeField};
+
+use crate::fft::EvaluationDomain;
+
+// Largest number of evaluations folded into one
+const MAX_FOLDING_FACTOR: usize = 16;
+
+// Smallest number of bits of the Merkle digests, so that finding a collision takes about 2^125
+// hashes: one element of the 252-bit StarkNet field, two of a 128-bit field or four of a 64-bit one
+const MIN_DIGEST_BITS: usize = 250;
+
+// FRI OPTIONS
+// ================================================================================================
+
+// Parameters of the FRI protocol. The polynomial is evaluated over a domain blowup_factor times
+// larger than its degree bound, and each layer folds folding_factor evaluations of the previous one
+// into one. Folding stops once the degree bound of a layer is at most max_remainder_degree + 1 or
+// smaller than the folding factor, and the polynomial of this last layer, the remainder, is sent
+// in the clear. The verifier checks the layers at num_queries random positions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FriOptions {
+    blowup_factor: usize,
+    folding_factor: usize,
+    num_queries: usize,
+    max_remainder_degree: usize,
+}
+
+impl FriOptions {
+    // Returns the options with the given parameters. The blowup factor, the folding factor and
+    // max_remainder_degree + 1 must be powers of two, with a folding factor between 2 and 16.
+    pub fn new(
+        blowup_factor: usize,
+        folding_factor: usize,
+        num_queries: usize,
+        max_remainder_degree: usize,
+    ) -> Result<Self, FriError> {
+        if blowup_factor < 2 || !blowup_factor.is_power_of_two() {
+            return Err(FriError::InvalidBlowupFactor(blowup_factor));
+        }
+        if !(2..=MAX_FOLDING_FACTOR).contains(&folding_factor) || !folding_factor.is_power_of_two()
+        {
+            return Err(FriError::InvalidFoldingFactor(folding_factor));
+        }
+        if num_queries == 0 {
+            return Err(FriError::InvalidNumberOfQueries(num_queries));
+        }
+        if !max_remainder_degree
+            .checked_add(1)
+            .is_some_and(usize::is_power_of_two)
+        {
+            return Err(FriError::InvalidMaxRemainderDegree(max_remainder_degree));
+        }
+        Ok(Self {
+            blowup_factor,
+            folding_factor,
+            num_queries,
+            max_remainder_degree,
+        })
+    }
+
+    pub fn blowup_factor(&self) -> usize {
+        self.blowup_factor
+    }
+
+    pub fn folding_factor(&self) -> usize {
+        self.folding_factor
+    }
+
+    pub fn num_queries(&self) -> usize {
+        self.num_queries
+    }
+
+    pub fn max_remainder_degree(&self) -> usize {
+        self.max_remainder_degree
+    }
+
+    // Returns the number of layers committed to for an evaluation domain of the given size. A
+    // layer is folded while its degree bound exceeds max_remainder_degree + 1 and is at least the
+    // folding factor, since folding a polynomial of a smaller degree bound would no longer bound
+    // its degree.
+    pub fn num_layers(&self, domain_size: usize) -> usize {
+        let mut degree_bound = domain_size / self.blowup_factor;
+        let mut num_layers = 0;
+        while degree_bound > self.max_remainder_degree + 1 && degree_bound >= self.folding_factor {
+            degree_bound /= self.folding_factor;
+            num_layers += 1;
+        }
+        num_layers
+    }
+
+    // Returns the number of coefficients of the remainder for an evaluation domain of the given
+    // size
+    pub fn remainder_size(&self, domain_size: usize) -> usize {
+        let folding = self.folding_factor.pow(self.num_layers(domain_size) as u32);
+        domain_size / self.blowup_factor / folding
+    }
+
+    // Returns the domain of the given size, which must be a power of two at least as large as the
+    // blowup factor
+    fn domain<B: Field + PrimeField>(
+        &self,
+        domain_size: usize,
+    ) -> Result<EvaluationDomain<B>, FriError> {
+        if domain_size < self.blowup_factor {
+            return Err(FriError::InvalidDomainSize(domain_size));
+        }
+        EvaluationDomain::new(domain_size).map_err(|_| FriError::InvalidDomainSize(domain_size))
+    }
+}
+
+// FRI PROOF
+// ================================================================================================
+
+// Openings of a layer at the queried positions: the root of the Merkle tree of the layer, the
+// cosets containing the positions, in increasing order of index, and the batch proof of their
+// leaves. The nodes of the tree are digests of N base field elements.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FriLayerProof<B, E, const N: usize = 1> {
+    pub root: [B; N],
+    pub cosets: Vec<Vec<E>>,
+    pub merkle_proof: BatchMerkleProof<[B; N]>,
+}
+
+// Proof that evaluations are close to the ones of a polynomial of degree less than the size of the
+// domain divided by the blowup factor: the openings of every committed layer and the coefficients
+// of the remainder, from the constant one up
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FriProof<B, E, const N: usize = 1> {
+    pub layers: Vec<FriLayerProof<B, E, N>>,
+    pub remainder: Vec<E>,
+}
+
+// FRI PROVER
+// ================================================================================================
+// The evaluations are over the coset g * <w> of the subgroup of size n of the base field B, where
+// g is the generator of the multiplicative group of B and w the root of unity of order n, in
+// natural order: the value at g * w^i is the i-th one. They take their values in E, an extension
+// of B from which the folding challenges are drawn. With a folding factor k, the j-th leaf of the
+// Merkle tree of a layer of size n is the hash of the base field coordinates of the k values at
+// the coset {x * w^(t * n / k)}, x = g * w^j, which fold into the j-th value of the next layer.
+// The Merkle trees are binary, with digests of N elements of B squeezed from a sponge over the
+// permutation which also runs the Fiat-Shamir transcript. N must be large enough for the digests
+// to have MIN_DIGEST_BITS bits.
+
+// Evaluations of a committed layer, with their Merkle tree
+#[derive(Clone, Debug)]
+struct FriLayer<H: Permutation, E, const N: usize> {
+    evaluations: Vec<E>,
+    tree: BinaryMerkleTree<WideHasher<H, N>>,
+}
+
+// Prover holding the layers committed to, so that they can be opened at the positions drawn
+// afterwards
+#[derive(Clone, Debug)]
+pub struct FriProver<B, E, H, const N: usize = 1>
+where
+    B: Field + PrimeField,
+    E: Field + ExtensionOf<B>,
+    H: Permutation<Field = B>,
+{
+    options: FriOptions,
+    domain_size: usize,
+    layers: Vec<FriLayer<H, E, N>>,
+    remainder: Vec<E>,
+}
+
+impl<B, E, H, const N: usize> FriProver<B, E, H, N>
+where
+    B: Field + PrimeField,
+    E: Field + ExtensionOf<B>,
+    H: Permutation<Field = B>,
+{
+    // Commits to the layers of the evaluations: the root of each layer is appended to the
+    // transcript before drawing the challenge which folds it into the next, and the remainder is
+    // appended last
+    pub fn commit(
+        options: FriOptions,
+        evaluations: Vec<E>,
+        transcript: &mut Transcript<H>,
+    ) -> Result<Self, FriError> {
+        check_digest_size::<B, N>()?;
+        let domain_size = evaluations.len();
+        let domain = options.domain::<B>(domain_size)?;
+        let folding_factor = options.folding_factor;
+        let mut offset = B::GENERATOR;
+        let mut generator = domain.generator();
+
+        let mut evaluations = evaluations;
+        let mut layers = Vec::new();
+        for _ in 0..options.num_layers(domain_size) {
+            let leaves = cosets(&evaluations, folding_factor)
+                .iter()
+                .map(|coset| hash_coset::<B, E, H, N>(coset))
+                .collect();
+            let tree = BinaryMerkleTree::<WideHasher<H, N>>::new(leaves)
+                .expect("the number of cosets is a power of two larger than one");
+            transcript.append_field(b"fri layer root", &tree.root());
+            let alpha = draw_challenge::<B, E, H>(transcript);
+            let folded = fold_layer(&evaluations, folding_factor, offset, generator, alpha);
+            layers.push(FriLayer { evaluations, tree });
+            evaluations = folded;
+            offset = offset.power_by([folding_factor as u64]);
+            generator = generator.power_by([folding_factor as u64]);
+        }
+
+        let remainder =
+            interpolate_remainder(evaluations, offset, options.remainder_size(domain_size));
+        transcript.append_field(b"fri remainder", &to_base_elements(&remainder));
+        Ok(Self {
+            options,
+            domain_size,
+            layers,
+            remainder,
+        })
+    }
+
+    // Returns the proof opening the layers at the positions of the first evaluation domain
+    pub fn query(&self, positions: &[usize]) -> Result<FriProof<B, E, N>, FriError> {
+        if let Some(&position) = positions.iter().find(|&&p| p >= self.domain_size) {
+            return Err(FriError::PositionOutOfBounds(self.domain_size, position));
+        }
+        let folding_factor = self.options.folding_factor;
+        let mut size = self.domain_size;
+        let mut layers = Vec::with_capacity(self.layers.len());
+        for layer in &self.layers {
+            let num_cosets = size / folding_factor;
+            let indexes = coset_indexes(positions, num_cosets);
+            let cosets = indexes
+                .iter()
+                .map(|&j| {
+                    (0..folding_factor)
+                        .map(|t| layer.evaluations[j + t * num_cosets])
+                        .collect()
+                })
+                .collect();
+            let merkle_proof = layer
+                .tree
+                .prove_batch(&indexes)
+                .expect("the coset indexes are in the tree");
+            layers.push(FriLayerProof {
+                root: layer.tree.root(),
+                cosets,
+                merkle_proof,
+            });
+            size = num_cosets;
+        }
+        Ok(FriProof {
+            layers,
+            remainder: self.remainder.clone(),
+        })
+    }
+
+    // Commits to the evaluations, draws the query positions from the transcript and opens the
+    // layers at these positions
+    pub fn prove(
+        options: FriOptions,
+        evaluations: Vec<E>,
+        transcript: &mut Transcript<H>,
+    ) -> Result<FriProof<B, E, N>, FriError> {
+        let domain_size = evaluations.len();
+        let prover = Self::commit(options, evaluations, transcript)?;
+        let positions = draw_query_positions(transcript, domain_size, options.num_queries);
+        prover.query(&positions)
+    }
+
+    // Returns the remainder, from the constant coefficient up
+    pub fn remainder(&self) -> &[E] {
+        &self.remainder
+    }
+}
+
+// FRI VERIFIER
+// ================================================================================================
+
+// Verifier holding the commitments of a proof and the challenges drawn from them
+#[derive(Clone, Debug)]
+pub struct FriVerifier<B, E, H, const N: usize = 1>
+where
+    B: Field + PrimeField,
+    E: Field + ExtensionOf<B>,
+    H: Permutation<Field = B>,
+{
+    options: FriOptions,
+    domain: EvaluationDomain<B>,
+    proof: FriProof<B, E, N>,
+    alphas: Vec<E>,
+    _hasher: PhantomData<H>,
+}
+
+impl<B, E, H, const N: usize> FriVerifier<B, E, H, N>
+where
+    B: Field + PrimeField,
+    E: Field + ExtensionOf<B>,
+    H: Permutation<Field = B>,
+{
+    // Appends the commitments of the proof to the transcript and draws the folding challenges,
+    // as the prover did
+    pub fn new(
+        options: FriOptions,
+        domain_size: usize,
+        proof: FriProof<B, E, N>,
+        transcript: &mut Transcript<H>,
+    ) -> Result<Self, FriError> {
+        check_digest_size::<B, N>()?;
+        let domain = options.domain::<B>(domain_size)?;
+        let num_layers = options.num_layers(domain_size);
+        if proof.layers.len() != num_layers {
+            return Err(FriError::NumberOfLayersMismatch(
+                num_layers,
+                proof.layers.len(),
+            ));
+        }
+        let remainder_size = options.remainder_size(domain_size);
+        if proof.remainder.len() != remainder_size {
+            return Err(FriError::RemainderSizeMismatch(
+                remainder_size,
+                proof.remainder.len(),
+            ));
+        }
+        let alphas = proof
+            .layers
+            .iter()
+            .map(|layer| {
+                transcript.append_field(b"fri layer root", &layer.root);
+                draw_challenge::<B, E, H>(transcript)
+            })
+            .collect();
+        transcript.append_field(b"fri remainder", &to_base_elements(&proof.remainder));
+        Ok(Self {
+            options,
+            domain,
+            proof,
+            alphas,
+            _hasher: PhantomData,
+        })
+    }
+
+    // Checks the openings of the layers at the positions of the first evaluation domain, and that
+    // each layer folds into the next one and the last one into the remainder. Returns the values
+    // of the first layer at the positions, which a protocol built on FRI compares with its own.
+    pub fn verify_queries(&self, positions: &[usize]) -> Result<Vec<E>, FriError> {
+        let domain_size = self.domain.size();
+        if let Some(&position) = positions.iter().find(|&&p| p >= domain_size) {
+            return Err(FriError::PositionOutOfBounds(domain_size, position));
+        }
+        let folding_factor = self.options.folding_factor;
+        let mut size = domain_size;
+        let mut offset = B::GENERATOR;
+        let mut generator = self.domain.generator();
+        let mut first_values = None;
+        let mut values = Vec::new();
+
+        for (i, (layer, &alpha)) in self.proof.layers.iter().zip(&self.alphas).enumerate() {
+            let num_cosets = size / folding_factor;
+            let indexes = coset_indexes(positions, num_cosets);
+            if layer.cosets.len() != indexes.len()
+                || layer
+                    .cosets
+                    .iter()
+                    .any(|coset| coset.len() != folding_factor)
+            {
+                return Err(FriError::InvalidLayerCommitment(i));
+            }
+            let leaves: Vec<[B; N]> = layer
+                .cosets
+                .iter()
+                .map(|coset| hash_coset::<B, E, H, N>(coset))
+                .collect();
+            BinaryMerkleTree::<WideHasher<H, N>>::verify_batch(
+                layer.root,
+                num_cosets,
+                &indexes,
+                &leaves,
+                &layer.merkle_proof,
+            )
+            .map_err(|_| FriError::InvalidLayerCommitment(i))?;
+
+            // the coset of the position p is the one of index p mod num_cosets, in which it is at
+            // position (p mod size) / num_cosets
+            let coset_of =
+                |p: usize| &layer.cosets[indexes.binary_search(&(p % num_cosets)).unwrap()];
+            let layer_values: Vec<E> = positions
+                .iter()
+                .map(|&p| coset_of(p)[(p % size) / num_cosets])
+                .collect();
+            match first_values {
+                None => first_values = Some(layer_values),
+                Some(_) if layer_values != values => return Err(FriError::InvalidFolding(i)),
+                Some(_) => {}
+            }
+
+            let (offset_inv, generator_inv) =
+                (offset.invert().unwrap(), generator.invert().unwrap());
+            let root_inv = generator_inv.power_by([num_cosets as u64]);
+            let scale = inverse_of_power_of_two::<B>(folding_factor);
+            values = positions
+                .iter()
+                .map(|&p| {
+                    let index = (p % num_cosets) as u64;
+                    let x_inv = offset_inv * generator_inv.power_by([index]);
+                    fold_coset(coset_of(p), x_inv, root_inv, scale, alpha)
+                })
+                .collect();
+            offset = offset.power_by([folding_factor as u64]);
+            generator = generator.power_by([folding_factor as u64]);
+            size = num_cosets;
+        }
+
+        let remainder_values: Vec<E> = positions
+            .iter()
+            .map(|&p| {
+                let x = offset * generator.power_by([(p % size) as u64]);
+                evaluate(&self.proof.remainder, x)
+            })
+            .collect();
+        match first_values {
+            None => Ok(remainder_values),
+            Some(_) if remainder_values != values => Err(FriError::InvalidRemainder),
+            Some(first_values) => Ok(first_values),
+        }
+    }
+
+    // Checks the proof for evaluations over the domain of the given size, drawing the query
+    // positions from the transcript as the prover did
+    pub fn verify(
+        options: FriOptions,
+        domain_size: usize,
+        proof: FriProof<B, E, N>,
+        transcript: &mut Transcript<H>,
+    ) -> Result<(), FriError> {
+        let verifier = Self::new(options, domain_size, proof, transcript)?;
+        let positions = draw_query_positions(transcript, domain_size, options.num_queries);
+        verifier.verify_queries(&positions).map(|_| ())
+    }
+}
+
+// Draws num_queries positions of the evaluation domain from the transcript, and returns the
+// distinct ones in increasing order
+pub fn draw_query_positions<H: Permutation>(
+    transcript: &mut Transcript<H>,
+    domain_size: usize,
+    num_queries: usize,
+) -> Vec<usize> {
+    let log_size = domain_size.trailing_zeros() as usize;
+    let bits = transcript.challenge_bits(b"fri query positions", num_queries * log_size);
+    let mut positions: Vec<usize> = bits
+        .chunks(log_size.max(1))
+        .map(|chunk| {
+            chunk
+                .iter()
+                .rev()
+                .fold(0, |acc, &bit| 2 * acc + bit as usize)
+        })
+        .collect();
+    positions.sort_unstable();
+    positions.dedup();
+    positions
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+// Draws a folding challenge of the extension, one base field coordinate at a time
+fn draw_challenge<B, E, H>(transcript: &mut Transcript<H>) -> E
+where
+    B: Field + PrimeField,
+    E: Field + ExtensionOf<B>,
+    H: Permutation<Field = B>,
+{
+    let coordinates: Vec<B> = (0..<E as ExtensionOf<B>>::DEGREE)
+        .map(|_| transcript.challenge_scalar(b"fri folding challenge"))
+        .collect();
+    <E as ExtensionOf<B>>::from_base_elements(&coordinates)
+}
+
+fn to_base_elements<B: Field, E: ExtensionOf<B> + Copy>(values: &[E]) -> Vec<B> {
+    values
+        .iter()
+        .flat_map(|&value| value.to_base_elements())
+        .collect()
+}
+
+// Hashes the base field coordinates of the values of a coset into a leaf
+fn hash_coset<B, E, H, const N: usize>(coset: &[E]) -> [B; N]
+where
+    B: Field + PrimeField,
+    E: Field + ExtensionOf<B>,
+    H: Permutation<Field = B>,
+{
+    WideHasher::<H, N>::hash(&to_base_elements(coset))
+}
+
+// Checks that digests of N elements of B have at least MIN_DIGEST_BITS bits
+fn check_digest_size<B: PrimeField, const N: usize>() -> Result<(), FriError> {
+    let bits = N * modulus_bits::<B>();
+    if bits < MIN_DIGEST_BITS {
+        return Err(FriError::DigestTooShort(bits));
+    }
+    Ok(())
+}
+
+// Returns the cosets of the evaluations for the folding factor: the j-th one holds the values at
+// positions j, j + n / k, ..., j + (k - 1) * n / k
+fn cosets<E: Field>(evaluations: &[E], folding_factor: usize) -> Vec<Vec<E>> {
+    let num_cosets = evaluations.len() / folding_factor;
+    (0..num_cosets)
+        .map(|j| {
+            (0..folding_factor)
+                .map(|t| evaluations[j + t * num_cosets])
+                .collect()
+        })
+        .collect()
+}
+
+// Returns the distinct indexes of the cosets containing the positions, in increasing order
+fn coset_indexes(positions: &[usize], num_cosets: usize) -> Vec<usize> {
+    let mut indexes: Vec<usize> = positions.iter().map(|&p| p % num_cosets).collect();
+    indexes.sort_unstable();
+    indexes.dedup();
+    indexes
+}
+
+// Folds the layer over the coset offset * <generator> into the layer over its image under
+// x -> x^k
+fn fold_layer<B, E>(
+    evaluations: &[E],
+    folding_factor: usize,
+    offset: B,
+    generator: B,
+    alpha: E,
+) -> Vec<E>
+where
+    B: Field + PrimeField,
+    E: Field + ExtensionOf<B>,
+{
+    let num_cosets = evaluations.len() / folding_factor;
+    let (offset_inv, generator_inv) = (offset.invert().unwrap(), generator.invert().unwrap());
+    let root_inv = generator_inv.power_by([num_cosets as u64]);
+    let scale = inverse_of_power_of_two::<B>(folding_factor);
+    let mut x_invs = Vec::with_capacity(num_cosets);
+    let mut x_inv = offset_inv;
+    for _ in 0..num_cosets {
+        x_invs.push(x_inv);
+        x_inv *= generator_inv;
+    }
+    let fold = |(j, x_inv): (usize, &B)| {
+        let coset: Vec<E> = (0..folding_factor)
+            .map(|t| evaluations[j + t * num_cosets])
+            .collect();
+        fold_coset(&coset, *x_inv, root_inv, scale, alpha)
+    };
+    #[cfg(feature = "concurrent")]
+    let folded = x_invs.par_iter().enumerate().map(fold).collect();
+    #[cfg(not(feature = "concurrent"))]
+    let folded = x_invs.iter().enumerate().map(fold).collect();
+    folded
+}
+
+// Folds the values of p at the coset {x * r^t} of the k-th roots of unity r^t into the value at
+// x^k of sum_i alpha^i * p_i, where p(X) = sum_i X^i * p_i(X^k). The values p_i(x^k) are the
+// coefficients of the polynomial of degree less than k interpolating the coset, obtained by an
+// inverse DFT: p_i(x^k) = x^-i / k * sum_t p(x * r^t) * r^(-i * t).
+fn fold_coset<B, E>(coset: &[E], x_inv: B, root_inv: B, scale: B, alpha: E) -> E
+where
+    B: Field + PrimeField,
+    E: Field + ExtensionOf<B>,
+{
+    let mut coefficients = Vec::with_capacity(coset.len());
+    let mut factor = scale;
+    let mut root_inv_i = B::ONE;
+    for _ in 0..coset.len() {
+        let mut root_inv_it = B::ONE;
+        let mut sum = E::ZERO;
+        for &value in coset {
+            sum += value.mul_base(root_inv_it);
+            root_inv_it *= root_inv_i;
+        }
+        coefficients.push(sum.mul_base(factor));
+        factor *= x_inv;
+        root_inv_i *= root_inv;
+    }
+    coefficients
+        .iter()
+        .rev()
+        .fold(E::ZERO, |acc, &coefficient| acc * alpha + coefficient)
+}
+
+// Returns the coefficients of degree less than size of the polynomial interpolating the
+// evaluations over the coset offset * <w>. The inverse DFT is applied to every base field
+// coordinate of the values.
+fn interpolate_remainder<B, E>(evaluations: Vec<E>, offset: B, size: usize) -> Vec<E>
+where
+    B: Field + PrimeField,
+    E: Field + ExtensionOf<B>,
+{
+    let domain = EvaluationDomain::<B>::new(evaluations.len())
+        .expect("the remainder domain is a subgroup of the first one");
+    let degree = <E as ExtensionOf<B>>::DEGREE;
+    let mut columns = vec![Vec::with_capacity(evaluations.len()); degree];
+    for value in evaluations {
+        for (column, coordinate) in columns.iter_mut().zip(value.to_base_elements()) {
+            column.push(coordinate);
+        }
+    }
+    for column in columns.iter_mut() {
+        domain
+            .coset_ifft(column, offset)
+            .expect("the offset is a power of the generator and the column fills the domain");
+    }
+    (0..size)
+        .map(|i| {
+            let coordinates: Vec<B> = columns.iter().map(|column| column[i]).collect();
+            <E as ExtensionOf<B>>::from_base_elements(&coordinates)
+        })
+        .collect()
+}
+
+// Returns 1 / n for a power of two n, built from ONE as from(u64) is not a Montgomery conversion
+// in every field
+fn inverse_of_power_of_two<B: Field>(n: usize) -> B {
+    let two_inv = (B::ONE + B::ONE).invert().unwrap();
+    two_inv.power_by([n.trailing_zeros() as u64])
+}
+
+// Evaluates the polynomial with coefficients in E at a point of the base field
+fn evaluate<B, E>(coefficients: &[E], x: B) -> E
+where
+    B: Field + PrimeField,
+    E: Field + ExtensionOf<B>,
+{
+    coefficients
+        .iter()
+        .rev()
+        .fold(E::ZERO, |acc, &coefficient| acc.mul_base(x) + coefficient)
+}
+
+// ERRORS
+// ================================================================================================
+
+// Errors which can occur when configuring FRI, proving or verifying
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FriError {
+    // The blowup factor is not a power of two larger than one
+    InvalidBlowupFactor(usize),
+    // The folding factor is not a power of two between 2 and 16
+    InvalidFoldingFactor(usize),
+    // No queries were requested
+    InvalidNumberOfQueries(usize),
+    // The largest degree of the remainder plus one is not a power of two
+    InvalidMaxRemainderDegree(usize),
+    // The size of the evaluation domain is not a power of two at least as large as the blowup
+    // factor, or the field has no subgroup of this size
+    InvalidDomainSize(usize),
+    // A queried position is outside of the evaluation domain
+    PositionOutOfBounds(usize, usize),
+    // The proof does not have the number of layers of the options
+    NumberOfLayersMismatch(usize, usize),
+    // The remainder of the proof does not have the number of coefficients of the options
+    RemainderSizeMismatch(usize, usize),
+    // The openings of the layer at the index do not match its commitment
+    InvalidLayerCommitment(usize),
+    // The values of the layer at the index differ at a queried position from the folding of the
+    // previous layer
+    InvalidFolding(usize),
+    // The last layer does not match the remainder at a queried position
+    InvalidRemainder,
+    // The Merkle digests of N base field elements have fewer than MIN_DIGEST_BITS bits
+    DigestTooShort(usize),
+}
+
+impl fmt::Display for FriError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidBlowupFactor(factor) => {
+                write!(
+                    f,
+                    "the blowup factor must be a power of two larger than one, but was {factor}"
+                )
+            }
+            Self::InvalidFoldingFactor(factor) => {
+                write!(
+                    f,
+                    "the folding factor must be a power of two from 2 to 16, but was {factor}"
+                )
+            }
+            Self::InvalidNumberOfQueries(count) => {
+                write!(
+                    f,
+                    "at least one query is needed, but {count} were requested"
+                )
+            }
+            Self::InvalidMaxRemainderDegree(degree) => {
+                write!(
+                    f,
+                    "the remainder degree plus one must be a power of two, but was {degree}"
+                )
+            }
+            Self::InvalidDomainSize(size) => {
+                write!(f, "no evaluation domain of size {size} is supported")
+            }
+            Self::PositionOutOfBounds(size, position) => {
+                write!(
+                    f,
+                    "position {position} is outside of the evaluation domain of size {size}"
+                )
+            }
+            Self::NumberOfLayersMismatch(expected, actual) => {
+                write!(f, "expected {expected} layers, but the proof has {actual}")
+            }
+            Self::RemainderSizeMismatch(expected, actual) => {
+                write!(
+                    f,
+                    "expected a remainder of {expected} coefficients, but the proof has {actual}"
+                )
+            }
+            Self::InvalidLayerCommitment(layer) => {
+                write!(
+                    f,
+                    "the openings of layer {layer} do not match its commitment"
+                )
+            }
+            Self::InvalidFolding(layer) => {
+                write!(f, "layer {} does not fold into layer {layer}", layer - 1)
+            }
+            Self::InvalidRemainder => write!(f, "the last layer does not match the remainder"),
+            Self::DigestTooShort(bits) => {
+                write!(
+                    f,
+                    "Merkle digests must have at least {MIN_DIGEST_BITS} bits, but have {bits}"
+                )
+            }
+        }
+    }
+}
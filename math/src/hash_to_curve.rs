@@ -0,0 +1,99 @@
+use hash::hash_to_field::HashToFieldError;
+use traits::traits::Field;
+
+use crate::weierstrass::{AffinePoint, CurveParameters, ProjectivePoint};
+
+// HASH TO CURVE
+// ================================================================================================
+// hash_to_curve of RFC 9380 for short Weierstrass curves with the simplified SWU map. The map is
+// not defined when a or b is zero, in which case field elements are mapped to an isogenous curve
+// E' : y^2 = x^3 + A' * x + B' and carried to the curve by the isogeny (RFC 9380, section 6.6.3).
+
+// Parameters of a hash to curve suite of RFC 9380 with the simplified SWU map
+pub trait HashToCurveParameters: CurveParameters {
+    // Coefficients A' and B' of the curve E' of the simplified SWU map: the curve itself, or an
+    // isogenous curve when a or b is zero
+    const ISO_A: Self::BaseField = Self::COEFF_A;
+    const ISO_B: Self::BaseField = Self::COEFF_B;
+
+    // Non-square Z of the simplified SWU map
+    const SSWU_Z: Self::BaseField;
+
+    // Effective cofactor h_eff of the suite, as little-endian u64 words
+    const H_EFF: &'static [u64];
+
+    // Hashes a message to count elements of the base field as specified by RFC 9380, section 5.2
+    fn hash_to_field(
+        msg: &[u8],
+        dst: &[u8],
+        count: usize,
+    ) -> Result<Vec<Self::BaseField>, HashToFieldError>;
+
+    // Sign of an element as defined by RFC 9380, section 4.1
+    fn sgn0(a: &Self::BaseField) -> bool;
+
+    // Maps a point of E' to the curve: the identity when E' is the curve, otherwise the isogeny
+    fn iso_map(x: Self::BaseField, y: Self::BaseField) -> AffinePoint<Self> {
+        AffinePoint::new(x, y)
+    }
+}
+
+// Hashes a message to a point of the prime order subgroup as specified by the hash_to_curve
+// function of RFC 9380: two field elements are mapped to the curve and their sum is multiplied
+// by h_eff. The domain separation tag must be unique to the application, and an empty one is
+// rejected. This runs in variable time and is meant for public messages.
+pub fn hash_to_curve<C: HashToCurveParameters>(
+    msg: &[u8],
+    dst: &[u8],
+) -> Result<ProjectivePoint<C>, HashToFieldError> {
+    let u = C::hash_to_field(msg, dst, 2)?;
+    let q0 = map_to_curve::<C>(&u[0]);
+    let q1 = map_to_curve::<C>(&u[1]);
+    Ok(clear_cofactor(&(ProjectivePoint::from(q0) + q1)))
+}
+
+// Maps a field element to a point of the curve: the simplified SWU map to E' followed by the
+// isogeny, if any. The point may have a cofactor component.
+pub fn map_to_curve<C: HashToCurveParameters>(u: &C::BaseField) -> AffinePoint<C> {
+    let (x, y) = map_to_curve_simple_swu::<C>(u);
+    C::iso_map(x, y)
+}
+
+// Maps a field element to the affine coordinates of a point of E' with the simplified SWU map of
+// RFC 9380, section 6.6.2
+pub fn map_to_curve_simple_swu<C: HashToCurveParameters>(
+    u: &C::BaseField,
+) -> (C::BaseField, C::BaseField) {
+    let zu2 = C::SSWU_Z * u.square();
+    let den = zu2.square() + zu2;
+    // x1 = -B' / A' * (1 + 1 / (Z^2 * u^4 + Z * u^2)), or B' / (Z * A') when the denominator is
+    // zero
+    let x1 = if den.is_zero() {
+        C::ISO_B * (C::SSWU_Z * C::ISO_A).invert().unwrap()
+    } else {
+        -C::ISO_B * C::ISO_A.invert().unwrap() * (C::BaseField::ONE + den.invert().unwrap())
+    };
+    let y1 = iso_curve_rhs::<C>(x1).sqrt();
+    let (x, y) = if bool::from(y1.is_some()) {
+        (x1, y1.unwrap())
+    } else {
+        // g(Z * u^2 * x1) = Z^3 * u^6 * g(x1) is a square when g(x1) is not
+        let x2 = zu2 * x1;
+        (x2, iso_curve_rhs::<C>(x2).sqrt().unwrap())
+    };
+    let y = if C::sgn0(u) == C::sgn0(&y) { y } else { -y };
+    (x, y)
+}
+
+// Returns [h_eff]P, which lies in the prime order subgroup
+pub fn clear_cofactor<C: HashToCurveParameters>(p: &ProjectivePoint<C>) -> ProjectivePoint<C> {
+    p.mul_by_words(C::H_EFF)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+// Returns x^3 + A' * x + B'
+fn iso_curve_rhs<C: HashToCurveParameters>(x: C::BaseField) -> C::BaseField {
+    x.cube() + C::ISO_A * x + C::ISO_B
+}
@@ -0,0 +1,317 @@
+use std::fmt;
+
+#[cfg(feature = "insecure-setup")]
+use traits::traits::Field;
+use traits::traits::{CurveGroup, Pairing};
+
+#[cfg(feature = "insecure-setup")]
+use crate::{batch_inversion::batch_invert, fft::EvaluationDomain};
+use crate::{
+    msm::msm,
+    polynomial::{DensePolynomial, PolynomialError},
+};
+
+// STRUCTURED REFERENCE STRING
+// ================================================================================================
+
+// Structured reference string of the KZG polynomial commitment scheme for a secret tau:
+// [tau^i]G1 for the monomial basis, [tau^i]G2 for the verifier, and optionally [L_i(tau)]G1 for
+// the Lagrange basis of the multiplicative subgroup of size n, in natural order, where L_i is the
+// polynomial of degree less than n which is 1 at w^i and 0 at the other powers of w. The first
+// powers are the generators the commitments are relative to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Srs<E: Pairing> {
+    g1_powers: Vec<E::G1>,
+    g2_powers: Vec<E::G2>,
+    g1_lagrange: Vec<E::G1>,
+}
+
+impl<E: Pairing> Srs<E> {
+    // Returns the reference string with the given powers, which may come from a trusted setup
+    // ceremony. This checks with a single pairing that the G1 and G2 powers share the same secret,
+    // and that the Lagrange basis, when not empty, sums to the G1 generator as it must for a
+    // subgroup of size a power of two. The other powers are not checked.
+    pub fn new(
+        g1_powers: Vec<E::G1>,
+        g2_powers: Vec<E::G2>,
+        g1_lagrange: Vec<E::G1>,
+    ) -> Result<Self, KzgError> {
+        if g1_powers.is_empty() || g2_powers.len() < 2 {
+            return Err(KzgError::SetupTooSmall(g1_powers.len(), g2_powers.len()));
+        }
+        // e(G1, [tau]G2) == e([tau]G1, G2)
+        if g1_powers.len() > 1
+            && !E::pairing_check(&[(g1_powers[0], g2_powers[1]), (-g1_powers[1], g2_powers[0])])
+        {
+            return Err(KzgError::InconsistentSetup);
+        }
+        if !g1_lagrange.is_empty() {
+            if !g1_lagrange.len().is_power_of_two() {
+                return Err(KzgError::InvalidLagrangeBasisSize(g1_lagrange.len()));
+            }
+            let sum = g1_lagrange
+                .iter()
+                .fold(E::G1::identity(), |acc, &point| acc + point);
+            if sum != g1_powers[0] {
+                return Err(KzgError::InconsistentSetup);
+            }
+        }
+        Ok(Self {
+            g1_powers,
+            g2_powers,
+            g1_lagrange,
+        })
+    }
+
+    // Returns the reference string for the given secret, with g1_count and g2_count powers, and
+    // the Lagrange basis of the subgroup of size g1_count when g1_count is a power of two. Anyone
+    // knowing tau can open a commitment to any value, so this is only meant for tests.
+    #[cfg(feature = "insecure-setup")]
+    pub fn insecure_from_secret(tau: E::ScalarField, g1_count: usize, g2_count: usize) -> Self {
+        assert!(
+            g1_count > 0 && g2_count > 1,
+            "the setup needs at least 1 power in G1 and 2 powers in G2"
+        );
+        let g1 = E::G1::generator();
+        let g2 = E::G2::generator();
+        let tau_powers = powers(tau, g1_count.max(g2_count));
+        let g1_powers = tau_powers[..g1_count]
+            .iter()
+            .map(|&power| g1 * power)
+            .collect();
+        let g2_powers = tau_powers[..g2_count]
+            .iter()
+            .map(|&power| g2 * power)
+            .collect();
+
+        // L_i(tau) = w^i * (tau^n - 1) / (n * (tau - w^i))
+        let g1_lagrange = match EvaluationDomain::<E::ScalarField>::new(g1_count) {
+            Ok(domain) => {
+                let elements = domain.elements();
+                let mut denominators: Vec<E::ScalarField> = elements
+                    .iter()
+                    .map(|&element| E::ScalarField::from(g1_count as u64) * (tau - element))
+                    .collect();
+                batch_invert(&mut denominators);
+                let numerator = tau.power_by([g1_count as u64]) - E::ScalarField::ONE;
+                elements
+                    .iter()
+                    .zip(denominators)
+                    .map(|(&element, denominator_inv)| g1 * (element * numerator * denominator_inv))
+                    .collect()
+            }
+            Err(_) => Vec::new(),
+        };
+        Self {
+            g1_powers,
+            g2_powers,
+            g1_lagrange,
+        }
+    }
+
+    // Returns the powers [tau^i]G1
+    pub fn g1_powers(&self) -> &[E::G1] {
+        &self.g1_powers
+    }
+
+    // Returns the powers [tau^i]G2
+    pub fn g2_powers(&self) -> &[E::G2] {
+        &self.g2_powers
+    }
+
+    // Returns the Lagrange basis [L_i(tau)]G1, which is empty when the setup does not provide it
+    pub fn g1_lagrange(&self) -> &[E::G1] {
+        &self.g1_lagrange
+    }
+
+    // Returns the largest degree of the polynomials which can be committed to
+    pub fn max_degree(&self) -> usize {
+        self.g1_powers.len() - 1
+    }
+
+    // COMMITMENTS
+    // --------------------------------------------------------------------------------------------
+
+    // Returns the commitment [p(tau)]G1 to the polynomial
+    pub fn commit(&self, poly: &DensePolynomial<E::ScalarField>) -> Result<E::G1, KzgError> {
+        if poly.degree() > self.max_degree() {
+            return Err(KzgError::DegreeTooLarge(poly.degree(), self.max_degree()));
+        }
+        let coeffs = poly.coeffs();
+        Ok(msm(&self.g1_powers[..coeffs.len()], coeffs))
+    }
+
+    // Returns the commitment to the polynomial taking the given values over the subgroup of the
+    // Lagrange basis, in natural order. This is the same commitment as the one to the
+    // interpolated polynomial, without interpolating it.
+    pub fn commit_lagrange(&self, evaluations: &[E::ScalarField]) -> Result<E::G1, KzgError> {
+        if evaluations.len() != self.g1_lagrange.len() {
+            return Err(KzgError::LagrangeBasisSizeMismatch(
+                self.g1_lagrange.len(),
+                evaluations.len(),
+            ));
+        }
+        Ok(msm(&self.g1_lagrange, evaluations))
+    }
+
+    // OPENINGS
+    // --------------------------------------------------------------------------------------------
+
+    // Returns the value of the polynomial at the point and the proof of this value: the
+    // commitment to the quotient (p(X) - p(z)) / (X - z)
+    pub fn open(
+        &self,
+        poly: &DensePolynomial<E::ScalarField>,
+        point: E::ScalarField,
+    ) -> Result<(E::ScalarField, E::G1), KzgError> {
+        let (values, proof) = self.open_batch(poly, &[point])?;
+        Ok((values[0], proof))
+    }
+
+    // Returns the values of the polynomial at the points and a single proof of all of them: the
+    // commitment to the quotient (p(X) - I(X)) / Z(X), where I interpolates the values over the
+    // points and Z vanishes over them. The remainder of the division of p by Z is I, so the
+    // quotient is obtained without interpolating.
+    pub fn open_batch(
+        &self,
+        poly: &DensePolynomial<E::ScalarField>,
+        points: &[E::ScalarField],
+    ) -> Result<(Vec<E::ScalarField>, E::G1), KzgError> {
+        if let Some(i) = (1..points.len()).find(|&i| points[..i].contains(&points[i])) {
+            return Err(KzgError::Polynomial(PolynomialError::DuplicatePoint(i)));
+        }
+        let vanishing = DensePolynomial::vanishing(points);
+        let (quotient, _) = poly.divide_with_remainder(&vanishing)?;
+        let values = poly.evaluate_many(points);
+        Ok((values, self.commit(&quotient)?))
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    // Checks the proof that the polynomial committed to takes the value at the point, with the
+    // pairing equation e(C - [y]G1 + [z]proof, G2) == e(proof, [tau]G2)
+    pub fn verify(
+        &self,
+        commitment: &E::G1,
+        point: E::ScalarField,
+        value: E::ScalarField,
+        proof: &E::G1,
+    ) -> bool {
+        let lhs = *commitment - self.g1_powers[0] * value + *proof * point;
+        E::pairing_check(&[(lhs, self.g2_powers[0]), (-*proof, self.g2_powers[1])])
+    }
+
+    // Checks the proof that the polynomial committed to takes the values at the points, with the
+    // pairing equation e(C - [I(tau)]G1, G2) == e(proof, [Z(tau)]G2). Z has degree the number
+    // of points, which cannot exceed the number of G2 powers minus one.
+    pub fn verify_batch(
+        &self,
+        commitment: &E::G1,
+        points: &[E::ScalarField],
+        values: &[E::ScalarField],
+        proof: &E::G1,
+    ) -> Result<bool, KzgError> {
+        if points.len() >= self.g2_powers.len() {
+            return Err(KzgError::TooManyPoints(
+                points.len(),
+                self.g2_powers.len() - 1,
+            ));
+        }
+        let interpolant = DensePolynomial::interpolate(points, values)?;
+        let vanishing = DensePolynomial::vanishing(points);
+        let lhs = *commitment - self.commit(&interpolant)?;
+        let vanishing_commitment = msm(
+            &self.g2_powers[..vanishing.coeffs().len()],
+            vanishing.coeffs(),
+        );
+        Ok(E::pairing_check(&[
+            (lhs, self.g2_powers[0]),
+            (-*proof, vanishing_commitment),
+        ]))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+// Returns 1, x, ..., x^(n - 1)
+#[cfg(feature = "insecure-setup")]
+fn powers<F: Field>(x: F, n: usize) -> Vec<F> {
+    let mut result = Vec::with_capacity(n);
+    let mut power = F::ONE;
+    for _ in 0..n {
+        result.push(power);
+        power *= x;
+    }
+    result
+}
+
+// ERRORS
+// ================================================================================================
+
+// Errors which can occur when building a reference string, committing or opening
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum KzgError {
+    // The setup has fewer than 1 power in G1 or 2 powers in G2
+    SetupTooSmall(usize, usize),
+    // The powers of the setup are not powers of the same secret
+    InconsistentSetup,
+    // The size of the Lagrange basis is not a power of two
+    InvalidLagrangeBasisSize(usize),
+    // The degree of the polynomial exceeds the largest degree supported by the setup
+    DegreeTooLarge(usize, usize),
+    // The number of evaluations differs from the size of the Lagrange basis
+    LagrangeBasisSizeMismatch(usize, usize),
+    // The number of points exceeds the largest number supported by the G2 powers of the setup
+    TooManyPoints(usize, usize),
+    // Dividing or interpolating the polynomials failed
+    Polynomial(PolynomialError),
+}
+
+impl From<PolynomialError> for KzgError {
+    fn from(error: PolynomialError) -> Self {
+        Self::Polynomial(error)
+    }
+}
+
+impl fmt::Display for KzgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SetupTooSmall(g1_count, g2_count) => {
+                write!(f, "the setup needs at least 1 power in G1 and 2 powers in G2, but has {g1_count} and {g2_count}")
+            }
+            Self::InconsistentSetup => {
+                write!(
+                    f,
+                    "the powers of the setup are not powers of the same secret"
+                )
+            }
+            Self::InvalidLagrangeBasisSize(size) => {
+                write!(
+                    f,
+                    "the size of the Lagrange basis must be a power of two, but was {size}"
+                )
+            }
+            Self::DegreeTooLarge(degree, max_degree) => {
+                write!(
+                    f,
+                    "the degree of the polynomial cannot exceed {max_degree}, but was {degree}"
+                )
+            }
+            Self::LagrangeBasisSizeMismatch(expected, actual) => {
+                write!(
+                    f,
+                    "expected {expected} evaluations, but {actual} were provided"
+                )
+            }
+            Self::TooManyPoints(count, max_count) => {
+                write!(
+                    f,
+                    "at most {max_count} points can be opened at once, but {count} were provided"
+                )
+            }
+            Self::Polynomial(error) => write!(f, "{error}"),
+        }
+    }
+}
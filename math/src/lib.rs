@@ -0,0 +1,9 @@
+pub mod batch_inversion;
+pub mod edwards;
+pub mod fft;
+pub mod fri;
+pub mod hash_to_curve;
+pub mod kzg;
+pub mod msm;
+pub mod polynomial;
+pub mod weierstrass;
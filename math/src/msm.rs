@@ -0,0 +1,125 @@
+#[cfg(feature = "concurrent")]
+use rayon::prelude::*;
+use traits::traits::{CurveGroup, Field};
+
+// MULTI-SCALAR MULTIPLICATION
+// ================================================================================================
+
+// Returns sum(scalars[i] * bases[i]) with Pippenger's bucket method. The scalars are split into
+// signed digits of window_size(n) bits, so that a window needs half as many buckets as with
+// unsigned digits: a point with a negative digit is subtracted from the bucket of the opposite
+// digit. Under the concurrent feature the windows are accumulated in parallel.
+// This runs in variable time and is meant for public scalars.
+pub fn msm<G: CurveGroup>(bases: &[G], scalars: &[G::ScalarField]) -> G {
+    assert_eq!(
+        bases.len(),
+        scalars.len(),
+        "expected as many scalars as bases, but {} bases and {} scalars were provided",
+        bases.len(),
+        scalars.len()
+    );
+    if bases.is_empty() {
+        return G::identity();
+    }
+
+    let c = window_size(bases.len());
+    let digits: Vec<Vec<i64>> = scalars.iter().map(|scalar| signed_digits(scalar, c)).collect();
+    let num_windows = digits[0].len();
+
+    #[cfg(feature = "concurrent")]
+    let window_sums: Vec<G> = (0..num_windows)
+        .into_par_iter()
+        .map(|window| window_sum(bases, &digits, window, c))
+        .collect();
+    #[cfg(not(feature = "concurrent"))]
+    let window_sums: Vec<G> = (0..num_windows)
+        .map(|window| window_sum(bases, &digits, window, c))
+        .collect();
+
+    // combines the windows from the most significant one: sum(2^(c * w) * window_sums[w])
+    let mut result = G::identity();
+    for window_sum in window_sums.iter().rev() {
+        for _ in 0..c {
+            result = result.double();
+        }
+        result += *window_sum;
+    }
+    result
+}
+
+// Returns sum(scalars[i] * bases[i]) with one double-and-add scalar multiplication per base. This
+// is the reference msm is checked and benchmarked against.
+pub fn naive_msm<G: CurveGroup>(bases: &[G], scalars: &[G::ScalarField]) -> G {
+    assert_eq!(
+        bases.len(),
+        scalars.len(),
+        "expected as many scalars as bases, but {} bases and {} scalars were provided",
+        bases.len(),
+        scalars.len()
+    );
+    bases
+        .iter()
+        .zip(scalars)
+        .fold(G::identity(), |acc, (&base, &scalar)| acc + base * scalar)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+// Window size minimizing the cost of the bucket method for n points, about log2(n) - log2(log2(n))
+// with a minimum of 3 bits for small inputs
+fn window_size(n: usize) -> usize {
+    if n < 32 {
+        3
+    } else {
+        let log_n = n.ilog2();
+        (log_n - log_n.ilog2() + 2) as usize
+    }
+}
+
+// Splits the scalar into digits of c bits in [-2^(c - 1), 2^(c - 1)], from the least significant
+// one, such that scalar = sum(digits[w] * 2^(c * w)). The digits are obtained from the unsigned
+// windows of get_windows by carrying into the next window whenever a window is larger than
+// 2^(c - 1).
+fn signed_digits<F: Field>(scalar: &F, c: usize) -> Vec<i64> {
+    let windows = scalar.get_windows(c);
+    let radix = 1i64 << c;
+    let half_radix = radix / 2;
+    let mut digits = Vec::with_capacity(windows.len() + 1);
+    let mut carry = 0;
+    for window in windows {
+        let digit = window as i64 + carry;
+        if digit > half_radix {
+            digits.push(digit - radix);
+            carry = 1;
+        } else {
+            digits.push(digit);
+            carry = 0;
+        }
+    }
+    digits.push(carry);
+    digits
+}
+
+// Returns sum(digits[i][window] * bases[i]). buckets[j] holds the sum of the bases whose digit is
+// j + 1, minus the bases whose digit is -(j + 1); the buckets are then added up with a running
+// sum, so that buckets[j] is counted j + 1 times.
+fn window_sum<G: CurveGroup>(bases: &[G], digits: &[Vec<i64>], window: usize, c: usize) -> G {
+    let mut buckets = vec![G::identity(); 1 << (c - 1)];
+    for (&base, scalar_digits) in bases.iter().zip(digits) {
+        let digit = scalar_digits[window];
+        if digit > 0 {
+            buckets[(digit - 1) as usize] += base;
+        } else if digit < 0 {
+            buckets[(-digit - 1) as usize] -= base;
+        }
+    }
+
+    let mut running_sum = G::identity();
+    let mut sum = G::identity();
+    for bucket in buckets.into_iter().rev() {
+        running_sum += bucket;
+        sum += running_sum;
+    }
+    sum
+}
@@ -0,0 +1,497 @@
+use std::{
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+#[cfg(feature = "concurrent")]
+use rayon::prelude::*;
+use traits::traits::{Field, PrimeField};
+
+use crate::{
+    batch_inversion::batch_invert,
+    fft::{EvaluationDomain, FftError},
+};
+
+// Length of the shorter factor below which Karatsuba multiplication falls back to the schoolbook
+// algorithm
+const KARATSUBA_THRESHOLD: usize = 32;
+
+// DENSE POLYNOMIAL
+// ================================================================================================
+
+// Univariate polynomial given by its coefficients, from the constant term up. The coefficients are
+// kept without trailing zeros, so that the zero polynomial has no coefficients and two equal
+// polynomials have the same representation.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DensePolynomial<F: Field> {
+    coeffs: Vec<F>,
+}
+
+impl<F: Field> DensePolynomial<F> {
+    // Returns the polynomial with the given coefficients, starting with the constant term
+    pub fn new(coeffs: Vec<F>) -> Self {
+        let mut polynomial = Self { coeffs };
+        polynomial.trim();
+        polynomial
+    }
+
+    pub fn zero() -> Self {
+        Self { coeffs: Vec::new() }
+    }
+
+    pub fn constant(value: F) -> Self {
+        Self::new(vec![value])
+    }
+
+    // Returns the polynomial X
+    pub fn x() -> Self {
+        Self::new(vec![F::ZERO, F::ONE])
+    }
+
+    pub fn coeffs(&self) -> &[F] {
+        &self.coeffs
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    // Returns the degree of the polynomial, which is 0 for the zero polynomial
+    pub fn degree(&self) -> usize {
+        self.coeffs.len().saturating_sub(1)
+    }
+
+    // Returns the coefficient of the highest power, or ZERO for the zero polynomial
+    pub fn leading_coefficient(&self) -> F {
+        self.coeffs.last().copied().unwrap_or(F::ZERO)
+    }
+
+    // Evaluates the polynomial at x with Horner's rule
+    pub fn evaluate(&self, x: F) -> F {
+        evaluate_coeffs(&self.coeffs, x)
+    }
+
+    // Evaluates the polynomial at each of the points
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        #[cfg(feature = "concurrent")]
+        let evaluations = points.par_iter().map(|&x| self.evaluate(x)).collect();
+        #[cfg(not(feature = "concurrent"))]
+        let evaluations = points.iter().map(|&x| self.evaluate(x)).collect();
+        evaluations
+    }
+
+    // Returns the polynomial multiplied by a constant
+    pub fn scale(&self, factor: F) -> Self {
+        Self::new(self.coeffs.iter().map(|&coeff| coeff * factor).collect())
+    }
+
+    // MULTIPLICATION
+    // --------------------------------------------------------------------------------------------
+
+    // Multiplies with the schoolbook algorithm, in O(n * m) operations
+    pub fn mul_naive(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        Self::new(naive_mul(&self.coeffs, &other.coeffs))
+    }
+
+    // Multiplies with Karatsuba's algorithm, in O(n^1.59) operations. Short factors are multiplied
+    // with the schoolbook algorithm.
+    pub fn mul_karatsuba(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        Self::new(karatsuba_mul(&self.coeffs, &other.coeffs))
+    }
+
+    // DIVISION
+    // --------------------------------------------------------------------------------------------
+
+    // Returns the quotient and the remainder of the division by the divisor, the remainder having
+    // a lower degree than the divisor
+    pub fn divide_with_remainder(&self, divisor: &Self) -> Result<(Self, Self), PolynomialError> {
+        if divisor.is_zero() {
+            return Err(PolynomialError::DivisionByZero);
+        }
+        if self.coeffs.len() < divisor.coeffs.len() {
+            return Ok((Self::zero(), self.clone()));
+        }
+        let leading_inv = divisor.leading_coefficient().invert().unwrap();
+        let divisor_degree = divisor.degree();
+        let mut remainder = self.coeffs.clone();
+        let mut quotient = vec![F::ZERO; self.coeffs.len() - divisor_degree];
+        for i in (0..quotient.len()).rev() {
+            let factor = remainder[i + divisor_degree] * leading_inv;
+            quotient[i] = factor;
+            for (r, &d) in remainder[i..].iter_mut().zip(&divisor.coeffs) {
+                *r -= factor * d;
+            }
+        }
+        remainder.truncate(divisor_degree);
+        Ok((Self::new(quotient), Self::new(remainder)))
+    }
+
+    // INTERPOLATION AND VANISHING POLYNOMIALS
+    // --------------------------------------------------------------------------------------------
+
+    // Returns the polynomial of degree less than the number of points taking the value ys[i] at
+    // xs[i], with Lagrange interpolation in O(n^2) operations. The points must be distinct.
+    pub fn interpolate(xs: &[F], ys: &[F]) -> Result<Self, PolynomialError> {
+        if xs.len() != ys.len() {
+            return Err(PolynomialError::NumberOfValuesMismatch(xs.len(), ys.len()));
+        }
+        if let Some(i) = (1..xs.len()).find(|&i| xs[..i].contains(&xs[i])) {
+            return Err(PolynomialError::DuplicatePoint(i));
+        }
+        // the basis polynomial of x_i is Z / (X - x_i), whose value at x_i is Z'(x_i), where Z is
+        // the vanishing polynomial of the points
+        let vanishing = Self::vanishing(xs);
+        // the factors i are built from ONE as from(u64) is not a Montgomery conversion in every
+        // field
+        let mut i = F::ZERO;
+        let derivative: Vec<F> = vanishing.coeffs[1..]
+            .iter()
+            .map(|&coeff| {
+                i += F::ONE;
+                coeff * i
+            })
+            .collect();
+        let mut denominators: Vec<F> = xs.iter().map(|&x| evaluate_coeffs(&derivative, x)).collect();
+        batch_invert(&mut denominators);
+
+        let mut coeffs = vec![F::ZERO; xs.len()];
+        for ((&x, &y), &denominator_inv) in xs.iter().zip(ys).zip(&denominators) {
+            let basis = divide_by_linear(&vanishing.coeffs, x);
+            let factor = y * denominator_inv;
+            for (coeff, &b) in coeffs.iter_mut().zip(&basis) {
+                *coeff += factor * b;
+            }
+        }
+        Ok(Self::new(coeffs))
+    }
+
+    // Returns the monic polynomial vanishing exactly at the points: (X - x_0) * ... * (X - x_n-1)
+    pub fn vanishing(points: &[F]) -> Self {
+        let mut coeffs = vec![F::ONE];
+        for &point in points {
+            // multiplies by X - point
+            coeffs.push(F::ZERO);
+            for i in (1..coeffs.len()).rev() {
+                coeffs[i] = coeffs[i - 1] - point * coeffs[i];
+            }
+            coeffs[0] = -point * coeffs[0];
+        }
+        Self::new(coeffs)
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn trim(&mut self) {
+        while self.coeffs.last().is_some_and(|coeff| coeff.is_zero()) {
+            self.coeffs.pop();
+        }
+    }
+}
+
+impl<F: Field + PrimeField> DensePolynomial<F> {
+    // Multiplies by evaluating both factors over a domain large enough for the product, in
+    // O(n log n) operations. When the product is too large for the two-adicity of the field, the
+    // factors are multiplied with Karatsuba's algorithm instead.
+    pub fn mul_ntt(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        let size = (self.coeffs.len() + other.coeffs.len() - 1).next_power_of_two();
+        let domain = match EvaluationDomain::new(size) {
+            Ok(domain) => domain,
+            Err(_) => return self.mul_karatsuba(other),
+        };
+        let mut lhs = padded(&self.coeffs, size);
+        let mut rhs = padded(&other.coeffs, size);
+        // both factors are padded to the size of the domain, so the transforms can't fail
+        domain.fft(&mut lhs).unwrap();
+        domain.fft(&mut rhs).unwrap();
+        for (l, r) in lhs.iter_mut().zip(rhs) {
+            *l *= r;
+        }
+        domain.ifft(&mut lhs).unwrap();
+        Self::new(lhs)
+    }
+
+    // Returns the polynomial of degree less than the size of the domain taking the given values over
+    // the coset offset * domain, in natural order
+    pub fn interpolate_over_coset(
+        domain: &EvaluationDomain<F>,
+        offset: F,
+        evaluations: &[F],
+    ) -> Result<Self, PolynomialError> {
+        if evaluations.len() != domain.size() {
+            return Err(PolynomialError::NumberOfValuesMismatch(domain.size(), evaluations.len()));
+        }
+        let mut coeffs = evaluations.to_vec();
+        domain.coset_ifft(&mut coeffs, offset)?;
+        Ok(Self::new(coeffs))
+    }
+
+    // Returns the polynomial vanishing over the coset offset * domain: X^n - offset^n, where n is
+    // the size of the domain
+    pub fn coset_vanishing(domain: &EvaluationDomain<F>, offset: F) -> Self {
+        let mut coeffs = vec![F::ZERO; domain.size() + 1];
+        coeffs[0] = -offset.power_by([domain.size() as u64]);
+        coeffs[domain.size()] = F::ONE;
+        Self::new(coeffs)
+    }
+
+    // Evaluates the polynomial over the coset offset * domain, in natural order. The domain must be
+    // larger than the degree of the polynomial.
+    pub fn evaluate_over_coset(
+        &self,
+        domain: &EvaluationDomain<F>,
+        offset: F,
+    ) -> Result<Vec<F>, PolynomialError> {
+        if self.coeffs.len() > domain.size() {
+            return Err(PolynomialError::DegreeTooLarge(self.degree(), domain.size()));
+        }
+        let mut evaluations = padded(&self.coeffs, domain.size());
+        domain.coset_fft(&mut evaluations, offset)?;
+        Ok(evaluations)
+    }
+
+    // Evaluates the polynomial over each of the cosets offsets[i] * domain. Under the concurrent
+    // feature the cosets are evaluated in parallel.
+    pub fn evaluate_over_cosets(
+        &self,
+        domain: &EvaluationDomain<F>,
+        offsets: &[F],
+    ) -> Result<Vec<Vec<F>>, PolynomialError> {
+        #[cfg(feature = "concurrent")]
+        let evaluations = offsets
+            .par_iter()
+            .map(|&offset| self.evaluate_over_coset(domain, offset))
+            .collect();
+        #[cfg(not(feature = "concurrent"))]
+        let evaluations = offsets
+            .iter()
+            .map(|&offset| self.evaluate_over_coset(domain, offset))
+            .collect();
+        evaluations
+    }
+}
+
+// OPERATORS
+// ================================================================================================
+
+impl<'a, F: Field> Add<&'a DensePolynomial<F>> for &'a DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn add(self, rhs: &'a DensePolynomial<F>) -> DensePolynomial<F> {
+        let (long, short) = if self.coeffs.len() >= rhs.coeffs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut coeffs = long.coeffs.clone();
+        for (coeff, &s) in coeffs.iter_mut().zip(&short.coeffs) {
+            *coeff += s;
+        }
+        DensePolynomial::new(coeffs)
+    }
+}
+
+impl<'a, F: Field> Sub<&'a DensePolynomial<F>> for &'a DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn sub(self, rhs: &'a DensePolynomial<F>) -> DensePolynomial<F> {
+        let mut coeffs = self.coeffs.clone();
+        if coeffs.len() < rhs.coeffs.len() {
+            coeffs.resize(rhs.coeffs.len(), F::ZERO);
+        }
+        for (coeff, &r) in coeffs.iter_mut().zip(&rhs.coeffs) {
+            *coeff -= r;
+        }
+        DensePolynomial::new(coeffs)
+    }
+}
+
+// Multiplies with Karatsuba's algorithm; for prime fields, mul_ntt is faster on large polynomials
+impl<'a, F: Field> Mul<&'a DensePolynomial<F>> for &'a DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn mul(self, rhs: &'a DensePolynomial<F>) -> DensePolynomial<F> {
+        self.mul_karatsuba(rhs)
+    }
+}
+
+impl<F: Field> Neg for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn neg(self) -> DensePolynomial<F> {
+        DensePolynomial {
+            coeffs: self.coeffs.iter().map(|&coeff| -coeff).collect(),
+        }
+    }
+}
+
+impl<F: Field> Add for DensePolynomial<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        &self + &rhs
+    }
+}
+
+impl<F: Field> Sub for DensePolynomial<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        &self - &rhs
+    }
+}
+
+impl<F: Field> Mul for DensePolynomial<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        &self * &rhs
+    }
+}
+
+impl<F: Field> Neg for DensePolynomial<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        -&self
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn evaluate_coeffs<F: Field>(coeffs: &[F], x: F) -> F {
+    coeffs.iter().rev().fold(F::ZERO, |acc, &coeff| acc * x + coeff)
+}
+
+// Returns the quotient of the division by X - x, with synthetic division
+fn divide_by_linear<F: Field>(coeffs: &[F], x: F) -> Vec<F> {
+    let mut quotient = vec![F::ZERO; coeffs.len().saturating_sub(1)];
+    let mut carry = F::ZERO;
+    for i in (0..quotient.len()).rev() {
+        carry = coeffs[i + 1] + carry * x;
+        quotient[i] = carry;
+    }
+    quotient
+}
+
+fn padded<F: Field>(coeffs: &[F], size: usize) -> Vec<F> {
+    let mut result = coeffs.to_vec();
+    result.resize(size, F::ZERO);
+    result
+}
+
+// Both slices must be non-empty; the product has a.len() + b.len() - 1 coefficients
+fn naive_mul<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut result = vec![F::ZERO; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (r, &y) in result[i..].iter_mut().zip(b) {
+            *r += x * y;
+        }
+    }
+    result
+}
+
+// Both slices must be non-empty; the product has a.len() + b.len() - 1 coefficients
+fn karatsuba_mul<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.len().min(b.len()) <= KARATSUBA_THRESHOLD {
+        return naive_mul(a, b);
+    }
+    let half = a.len().max(b.len()) / 2;
+    let mut result = vec![F::ZERO; a.len() + b.len() - 1];
+
+    // when one factor fits in the low half, only the other one is split
+    if a.len() <= half || b.len() <= half {
+        let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+        let (long_low, long_high) = long.split_at(half);
+        add_into(&mut result, &karatsuba_mul(long_low, short), 0);
+        add_into(&mut result, &karatsuba_mul(long_high, short), half);
+        return result;
+    }
+
+    // (a0 + a1 X^h)(b0 + b1 X^h) = z0 + ((a0 + a1)(b0 + b1) - z0 - z2) X^h + z2 X^2h
+    let (a_low, a_high) = a.split_at(half);
+    let (b_low, b_high) = b.split_at(half);
+    let z0 = karatsuba_mul(a_low, b_low);
+    let z2 = karatsuba_mul(a_high, b_high);
+    let mut z1 = karatsuba_mul(&add_slices(a_low, a_high), &add_slices(b_low, b_high));
+    for (z, &low) in z1.iter_mut().zip(&z0) {
+        *z -= low;
+    }
+    for (z, &high) in z1.iter_mut().zip(&z2) {
+        *z -= high;
+    }
+    add_into(&mut result, &z0, 0);
+    add_into(&mut result, &z1, half);
+    add_into(&mut result, &z2, 2 * half);
+    result
+}
+
+fn add_slices<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut result = vec![F::ZERO; a.len().max(b.len())];
+    add_into(&mut result, a, 0);
+    add_into(&mut result, b, 0);
+    result
+}
+
+// Adds the values to the result, starting at the offset. Terms past the end of the result must be
+// zero, as they would be for the middle term of Karatsuba's algorithm.
+fn add_into<F: Field>(result: &mut [F], values: &[F], offset: usize) {
+    for (r, &value) in result[offset..].iter_mut().zip(values) {
+        *r += value;
+    }
+}
+
+// ERRORS
+// ================================================================================================
+
+// Errors which can occur in polynomial division, interpolation and evaluation
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PolynomialError {
+    // The divisor is the zero polynomial
+    DivisionByZero,
+    // The number of values differs from the number of points
+    NumberOfValuesMismatch(usize, usize),
+    // Returned by interpolation and batch openings when the point at the index is equal to a point
+    // at a smaller index; the index is the first such one
+    DuplicatePoint(usize),
+    // The degree of the polynomial is not smaller than the size of the domain
+    DegreeTooLarge(usize, usize),
+    // Transforming the values over the domain failed
+    Fft(FftError),
+}
+
+impl From<FftError> for PolynomialError {
+    fn from(error: FftError) -> Self {
+        Self::Fft(error)
+    }
+}
+
+impl fmt::Display for PolynomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DivisionByZero => {
+                write!(f, "cannot divide by the zero polynomial")
+            }
+            Self::NumberOfValuesMismatch(expected, actual) => {
+                write!(f, "expected {expected} values, but {actual} were provided")
+            }
+            Self::DuplicatePoint(index) => {
+                write!(f, "interpolation point {index} appears more than once")
+            }
+            Self::DegreeTooLarge(degree, size) => {
+                write!(f, "a polynomial of degree {degree} cannot be evaluated over a domain of size {size}")
+            }
+            Self::Fft(error) => write!(f, "{error}"),
+        }
+    }
+}
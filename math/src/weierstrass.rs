@@ -0,0 +1,911 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use core::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use crypto_bigint::{
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
+    U256,
+};
+use traits::traits::{CurveGroup, Field};
+
+// Parameters of a short Weierstrass curve y^2 = x^3 + a * x + b defined over the base field, whose
+// prime order subgroup is the group of the scalars. a defaults to 0, which holds for the curves of
+// pairing friendly fields such as G1 and G2 of BLS12-381 and BN254; the formulas below have a
+// faster path for this case.
+pub trait CurveParameters: Copy + Clone + Debug + Default + PartialEq + Eq + Send + Sync {
+    // Field over which the curve is defined
+    type BaseField: Field + ConditionallySelectable + 'static;
+
+    // Field of integers modulo the order of the prime order subgroup
+    type ScalarField: Field;
+
+    // Coefficient a of the curve equation
+    const COEFF_A: Self::BaseField = <Self::BaseField as Field>::ZERO;
+
+    // Coefficient b of the curve equation
+    const COEFF_B: Self::BaseField;
+
+    // 3 * b, used by the complete projective formulas
+    const COEFF_B3: Self::BaseField;
+
+    // Affine coordinates of the standard generator
+    const GENERATOR_X: Self::BaseField;
+    const GENERATOR_Y: Self::BaseField;
+
+    // Order of the prime order subgroup
+    const SUBGROUP_ORDER: U256;
+}
+
+// Compressed encoding of the points of a curve, from which the points get the to_compressed and
+// from_compressed methods and their serialization
+pub trait CurveEncoding: CurveParameters {
+    // Size in bytes of an encoded point
+    const ENCODED_BYTES: usize;
+
+    // Byte array of ENCODED_BYTES bytes holding an encoded point
+    type Encoding: AsRef<[u8]> + for<'a> TryFrom<&'a [u8]>;
+
+    // Returns the encoding of the point
+    fn encode(point: &AffinePoint<Self>) -> Self::Encoding;
+
+    // Decodes a point of the curve, rejecting non canonical encodings. The point may lie outside
+    // of the prime order subgroup.
+    fn decode(bytes: &Self::Encoding) -> Result<AffinePoint<Self>, DeserializationError>;
+}
+
+// AFFINE POINT
+// ================================================================================================
+
+// A point (x, y) on the curve. The point at infinity is flagged by `infinity`.
+#[derive(Clone, Copy, Debug, Eq)]
+pub struct AffinePoint<C: CurveParameters> {
+    pub x: C::BaseField,
+    pub y: C::BaseField,
+    pub infinity: bool,
+}
+
+impl<C: CurveParameters> AffinePoint<C> {
+    // returns the point at infinity
+    pub fn identity() -> Self {
+        Self {
+            x: C::BaseField::ZERO,
+            y: C::BaseField::ONE,
+            infinity: true,
+        }
+    }
+    // returns the standard generator of the group
+    pub fn generator() -> Self {
+        Self {
+            x: C::GENERATOR_X,
+            y: C::GENERATOR_Y,
+            infinity: false,
+        }
+    }
+    // returns the affine point (x, y). The caller is responsible for (x, y) being on the curve.
+    pub fn new(x: C::BaseField, y: C::BaseField) -> Self {
+        Self {
+            x,
+            y,
+            infinity: false,
+        }
+    }
+    // checks whether the point is the point at infinity
+    pub fn is_identity(&self) -> bool {
+        self.infinity
+    }
+    // checks whether the point satisfies y^2 = x^3 + a * x + b
+    pub fn is_on_curve(&self) -> bool {
+        self.infinity || self.y.square() == curve_rhs::<C>(self.x)
+    }
+    // checks whether the point lies in the prime order subgroup
+    pub fn is_torsion_free(&self) -> bool {
+        ProjectivePoint::from(*self).is_torsion_free()
+    }
+    // returns the projective representation of the point
+    pub fn to_projective(&self) -> ProjectivePoint<C> {
+        ProjectivePoint::from(*self)
+    }
+    // returns the jacobian representation of the point
+    pub fn to_jacobian(&self) -> JacobianPoint<C> {
+        JacobianPoint::from(*self)
+    }
+}
+
+impl<C: CurveEncoding> AffinePoint<C> {
+    // returns the compressed encoding of the point
+    pub fn to_compressed(&self) -> C::Encoding {
+        C::encode(self)
+    }
+
+    // Decodes a point of the prime order subgroup, rejecting non canonical encodings and points
+    // outside of the subgroup
+    pub fn from_compressed(bytes: &C::Encoding) -> Result<Self, DeserializationError> {
+        let point = C::decode(bytes)?;
+        if !point.is_torsion_free() {
+            return Err(DeserializationError::InvalidValue(
+                "invalid point: not in the prime order subgroup".to_string(),
+            ));
+        }
+        Ok(point)
+    }
+
+    // Decodes a point of the curve, rejecting non canonical encodings. The point is not checked to
+    // be in the prime order subgroup, which is much more expensive than decompression.
+    pub fn from_compressed_unchecked(bytes: &C::Encoding) -> Result<Self, DeserializationError> {
+        C::decode(bytes)
+    }
+}
+
+impl<C: CurveParameters> Default for AffinePoint<C> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<C: CurveParameters> PartialEq for AffinePoint<C> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.infinity, other.infinity) {
+            (true, true) => true,
+            (false, false) => self.x == other.x && self.y == other.y,
+            _ => false,
+        }
+    }
+}
+
+impl<C: CurveParameters> Neg for AffinePoint<C> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            x: self.x,
+            y: if self.infinity { self.y } else { -self.y },
+            infinity: self.infinity,
+        }
+    }
+}
+
+impl<C: CurveParameters> Mul<C::ScalarField> for AffinePoint<C> {
+    type Output = ProjectivePoint<C>;
+    fn mul(self, rhs: C::ScalarField) -> ProjectivePoint<C> {
+        ProjectivePoint::from(self) * rhs
+    }
+}
+
+impl<C: CurveParameters> From<ProjectivePoint<C>> for AffinePoint<C> {
+    fn from(p: ProjectivePoint<C>) -> Self {
+        if p.is_identity() {
+            return Self::identity();
+        }
+        let zinv = p.z.invert().unwrap();
+        Self::new(p.x * zinv, p.y * zinv)
+    }
+}
+
+impl<C: CurveParameters> From<JacobianPoint<C>> for AffinePoint<C> {
+    fn from(p: JacobianPoint<C>) -> Self {
+        if p.is_identity() {
+            return Self::identity();
+        }
+        let zinv = p.z.invert().unwrap();
+        let zinv2 = zinv.square();
+        Self::new(p.x * zinv2, p.y * zinv2 * zinv)
+    }
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+// Serialization of the prime order subgroup: deserialization rejects points outside of it
+impl<C: CurveEncoding> Serializable for AffinePoint<C> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8_slice(self.to_compressed().as_ref());
+    }
+}
+
+impl<C: CurveEncoding> Deserializable for AffinePoint<C> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let bytes = source.read_u8_vec(C::ENCODED_BYTES)?;
+        let Ok(bytes) = C::Encoding::try_from(bytes.as_slice()) else {
+            return Err(DeserializationError::UnexpectedEOF);
+        };
+        Self::from_compressed(&bytes)
+    }
+}
+
+// PROJECTIVE POINT
+// ================================================================================================
+
+// A point in homogeneous projective coordinates (X : Y : Z) representing the affine point
+// (X/Z, Y/Z). The identity is (0 : 1 : 0). Addition and doubling use the complete formulas of
+// https://eprint.iacr.org/2015/1060.pdf, for a = 0 or for arbitrary a, so no special cases are
+// needed.
+#[derive(Clone, Copy, Debug, Eq)]
+pub struct ProjectivePoint<C: CurveParameters> {
+    pub x: C::BaseField,
+    pub y: C::BaseField,
+    pub z: C::BaseField,
+}
+
+impl<C: CurveParameters> ProjectivePoint<C> {
+    // returns the point at infinity
+    pub fn identity() -> Self {
+        Self {
+            x: C::BaseField::ZERO,
+            y: C::BaseField::ONE,
+            z: C::BaseField::ZERO,
+        }
+    }
+    // returns the standard generator of the group
+    pub fn generator() -> Self {
+        Self::from(AffinePoint::<C>::generator())
+    }
+    // checks whether the point is the point at infinity
+    pub fn is_identity(&self) -> bool {
+        self.z.is_zero()
+    }
+    // checks whether the point satisfies Y^2 * Z = X^3 + a * X * Z^2 + b * Z^3
+    pub fn is_on_curve(&self) -> bool {
+        let z2 = self.z.square();
+        let rhs = self.x.cube() + C::COEFF_A * self.x * z2 + C::COEFF_B * z2 * self.z;
+        self.y.square() * self.z == rhs || self.is_identity()
+    }
+    // checks whether the point lies in the prime order subgroup, i.e. [r]P = O
+    pub fn is_torsion_free(&self) -> bool {
+        self.mul_by_words(&C::SUBGROUP_ORDER.to_words()).is_identity()
+    }
+    // returns the affine representation of the point
+    pub fn to_affine(&self) -> AffinePoint<C> {
+        AffinePoint::from(*self)
+    }
+
+    // returns 2 * self. Algorithm 9, https://eprint.iacr.org/2015/1060.pdf
+    pub fn double(&self) -> Self {
+        if !C::COEFF_A.is_zero() {
+            return self.double_any_a();
+        }
+        let t0 = self.y.square();
+        let z3 = t0.double().double().double();
+        let t1 = self.y * self.z;
+        let t2 = C::COEFF_B3 * self.z.square();
+        let x3 = t2 * z3;
+        let y3 = t0 + t2;
+        let z3 = t1 * z3;
+        let t2 = t2.triple();
+        let t0 = t0 - t2;
+        let y3 = x3 + t0 * y3;
+        let x3 = (t0 * (self.x * self.y)).double();
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    // returns self + rhs where rhs is in affine form. Algorithm 8, https://eprint.iacr.org/2015/1060.pdf
+    pub fn add_mixed(&self, rhs: &AffinePoint<C>) -> Self {
+        if rhs.is_identity() {
+            return *self;
+        }
+        if !C::COEFF_A.is_zero() {
+            return self.add_mixed_any_a(rhs);
+        }
+        let t0 = self.x * rhs.x;
+        let t1 = self.y * rhs.y;
+        let t3 = (rhs.x + rhs.y) * (self.x + self.y) - (t0 + t1);
+        let t4 = rhs.y * self.z + self.y;
+        let y3 = rhs.x * self.z + self.x;
+        let t0 = t0.triple();
+        let t2 = C::COEFF_B3 * self.z;
+        let z3 = t1 + t2;
+        let t1 = t1 - t2;
+        let y3 = C::COEFF_B3 * y3;
+        let x3 = t3 * t1 - t4 * y3;
+        let y3 = t1 * z3 + y3 * t0;
+        let z3 = z3 * t4 + t0 * t3;
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    // Multiplies the point by an integer given as little-endian u64 words. This runs in variable
+    // time and is meant for public multipliers such as the group order or cofactors.
+    pub fn mul_by_words(&self, by: &[u64]) -> Self {
+        let mut res = Self::identity();
+        for word in by.iter().rev() {
+            for i in (0..64).rev() {
+                res = res.double();
+                if ((word >> i) & 1) == 1 {
+                    res += *self;
+                }
+            }
+        }
+        res
+    }
+
+    // FORMULAS FOR ARBITRARY A
+    // --------------------------------------------------------------------------------------------
+
+    // returns 2 * self. Algorithm 3, https://eprint.iacr.org/2015/1060.pdf
+    fn double_any_a(&self) -> Self {
+        let t0 = self.x.square();
+        let t1 = self.y.square();
+        let t2 = self.z.square();
+        let t3 = (self.x * self.y).double();
+        let z3 = (self.x * self.z).double();
+        let y3 = C::COEFF_A * z3 + C::COEFF_B3 * t2;
+        let x3 = t1 - y3;
+        let y3 = x3 * (t1 + y3);
+        let x3 = t3 * x3;
+        let z3 = C::COEFF_B3 * z3;
+        let t2 = C::COEFF_A * t2;
+        let t3 = C::COEFF_A * (t0 - t2) + z3;
+        let t0 = (t0.triple() + t2) * t3;
+        let y3 = y3 + t0;
+        let t2 = (self.y * self.z).double();
+        let x3 = x3 - t2 * t3;
+        let z3 = (t2 * t1).double().double();
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    // returns self + rhs. Algorithm 1, https://eprint.iacr.org/2015/1060.pdf
+    fn add_any_a(&self, rhs: &Self) -> Self {
+        let t0 = self.x * rhs.x;
+        let t1 = self.y * rhs.y;
+        let t2 = self.z * rhs.z;
+        let t3 = (self.x + self.y) * (rhs.x + rhs.y) - (t0 + t1);
+        let t4 = (self.x + self.z) * (rhs.x + rhs.z) - (t0 + t2);
+        let t5 = (self.y + self.z) * (rhs.y + rhs.z) - (t1 + t2);
+        Self::add_any_a_tail(t0, t1, t2, t3, t4, t5)
+    }
+
+    // returns self + rhs where rhs is in affine form and not the identity. Algorithm 2,
+    // https://eprint.iacr.org/2015/1060.pdf
+    fn add_mixed_any_a(&self, rhs: &AffinePoint<C>) -> Self {
+        let t0 = self.x * rhs.x;
+        let t1 = self.y * rhs.y;
+        let t3 = (rhs.x + rhs.y) * (self.x + self.y) - (t0 + t1);
+        let t4 = rhs.x * self.z + self.x;
+        let t5 = rhs.y * self.z + self.y;
+        Self::add_any_a_tail(t0, t1, self.z, t3, t4, t5)
+    }
+
+    // Common end of Algorithms 1 and 2 from the products t0 = X1 * X2, t1 = Y1 * Y2, t2 = Z1 * Z2
+    // and the cross terms t3 = X1 * Y2 + X2 * Y1, t4 = X1 * Z2 + X2 * Z1, t5 = Y1 * Z2 + Y2 * Z1
+    fn add_any_a_tail(
+        t0: C::BaseField,
+        t1: C::BaseField,
+        t2: C::BaseField,
+        t3: C::BaseField,
+        t4: C::BaseField,
+        t5: C::BaseField,
+    ) -> Self {
+        let z3 = C::COEFF_A * t4 + C::COEFF_B3 * t2;
+        let x3 = t1 - z3;
+        let z3 = t1 + z3;
+        let y3 = x3 * z3;
+        let t2 = C::COEFF_A * t2;
+        let t1 = t0.triple() + t2;
+        let t4 = C::COEFF_B3 * t4 + C::COEFF_A * (t0 - t2);
+        let y3 = y3 + t1 * t4;
+        let x3 = t3 * x3 - t5 * t4;
+        let z3 = t5 * z3 + t3 * t1;
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+impl<C: CurveParameters> Default for ProjectivePoint<C> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<C: CurveParameters> PartialEq for ProjectivePoint<C> {
+    // (X1 : Y1 : Z1) == (X2 : Y2 : Z2) iff X1 * Z2 == X2 * Z1 and Y1 * Z2 == Y2 * Z1
+    fn eq(&self, other: &Self) -> bool {
+        match (self.is_identity(), other.is_identity()) {
+            (true, true) => true,
+            (false, false) => {
+                self.x * other.z == other.x * self.z && self.y * other.z == other.y * self.z
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<C: CurveParameters> ConditionallySelectable for ProjectivePoint<C> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: C::BaseField::conditional_select(&a.x, &b.x, choice),
+            y: C::BaseField::conditional_select(&a.y, &b.y, choice),
+            z: C::BaseField::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+impl<C: CurveParameters> From<AffinePoint<C>> for ProjectivePoint<C> {
+    fn from(p: AffinePoint<C>) -> Self {
+        if p.is_identity() {
+            return Self::identity();
+        }
+        Self {
+            x: p.x,
+            y: p.y,
+            z: C::BaseField::ONE,
+        }
+    }
+}
+
+impl<C: CurveParameters> From<JacobianPoint<C>> for ProjectivePoint<C> {
+    // (X, Y, Z) in jacobian coordinates is (X * Z : Y : Z^3) in projective coordinates
+    fn from(p: JacobianPoint<C>) -> Self {
+        if p.is_identity() {
+            return Self::identity();
+        }
+        Self {
+            x: p.x * p.z,
+            y: p.y,
+            z: p.z.cube(),
+        }
+    }
+}
+
+impl<C: CurveParameters> Neg for ProjectivePoint<C> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
+    }
+}
+
+impl<C: CurveParameters> Add for ProjectivePoint<C> {
+    type Output = Self;
+    // Algorithm 7, https://eprint.iacr.org/2015/1060.pdf
+    fn add(self, rhs: Self) -> Self {
+        if !C::COEFF_A.is_zero() {
+            return self.add_any_a(&rhs);
+        }
+        let t0 = self.x * rhs.x;
+        let t1 = self.y * rhs.y;
+        let t2 = self.z * rhs.z;
+        let t3 = (self.x + self.y) * (rhs.x + rhs.y) - (t0 + t1);
+        let t4 = (self.y + self.z) * (rhs.y + rhs.z) - (t1 + t2);
+        let y3 = (self.x + self.z) * (rhs.x + rhs.z) - (t0 + t2);
+        let t0 = t0.triple();
+        let t2 = C::COEFF_B3 * t2;
+        let z3 = t1 + t2;
+        let t1 = t1 - t2;
+        let y3 = C::COEFF_B3 * y3;
+        let x3 = t3 * t1 - t4 * y3;
+        let y3 = t1 * z3 + y3 * t0;
+        let z3 = z3 * t4 + t0 * t3;
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+impl<C: CurveParameters> Add<AffinePoint<C>> for ProjectivePoint<C> {
+    type Output = Self;
+    fn add(self, rhs: AffinePoint<C>) -> Self {
+        self.add_mixed(&rhs)
+    }
+}
+
+impl<C: CurveParameters> Sub for ProjectivePoint<C> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<C: CurveParameters> Sub<AffinePoint<C>> for ProjectivePoint<C> {
+    type Output = Self;
+    fn sub(self, rhs: AffinePoint<C>) -> Self {
+        self.add_mixed(&-rhs)
+    }
+}
+
+impl<C: CurveParameters> AddAssign for ProjectivePoint<C> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<C: CurveParameters> AddAssign<AffinePoint<C>> for ProjectivePoint<C> {
+    fn add_assign(&mut self, rhs: AffinePoint<C>) {
+        *self = self.add_mixed(&rhs);
+    }
+}
+
+impl<C: CurveParameters> SubAssign for ProjectivePoint<C> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<C: CurveParameters> SubAssign<AffinePoint<C>> for ProjectivePoint<C> {
+    fn sub_assign(&mut self, rhs: AffinePoint<C>) {
+        *self = self.add_mixed(&-rhs);
+    }
+}
+
+impl<C: CurveParameters> Mul<C::ScalarField> for ProjectivePoint<C> {
+    type Output = Self;
+    // Fixed window scalar multiplication over the 4 bit windows of the canonical value of the
+    // scalar. The table entry for each window is read with constant time selects, so the sequence
+    // of group operations does not depend on the scalar.
+    fn mul(self, rhs: C::ScalarField) -> Self {
+        let mut table = [Self::identity(); 16];
+        for i in 1..16 {
+            table[i] = table[i - 1] + self;
+        }
+        let mut res = Self::identity();
+        for &window in rhs.get_windows(4).iter().rev() {
+            res = res.double().double().double().double();
+            let mut addend = Self::identity();
+            for (j, entry) in table.iter().enumerate() {
+                addend = Self::conditional_select(&addend, entry, j.ct_eq(&window));
+            }
+            res += addend;
+        }
+        res
+    }
+}
+
+impl<C: CurveParameters> MulAssign<C::ScalarField> for ProjectivePoint<C> {
+    fn mul_assign(&mut self, rhs: C::ScalarField) {
+        *self = *self * rhs;
+    }
+}
+
+impl<C: CurveParameters> CurveGroup for ProjectivePoint<C> {
+    type ScalarField = C::ScalarField;
+
+    fn identity() -> Self {
+        Self::identity()
+    }
+
+    fn generator() -> Self {
+        Self::generator()
+    }
+
+    fn is_identity(&self) -> bool {
+        self.is_identity()
+    }
+
+    fn double(&self) -> Self {
+        self.double()
+    }
+}
+
+// JACOBIAN POINT
+// ================================================================================================
+
+// A point in jacobian coordinates (X, Y, Z) representing the affine point (X/Z^2, Y/Z^3). The
+// identity is (1, 1, 0). Formulas are dbl-2009-l, add-2007-bl and madd-2007-bl from the
+// Explicit-Formulas Database, with the a * Z^4 term added to the doubling when a is not 0; they
+// are not complete, so equal and opposite inputs are handled separately.
+#[derive(Clone, Copy, Debug, Eq)]
+pub struct JacobianPoint<C: CurveParameters> {
+    pub x: C::BaseField,
+    pub y: C::BaseField,
+    pub z: C::BaseField,
+}
+
+impl<C: CurveParameters> JacobianPoint<C> {
+    // returns the point at infinity
+    pub fn identity() -> Self {
+        Self {
+            x: C::BaseField::ONE,
+            y: C::BaseField::ONE,
+            z: C::BaseField::ZERO,
+        }
+    }
+    // returns the standard generator of the group
+    pub fn generator() -> Self {
+        Self::from(AffinePoint::<C>::generator())
+    }
+    // checks whether the point is the point at infinity
+    pub fn is_identity(&self) -> bool {
+        self.z.is_zero()
+    }
+    // checks whether the point satisfies Y^2 = X^3 + a * X * Z^4 + b * Z^6
+    pub fn is_on_curve(&self) -> bool {
+        let z2 = self.z.square();
+        let rhs = self.x.cube() + C::COEFF_A * self.x * z2.square() + C::COEFF_B * z2.cube();
+        self.is_identity() || self.y.square() == rhs
+    }
+    // checks whether the point lies in the prime order subgroup
+    pub fn is_torsion_free(&self) -> bool {
+        ProjectivePoint::from(*self).is_torsion_free()
+    }
+    // returns the affine representation of the point
+    pub fn to_affine(&self) -> AffinePoint<C> {
+        AffinePoint::from(*self)
+    }
+
+    // returns 2 * self using dbl-2009-l
+    pub fn double(&self) -> Self {
+        if self.is_identity() {
+            return *self;
+        }
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        let d = ((self.x + b).square() - a - c).double();
+        let e = if C::COEFF_A.is_zero() {
+            a.triple()
+        } else {
+            a.triple() + C::COEFF_A * self.z.square().square()
+        };
+        let f = e.square();
+        let x3 = f - d.double();
+        let y3 = e * (d - x3) - c.double().double().double();
+        let z3 = (self.y * self.z).double();
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    // returns self + rhs where rhs is in affine form, using madd-2007-bl
+    pub fn add_mixed(&self, rhs: &AffinePoint<C>) -> Self {
+        if rhs.is_identity() {
+            return *self;
+        }
+        if self.is_identity() {
+            return Self::from(*rhs);
+        }
+        let z1z1 = self.z.square();
+        let u2 = rhs.x * z1z1;
+        let s2 = rhs.y * self.z * z1z1;
+        let h = u2 - self.x;
+        let r = (s2 - self.y).double();
+        if h.is_zero() {
+            if r.is_zero() {
+                return self.double();
+            }
+            return Self::identity();
+        }
+        let hh = h.square();
+        let i = hh.double().double();
+        let j = h * i;
+        let v = self.x * i;
+        let x3 = r.square() - j - v.double();
+        let y3 = r * (v - x3) - (self.y * j).double();
+        let z3 = (self.z + h).square() - z1z1 - hh;
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    // Multiplies the point by an integer given as little-endian u64 words. This runs in variable
+    // time and is meant for public multipliers such as the group order or cofactors.
+    pub fn mul_by_words(&self, by: &[u64]) -> Self {
+        let mut res = Self::identity();
+        for word in by.iter().rev() {
+            for i in (0..64).rev() {
+                res = res.double();
+                if ((word >> i) & 1) == 1 {
+                    res += *self;
+                }
+            }
+        }
+        res
+    }
+}
+
+impl<C: CurveParameters> Default for JacobianPoint<C> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<C: CurveParameters> PartialEq for JacobianPoint<C> {
+    // (X1, Y1, Z1) == (X2, Y2, Z2) iff X1 * Z2^2 == X2 * Z1^2 and Y1 * Z2^3 == Y2 * Z1^3
+    fn eq(&self, other: &Self) -> bool {
+        match (self.is_identity(), other.is_identity()) {
+            (true, true) => true,
+            (false, false) => {
+                let z1z1 = self.z.square();
+                let z2z2 = other.z.square();
+                self.x * z2z2 == other.x * z1z1
+                    && self.y * z2z2 * other.z == other.y * z1z1 * self.z
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<C: CurveParameters> ConditionallySelectable for JacobianPoint<C> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: C::BaseField::conditional_select(&a.x, &b.x, choice),
+            y: C::BaseField::conditional_select(&a.y, &b.y, choice),
+            z: C::BaseField::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+impl<C: CurveParameters> From<AffinePoint<C>> for JacobianPoint<C> {
+    fn from(p: AffinePoint<C>) -> Self {
+        if p.is_identity() {
+            return Self::identity();
+        }
+        Self {
+            x: p.x,
+            y: p.y,
+            z: C::BaseField::ONE,
+        }
+    }
+}
+
+impl<C: CurveParameters> From<ProjectivePoint<C>> for JacobianPoint<C> {
+    // (X : Y : Z) in projective coordinates is (X * Z, Y * Z^2, Z) in jacobian coordinates
+    fn from(p: ProjectivePoint<C>) -> Self {
+        if p.is_identity() {
+            return Self::identity();
+        }
+        Self {
+            x: p.x * p.z,
+            y: p.y * p.z.square(),
+            z: p.z,
+        }
+    }
+}
+
+impl<C: CurveParameters> Neg for JacobianPoint<C> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
+    }
+}
+
+impl<C: CurveParameters> Add for JacobianPoint<C> {
+    type Output = Self;
+    // add-2007-bl
+    fn add(self, rhs: Self) -> Self {
+        if self.is_identity() {
+            return rhs;
+        }
+        if rhs.is_identity() {
+            return self;
+        }
+        let z1z1 = self.z.square();
+        let z2z2 = rhs.z.square();
+        let u1 = self.x * z2z2;
+        let u2 = rhs.x * z1z1;
+        let s1 = self.y * rhs.z * z2z2;
+        let s2 = rhs.y * self.z * z1z1;
+        let h = u2 - u1;
+        let r = (s2 - s1).double();
+        if h.is_zero() {
+            if r.is_zero() {
+                return self.double();
+            }
+            return Self::identity();
+        }
+        let i = h.double().square();
+        let j = h * i;
+        let v = u1 * i;
+        let x3 = r.square() - j - v.double();
+        let y3 = r * (v - x3) - (s1 * j).double();
+        let z3 = ((self.z + rhs.z).square() - z1z1 - z2z2) * h;
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+impl<C: CurveParameters> Add<AffinePoint<C>> for JacobianPoint<C> {
+    type Output = Self;
+    fn add(self, rhs: AffinePoint<C>) -> Self {
+        self.add_mixed(&rhs)
+    }
+}
+
+impl<C: CurveParameters> Sub for JacobianPoint<C> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<C: CurveParameters> Sub<AffinePoint<C>> for JacobianPoint<C> {
+    type Output = Self;
+    fn sub(self, rhs: AffinePoint<C>) -> Self {
+        self.add_mixed(&-rhs)
+    }
+}
+
+impl<C: CurveParameters> AddAssign for JacobianPoint<C> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<C: CurveParameters> AddAssign<AffinePoint<C>> for JacobianPoint<C> {
+    fn add_assign(&mut self, rhs: AffinePoint<C>) {
+        *self = self.add_mixed(&rhs);
+    }
+}
+
+impl<C: CurveParameters> SubAssign for JacobianPoint<C> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<C: CurveParameters> SubAssign<AffinePoint<C>> for JacobianPoint<C> {
+    fn sub_assign(&mut self, rhs: AffinePoint<C>) {
+        *self = self.add_mixed(&-rhs);
+    }
+}
+
+impl<C: CurveParameters> Mul<C::ScalarField> for JacobianPoint<C> {
+    type Output = Self;
+    // The jacobian formulas branch on their inputs, so the product is computed with the complete
+    // projective formulas and converted back.
+    fn mul(self, rhs: C::ScalarField) -> Self {
+        Self::from(ProjectivePoint::from(self) * rhs)
+    }
+}
+
+impl<C: CurveParameters> MulAssign<C::ScalarField> for JacobianPoint<C> {
+    fn mul_assign(&mut self, rhs: C::ScalarField) {
+        *self = *self * rhs;
+    }
+}
+
+impl<C: CurveParameters> CurveGroup for JacobianPoint<C> {
+    type ScalarField = C::ScalarField;
+
+    fn identity() -> Self {
+        Self::identity()
+    }
+
+    fn generator() -> Self {
+        Self::generator()
+    }
+
+    fn is_identity(&self) -> bool {
+        self.is_identity()
+    }
+
+    fn double(&self) -> Self {
+        self.double()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+// Returns x^3 + a * x + b, the right hand side of the curve equation
+pub fn curve_rhs<C: CurveParameters>(x: C::BaseField) -> C::BaseField {
+    x.cube() + C::COEFF_A * x + C::COEFF_B
+}
@@ -0,0 +1,47 @@
+use bls381::{fp::Fp, fp12::Fp12, fp2::Fp2, fp6::Fp6};
+use cheetah64::{fp3::Fp3, fp6::Fp6 as CheetahFp6};
+use math::batch_inversion::{batch_invert, batch_inverse, batch_inverse_chunked};
+use traits::traits::Field;
+
+// Checks batch_invert, batch_inverse and batch_inverse_chunked against one inversion per value,
+// with zeros among the values
+fn check_batch_inversion<F: Field>() {
+    let mut values: Vec<F> = (0..20).map(|_| F::random()).collect();
+    values[0] = F::ZERO;
+    values[7] = F::ZERO;
+    let expected: Vec<F> = values
+        .iter()
+        .map(|value| if value.is_zero() { F::ZERO } else { value.invert().unwrap() })
+        .collect();
+
+    assert_eq!(batch_inverse(&values), expected);
+    assert_eq!(batch_inverse_chunked(&values, 6), expected);
+    assert_eq!(batch_inverse_chunked(&values, 64), expected);
+    for (&value, &inverse) in values.iter().zip(&expected) {
+        assert!(value.is_zero() || value * inverse == F::ONE);
+    }
+    batch_invert(&mut values);
+    assert_eq!(values, expected);
+
+    let mut zeros = vec![F::ZERO; 3];
+    batch_invert(&mut zeros);
+    assert_eq!(zeros, vec![F::ZERO; 3]);
+    assert!(batch_inverse::<F>(&[]).is_empty());
+}
+
+#[test]
+fn test_batch_inversion() {
+    check_batch_inversion::<bls381::scalar::Scalar>();
+    check_batch_inversion::<Fp>();
+    check_batch_inversion::<Fp2<Fp>>();
+    check_batch_inversion::<Fp6<Fp>>();
+    check_batch_inversion::<Fp12<Fp>>();
+}
+
+#[test]
+fn test_batch_inversion_cheetah() {
+    check_batch_inversion::<cheetah64::scalar::Scalar>();
+    check_batch_inversion::<cheetah64::fp::Fp>();
+    check_batch_inversion::<Fp3<cheetah64::fp::Fp>>();
+    check_batch_inversion::<CheetahFp6<cheetah64::fp::Fp>>();
+}
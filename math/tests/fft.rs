@@ -0,0 +1,78 @@
+use math::fft::{bit_reverse_permutation, EvaluationDomain, FftError};
+use stark252::field::Fp;
+use traits::traits::{Field, PrimeField};
+
+// Checks the fft and ifft over a domain of the given size, and their coset variants with the field
+// generator as offset, against direct evaluations of a random polynomial
+fn check_fft<F: Field + PrimeField>(size: usize) {
+    let domain = <EvaluationDomain<F>>::new(size).unwrap();
+    let elements = domain.elements();
+    if size > 1 {
+        assert_eq!(domain.generator().power_by([size as u64 / 2]), -F::ONE);
+    }
+    let coefficients: Vec<F> = (0..size).map(|_| F::random()).collect();
+    let evaluate = |x: F| coefficients.iter().rev().fold(F::ZERO, |acc, &c| acc * x + c);
+
+    let mut values = coefficients.clone();
+    domain.fft(&mut values).unwrap();
+    assert_eq!(values, elements.iter().map(|&x| evaluate(x)).collect::<Vec<_>>());
+    domain.ifft(&mut values).unwrap();
+    assert_eq!(values, coefficients);
+
+    let offset = F::GENERATOR;
+    domain.coset_fft(&mut values, offset).unwrap();
+    assert_eq!(values, elements.iter().map(|&x| evaluate(offset * x)).collect::<Vec<_>>());
+    domain.coset_ifft(&mut values, offset).unwrap();
+    assert_eq!(values, coefficients);
+}
+
+#[test]
+fn test_fft() {
+    check_fft::<Fp>(16);
+    check_fft::<bls381::scalar::Scalar>(16);
+    check_fft::<bn254::scalar::Scalar>(16);
+    check_fft::<cheetah64::fp::Fp>(16);
+    check_fft::<f128::fp::Fp>(16);
+}
+
+// bls381 Fp is in Montgomery form and has two-adicity 1, so its only domains have sizes 1 and 2
+#[test]
+fn test_fft_montgomery() {
+    check_fft::<bls381::fp::Fp>(1);
+    check_fft::<bls381::fp::Fp>(2);
+}
+
+#[test]
+fn test_fft_domain() {
+    let mut values: Vec<usize> = (0..8).collect();
+    bit_reverse_permutation(&mut values);
+    assert_eq!(values, vec![0, 4, 2, 6, 1, 5, 3, 7]);
+
+    // a domain of size 1 holds only 1
+    let domain = <EvaluationDomain<Fp>>::new(1).unwrap();
+    let mut values = vec![Fp::from(5u64)];
+    domain.fft(&mut values).unwrap();
+    assert_eq!(values, vec![Fp::from(5u64)]);
+    assert_eq!(domain.elements(), vec![Fp::ONE]);
+
+    // fft and ifft are inverse of each other on larger domains
+    let domain = <EvaluationDomain<Fp>>::new(1 << 10).unwrap();
+    let coefficients: Vec<Fp> = (0..1 << 10).map(|_| Fp::random()).collect();
+    let mut values = coefficients.clone();
+    domain.fft(&mut values).unwrap();
+    let x = domain.elements()[3];
+    let expected = coefficients.iter().rev().fold(Fp::ZERO, |acc, &c| acc * x + c);
+    assert_eq!(values[3], expected);
+    domain.ifft(&mut values).unwrap();
+    assert_eq!(values, coefficients);
+
+    assert_eq!(
+        domain.fft(&mut values[1..]),
+        Err(FftError::NumberOfValuesMismatch(1 << 10, 1023))
+    );
+    assert_eq!(domain.coset_ifft(&mut values, Fp::ZERO), Err(FftError::ZeroOffset));
+    assert_eq!(domain.coset_fft(&mut values, Fp::ZERO), Err(FftError::ZeroOffset));
+    assert_eq!(values, coefficients);
+    assert_eq!(<EvaluationDomain<Fp>>::new(12), Err(FftError::SizeNotPowerOfTwo(12)));
+    assert_eq!(<EvaluationDomain<Fp>>::new(0), Err(FftError::SizeNotPowerOfTwo(0)));
+}
@@ -0,0 +1,194 @@
+use hash::{rescue::Rescue, sponge::Permutation, transcript::Transcript};
+use math::{
+    fft::EvaluationDomain,
+    fri::{draw_query_positions, FriError, FriOptions, FriProof, FriProver, FriVerifier},
+};
+use traits::traits::{ExtensionOf, Field, PrimeField};
+
+// Proves and verifies random polynomials of degree less than 16 for several folding factors,
+// blowup factors and remainder sizes, with challenges drawn from E and digests of N elements of B,
+// and checks that invalid proofs are rejected
+fn check_fri<B, E, H, const N: usize>()
+where
+    B: Field + PrimeField,
+    E: Field + ExtensionOf<B>,
+    H: Permutation<Field = B>,
+{
+    for (blowup_factor, folding_factor, max_remainder_degree, num_layers) in
+        [(2, 2, 0, 4), (4, 4, 3, 1), (8, 8, 0, 1), (2, 16, 0, 1), (2, 4, 15, 0)]
+    {
+        let options =
+            FriOptions::new(blowup_factor, folding_factor, 8, max_remainder_degree).unwrap();
+        let domain_size = 16 * blowup_factor;
+        let (coeffs, evaluations) = random_evaluations::<B, E>(16, domain_size);
+        let proof = FriProver::<B, E, H, N>::prove(
+            options,
+            evaluations.clone(),
+            &mut Transcript::new(b"fri test"),
+        )
+        .unwrap();
+        assert_eq!(proof.layers.len(), num_layers);
+        assert_eq!(proof.remainder.len(), options.remainder_size(domain_size));
+        if num_layers == 0 {
+            assert_eq!(proof.remainder, coeffs);
+        }
+
+        let mut transcript = Transcript::new(b"fri test");
+        let verifier =
+            FriVerifier::<B, E, H, N>::new(options, domain_size, proof.clone(), &mut transcript)
+                .unwrap();
+        let positions = draw_query_positions(&mut transcript, domain_size, 8);
+        let values: Vec<E> = positions.iter().map(|&p| evaluations[p]).collect();
+        assert_eq!(verifier.verify_queries(&positions), Ok(values));
+        assert_eq!(
+            FriVerifier::<B, E, H, N>::verify(
+                options,
+                domain_size,
+                proof,
+                &mut Transcript::new(b"fri test")
+            ),
+            Ok(())
+        );
+    }
+
+    let options = FriOptions::new(4, 4, 16, 0).unwrap();
+    let (_, evaluations) = random_evaluations::<B, E>(16, 64);
+    let proof =
+        FriProver::<B, E, H, N>::prove(options, evaluations, &mut Transcript::new(b"fri test"))
+            .unwrap();
+    let verify = |proof: FriProof<B, E, N>, label: &[u8]| {
+        FriVerifier::<B, E, H, N>::verify(options, 64, proof, &mut Transcript::new(label))
+    };
+    assert_eq!(verify(proof.clone(), b"fri test"), Ok(()));
+    assert!(verify(proof.clone(), b"other test").is_err());
+
+    let mut tampered = proof.clone();
+    tampered.layers[1].cosets[0][1] += E::ONE;
+    assert_eq!(verify(tampered, b"fri test"), Err(FriError::InvalidLayerCommitment(1)));
+    // the depth of the trees follows from the domain size, whatever the proofs claim
+    let mut tampered = proof.clone();
+    tampered.layers[1].merkle_proof.depth += 1;
+    assert_eq!(verify(tampered, b"fri test"), Err(FriError::InvalidLayerCommitment(1)));
+    let mut tampered = proof.clone();
+    tampered.layers[0].root[N - 1] += B::ONE;
+    assert!(verify(tampered, b"fri test").is_err());
+    let mut tampered = proof.clone();
+    tampered.remainder[0] += E::ONE;
+    assert!(verify(tampered, b"fri test").is_err());
+    let mut tampered = proof.clone();
+    tampered.remainder.push(E::ONE);
+    assert_eq!(verify(tampered, b"fri test"), Err(FriError::RemainderSizeMismatch(1, 2)));
+    let mut tampered = proof;
+    tampered.layers.pop();
+    assert_eq!(verify(tampered, b"fri test"), Err(FriError::NumberOfLayersMismatch(2, 1)));
+
+    // a polynomial of degree 31 is not of degree less than 16, which the remainder exposes
+    let (_, evaluations) = random_evaluations::<B, E>(32, 64);
+    let proof =
+        FriProver::<B, E, H, N>::prove(options, evaluations, &mut Transcript::new(b"fri test"))
+            .unwrap();
+    assert_eq!(verify(proof, b"fri test"), Err(FriError::InvalidRemainder));
+
+    let (_, evaluations) = random_evaluations::<B, E>(16, 64);
+    let prover =
+        FriProver::<B, E, H, N>::commit(options, evaluations, &mut Transcript::new(b"fri test"))
+            .unwrap();
+    assert_eq!(prover.query(&[3, 64]), Err(FriError::PositionOutOfBounds(64, 64)));
+    for domain_size in [2, 96] {
+        assert!(matches!(
+            FriProver::<B, E, H, N>::commit(
+                options,
+                vec![E::ONE; domain_size],
+                &mut Transcript::new(b"fri test")
+            ),
+            Err(FriError::InvalidDomainSize(size)) if size == domain_size
+        ));
+    }
+}
+
+// Returns the coefficients of a random polynomial with the given number of coefficients and its
+// evaluations over the coset of the generator of the given size
+fn random_evaluations<B, E>(num_coeffs: usize, domain_size: usize) -> (Vec<E>, Vec<E>)
+where
+    B: Field + PrimeField,
+    E: Field + ExtensionOf<B>,
+{
+    let coeffs: Vec<E> = (0..num_coeffs).map(|_| E::random()).collect();
+    let domain = EvaluationDomain::<B>::new(domain_size).unwrap();
+    let evaluations = domain
+        .elements()
+        .iter()
+        .map(|&element| {
+            let x = B::GENERATOR * element;
+            coeffs.iter().rev().fold(E::ZERO, |acc, &c| acc.mul_base(x) + c)
+        })
+        .collect();
+    (coeffs, evaluations)
+}
+
+#[test]
+fn test_fri_options() {
+    assert!(FriOptions::new(2, 2, 1, 0).is_ok());
+    assert_eq!(FriOptions::new(1, 2, 1, 0), Err(FriError::InvalidBlowupFactor(1)));
+    assert_eq!(FriOptions::new(6, 2, 1, 0), Err(FriError::InvalidBlowupFactor(6)));
+    assert_eq!(FriOptions::new(2, 1, 1, 0), Err(FriError::InvalidFoldingFactor(1)));
+    assert_eq!(FriOptions::new(2, 32, 1, 0), Err(FriError::InvalidFoldingFactor(32)));
+    assert_eq!(FriOptions::new(2, 2, 0, 0), Err(FriError::InvalidNumberOfQueries(0)));
+    assert_eq!(FriOptions::new(2, 2, 1, 2), Err(FriError::InvalidMaxRemainderDegree(2)));
+
+    let options = FriOptions::new(4, 4, 1, 0).unwrap();
+    assert_eq!(options.num_layers(1 << 10), 4);
+    assert_eq!(options.remainder_size(1 << 10), 1);
+    // folding stops once the degree bound is smaller than the folding factor
+    let options = FriOptions::new(2, 8, 1, 0).unwrap();
+    assert_eq!(options.num_layers(64), 1);
+    assert_eq!(options.remainder_size(64), 4);
+}
+
+// one element of the 252-bit StarkNet field is enough for a digest
+#[test]
+fn test_fri_stark252() {
+    use stark252::field::Fp;
+
+    check_fri::<Fp, Fp, Rescue<Fp>, 1>();
+}
+
+// digests of two 128-bit elements, as a single element is too small to resist collisions
+#[test]
+fn test_fri_f128() {
+    use f128::{fp::Fp, fp2::Fp2};
+
+    check_fri::<Fp, Fp, Rescue<Fp>, 2>();
+    check_fri::<Fp, Fp2<Fp>, Rescue<Fp>, 2>();
+}
+
+// digests of four 64-bit elements, as a single element is far too small to resist collisions
+#[test]
+fn test_fri_cheetah64() {
+    use cheetah64::{fp::Fp, fp3::Fp3, fp6::Fp6};
+
+    check_fri::<Fp, Fp, Rescue<Fp>, 4>();
+    check_fri::<Fp, Fp3<Fp>, Rescue<Fp>, 4>();
+    check_fri::<Fp, Fp6<Fp>, Rescue<Fp>, 4>();
+}
+
+#[test]
+fn test_fri_short_digests() {
+    let options = FriOptions::new(2, 2, 8, 0).unwrap();
+    assert_eq!(
+        FriProver::<f128::fp::Fp, f128::fp::Fp, Rescue<f128::fp::Fp>, 1>::prove(
+            options,
+            vec![f128::fp::Fp::ONE; 32],
+            &mut Transcript::new(b"fri test")
+        ),
+        Err(FriError::DigestTooShort(128))
+    );
+    assert_eq!(
+        FriProver::<cheetah64::fp::Fp, cheetah64::fp::Fp, Rescue<cheetah64::fp::Fp>, 2>::prove(
+            options,
+            vec![cheetah64::fp::Fp::ONE; 32],
+            &mut Transcript::new(b"fri test")
+        ),
+        Err(FriError::DigestTooShort(128))
+    );
+}
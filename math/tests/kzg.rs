@@ -0,0 +1,83 @@
+use std::fmt;
+
+use math::{
+    fft::EvaluationDomain,
+    kzg::{KzgError, Srs},
+    polynomial::{DensePolynomial, PolynomialError},
+};
+use traits::traits::{CurveGroup, Field, Pairing};
+
+// Commits, opens and verifies random polynomials with a setup of 16 G1 and 5 G2 powers, and checks
+// the errors of the commitments, openings and setups
+fn check_kzg<E: Pairing + fmt::Debug + PartialEq>() {
+    let srs = Srs::<E>::insecure_from_secret(E::ScalarField::random(), 16, 5);
+    let poly = DensePolynomial::new((0..16).map(|_| E::ScalarField::random()).collect());
+    let commitment = srs.commit(&poly).unwrap();
+
+    let point = E::ScalarField::random();
+    let (value, proof) = srs.open(&poly, point).unwrap();
+    assert_eq!(value, poly.evaluate(point));
+    assert!(srs.verify(&commitment, point, value, &proof));
+    assert!(!srs.verify(&commitment, point, value + E::ScalarField::ONE, &proof));
+    assert!(!srs.verify(&commitment, point + E::ScalarField::ONE, value, &proof));
+
+    let points: Vec<E::ScalarField> = (0..4).map(|_| E::ScalarField::random()).collect();
+    let (mut values, proof) = srs.open_batch(&poly, &points).unwrap();
+    assert_eq!(values, poly.evaluate_many(&points));
+    assert_eq!(srs.verify_batch(&commitment, &points, &values, &proof), Ok(true));
+    values[2] += E::ScalarField::ONE;
+    assert_eq!(srs.verify_batch(&commitment, &points, &values, &proof), Ok(false));
+
+    // the Lagrange basis commits to the same polynomial through its evaluations
+    let domain = EvaluationDomain::<E::ScalarField>::new(16).unwrap();
+    let mut evaluations = poly.coeffs().to_vec();
+    domain.fft(&mut evaluations).unwrap();
+    assert_eq!(srs.commit_lagrange(&evaluations), Ok(commitment));
+
+    let long_poly = DensePolynomial::new(vec![E::ScalarField::ONE; 17]);
+    assert_eq!(srs.commit(&long_poly), Err(KzgError::DegreeTooLarge(16, 15)));
+    assert_eq!(srs.commit(&DensePolynomial::zero()), Ok(E::G1::identity()));
+    assert_eq!(
+        srs.commit_lagrange(&evaluations[..8]),
+        Err(KzgError::LagrangeBasisSizeMismatch(16, 8))
+    );
+    let five_points: Vec<E::ScalarField> = (0..5).map(|_| E::ScalarField::random()).collect();
+    assert_eq!(
+        srs.verify_batch(&commitment, &five_points, &values, &proof),
+        Err(KzgError::TooManyPoints(5, 4))
+    );
+    assert_eq!(
+        srs.open_batch(&poly, &[points[0], points[1], points[0]]),
+        Err(KzgError::Polynomial(PolynomialError::DuplicatePoint(2)))
+    );
+
+    let g1 = srs.g1_powers().to_vec();
+    let g2 = srs.g2_powers().to_vec();
+    let lagrange = srs.g1_lagrange().to_vec();
+    assert_eq!(Srs::<E>::new(g1.clone(), g2.clone(), lagrange.clone()), Ok(srs));
+    let mut wrong_g2 = g2.clone();
+    wrong_g2[1] = wrong_g2[1].double();
+    assert_eq!(
+        Srs::<E>::new(g1.clone(), wrong_g2, lagrange.clone()),
+        Err(KzgError::InconsistentSetup)
+    );
+    assert_eq!(
+        Srs::<E>::new(g1.clone(), g2.clone(), lagrange[..12].to_vec()),
+        Err(KzgError::InvalidLagrangeBasisSize(12))
+    );
+    assert_eq!(
+        Srs::<E>::new(g1.clone(), g2.clone(), lagrange[..8].to_vec()),
+        Err(KzgError::InconsistentSetup)
+    );
+    assert_eq!(Srs::<E>::new(g1, g2[..1].to_vec(), lagrange), Err(KzgError::SetupTooSmall(16, 1)));
+}
+
+#[test]
+fn test_kzg_bls381() {
+    check_kzg::<bls381::pairing::Bls381>();
+}
+
+#[test]
+fn test_kzg_bn254() {
+    check_kzg::<bn254::pairing::Bn254>();
+}
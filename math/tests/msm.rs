@@ -0,0 +1,53 @@
+use math::msm::{msm, naive_msm};
+use traits::traits::{CurveGroup, Field};
+
+// Checks msm against naive_msm on random bases, with zero, one and the largest scalar among the
+// scalars when there are enough points
+fn check_msm<G: CurveGroup>(num_points: usize) {
+    let bases: Vec<G> = (0..num_points)
+        .map(|_| G::generator() * G::ScalarField::random())
+        .collect();
+    let mut scalars: Vec<G::ScalarField> =
+        (0..num_points).map(|_| G::ScalarField::random()).collect();
+    // edge cases for the signed digits: zero, one and the largest scalar
+    if num_points >= 3 {
+        scalars[0] = G::ScalarField::ZERO;
+        scalars[1] = G::ScalarField::ONE;
+        scalars[2] = -G::ScalarField::ONE;
+    }
+    assert_eq!(msm(&bases, &scalars), naive_msm(&bases, &scalars));
+}
+
+#[test]
+fn test_msm_bls381() {
+    use bls381::{g1::G1Projective, g2::G2Projective, jubjub::ExtendedPoint};
+
+    assert_eq!(msm::<G1Projective>(&[], &[]), G1Projective::identity());
+    check_msm::<G1Projective>(1);
+    check_msm::<G1Projective>(5);
+    check_msm::<G1Projective>(40);
+    check_msm::<G2Projective>(5);
+    check_msm::<ExtendedPoint>(40);
+}
+
+#[test]
+fn test_msm_bn254() {
+    use bn254::{
+        g1::{G1Jacobian, G1Projective},
+        g2::G2Projective,
+    };
+
+    check_msm::<G1Projective>(5);
+    check_msm::<G1Projective>(40);
+    check_msm::<G1Jacobian>(40);
+    check_msm::<G2Projective>(5);
+}
+
+#[test]
+fn test_msm_cheetah64() {
+    use cheetah64::curve::ProjectivePoint;
+
+    check_msm::<ProjectivePoint>(1);
+    check_msm::<ProjectivePoint>(5);
+    check_msm::<ProjectivePoint>(40);
+}
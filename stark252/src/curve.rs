@@ -0,0 +1,57 @@
+use crypto_bigint::U256;
+use math::weierstrass::{self, CurveParameters};
+use traits::traits::{Field, PrimeField};
+
+use crate::{
+    field::Fp,
+    scalar::{Scalar, SCALAR_MODULUS},
+};
+
+// The Stark curve y^2 = x^3 + alpha * x + beta over the field of the StarkNet prime, with
+// alpha = 1. Its group of points has prime order, the modulus of Scalar, so every point other
+// than the identity generates the group.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StarkParameters;
+
+impl CurveParameters for StarkParameters {
+    type BaseField = Fp;
+
+    type ScalarField = Scalar;
+
+    // alpha
+    const COEFF_A: Fp = Fp::ONE;
+
+    // beta
+    const COEFF_B: Fp = Fp(U256::from_be_hex(
+        "06F21413EFBE40DE150E596D72F7A8C5609AD26C15C915C1F4CDFCB99CEE9E89",
+    ));
+
+    const COEFF_B3: Fp = Fp(U256::from_be_hex(
+        "04D63C3BCF3AC2783F2B0C4858E6FA5021D07744415B4145DE69F62CD6CBDB99",
+    ));
+
+    // the standard generator used by StarkNet signatures
+    const GENERATOR_X: Fp = Fp(U256::from_be_hex(
+        "01EF15C18599971B7BECED415A40F0C7DEACFD9B0D1819E03D723D8BC943CFCA",
+    ));
+    const GENERATOR_Y: Fp = Fp(U256::from_be_hex(
+        "005668060AA49730B7BE4801DF46EC62DE53ECD11ABE43A32873000C36E8DC1F",
+    ));
+
+    const SUBGROUP_ORDER: U256 = SCALAR_MODULUS;
+}
+
+pub type AffinePoint = weierstrass::AffinePoint<StarkParameters>;
+pub type ProjectivePoint = weierstrass::ProjectivePoint<StarkParameters>;
+
+// Returns the point of abscissa x with an even ordinate, or None if x is not the abscissa of a
+// point of the curve
+pub fn from_x(x: Fp) -> Option<AffinePoint> {
+    let y = weierstrass::curve_rhs::<StarkParameters>(x).sqrt();
+    if bool::from(y.is_none()) {
+        return None;
+    }
+    let y = y.unwrap();
+    let y = if bool::from(y.is_odd()) { -y } else { y };
+    Some(AffinePoint::new(x, y))
+}
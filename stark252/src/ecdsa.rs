@@ -0,0 +1,184 @@
+use std::fmt;
+
+use crypto_bigint::{Encoding, U256};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use traits::traits::Field;
+
+use crate::{
+    curve::{from_x, ProjectivePoint},
+    field::Fp,
+    scalar::{Scalar, SCALAR_MODULUS},
+};
+
+// Messages, r and s must be smaller than 2^251
+const ELEMENT_UPPER_BOUND: U256 =
+    U256::from_be_hex("0800000000000000000000000000000000000000000000000000000000000000");
+
+// STARK ECDSA
+// ================================================================================================
+// ECDSA over the Stark curve as used by StarkNet and StarkEx. The public key is the x coordinate
+// of [private_key]G, and the message is a field element smaller than 2^251, usually a Pedersen or
+// Poseidon hash. Signatures are interoperable with starknet-crypto.
+
+// ECDSA signature (r, s), both in [1, 2^251)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub r: Fp,
+    pub s: Fp,
+}
+
+// Returns the public key of a private key: the x coordinate of [private_key]G
+pub fn get_public_key(private_key: &Scalar) -> Fp {
+    (ProjectivePoint::generator() * *private_key).to_affine().x
+}
+
+// Signs the message hash with a nonce derived from the private key and the message as specified
+// by RFC 6979. When the nonce yields an out of range r or s, which happens with probability about
+// 2^-4 per attempt, the derivation is retried with the seeds 1, 2, ... as in cairo-lang.
+pub fn sign(private_key: &Scalar, message_hash: &Fp) -> Result<Signature, EcdsaError> {
+    let mut seed = None;
+    loop {
+        let k = generate_k(message_hash, private_key, seed.as_ref());
+        match sign_with_k(private_key, message_hash, &k) {
+            Err(EcdsaError::InvalidK) => {
+                seed = Some(seed.map_or(Fp::ONE, |seed| seed + Fp::ONE));
+            }
+            result => return result,
+        }
+    }
+}
+
+// Signs the message hash with the nonce k: r is the x coordinate of [k]G and
+// s = (message_hash + r * private_key) / k. The same k must never be used for two signatures.
+pub fn sign_with_k(
+    private_key: &Scalar,
+    message_hash: &Fp,
+    k: &Scalar,
+) -> Result<Signature, EcdsaError> {
+    if message_hash.0 >= ELEMENT_UPPER_BOUND {
+        return Err(EcdsaError::InvalidMessageHash);
+    }
+    if k.is_zero() {
+        return Err(EcdsaError::InvalidK);
+    }
+    let r = (ProjectivePoint::generator() * *k).to_affine().x;
+    if r.is_zero() || r.0 >= ELEMENT_UPPER_BOUND {
+        return Err(EcdsaError::InvalidK);
+    }
+    // r and the message hash are smaller than 2^251, and thus than the group order
+    let s = (Scalar(message_hash.0) + Scalar(r.0) * *private_key) * k.invert().unwrap();
+    if s.is_zero() || s.0 >= ELEMENT_UPPER_BOUND {
+        return Err(EcdsaError::InvalidK);
+    }
+    Ok(Signature { r, s: Fp(s.0) })
+}
+
+// Verifies a signature of the message hash for the public key. Out of range inputs and public
+// keys which are not the x coordinate of a point are reported as errors rather than as invalid
+// signatures. Since the public key only determines [private_key]G up to its sign, the signature
+// is accepted for either point.
+pub fn verify(
+    public_key: &Fp,
+    message_hash: &Fp,
+    signature: &Signature,
+) -> Result<bool, EcdsaError> {
+    if message_hash.0 >= ELEMENT_UPPER_BOUND {
+        return Err(EcdsaError::InvalidMessageHash);
+    }
+    let Signature { r, s } = signature;
+    if r.is_zero() || r.0 >= ELEMENT_UPPER_BOUND {
+        return Err(EcdsaError::InvalidR);
+    }
+    if s.is_zero() || s.0 >= ELEMENT_UPPER_BOUND {
+        return Err(EcdsaError::InvalidS);
+    }
+    let public_key = from_x(*public_key).ok_or(EcdsaError::InvalidPublicKey)?;
+
+    let w = Scalar(s.0).invert().unwrap();
+    if w.0 >= ELEMENT_UPPER_BOUND {
+        return Err(EcdsaError::InvalidS);
+    }
+    // the scalars are public, so the variable time multiplication is used
+    let zw_g =
+        ProjectivePoint::generator().mul_by_words(&(Scalar(message_hash.0) * w).0.to_words());
+    let rw_q = public_key
+        .to_projective()
+        .mul_by_words(&(Scalar(r.0) * w).0.to_words());
+    Ok((zw_g + rw_q).to_affine().x == *r || (zw_g - rw_q).to_affine().x == *r)
+}
+
+// RFC 6979 NONCES
+// ================================================================================================
+
+// Derives the nonce for signing the message hash with HMAC-DRBG over SHA-256 as specified by
+// RFC 6979, section 3.2, with the optional seed, stripped of its leading zero bytes, as extra
+// data. The candidates are shifted right by 4 bits, since the group order has 252 bits. This
+// matches generate_k of starknet-crypto.
+pub fn generate_k(message_hash: &Fp, private_key: &Scalar, seed: Option<&Fp>) -> Scalar {
+    let x = private_key.0.to_be_bytes();
+    let h = message_hash.0.to_be_bytes();
+    let seed_bytes = seed.map_or([0u8; 32], |seed| seed.0.to_be_bytes());
+    let first_non_zero = seed_bytes.iter().position(|&byte| byte != 0).unwrap_or(32);
+    let data = &seed_bytes[first_non_zero..];
+
+    let mut k = [0u8; 32];
+    let mut v = [1u8; 32];
+    k = hmac_sha256(&k, &[&v, &[0x00], &x, &h, data]);
+    v = hmac_sha256(&k, &[&v]);
+    k = hmac_sha256(&k, &[&v, &[0x01], &x, &h, data]);
+    v = hmac_sha256(&k, &[&v]);
+    loop {
+        v = hmac_sha256(&k, &[&v]);
+        let candidate = U256::from_be_bytes(v).shr_vartime(4);
+        if candidate != U256::ZERO && candidate < SCALAR_MODULUS {
+            return Scalar(candidate);
+        }
+        k = hmac_sha256(&k, &[&v, &[0x00]]);
+        v = hmac_sha256(&k, &[&v]);
+    }
+}
+
+// Returns HMAC-SHA256 with the given key of the concatenation of the parts
+fn hmac_sha256(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    for part in parts {
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+// ERRORS
+// ================================================================================================
+
+// Errors which can occur when signing or verifying
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EcdsaError {
+    // The message hash is not smaller than 2^251
+    InvalidMessageHash,
+    // The nonce is zero or yields r or s out of [1, 2^251)
+    InvalidK,
+    // r is out of [1, 2^251)
+    InvalidR,
+    // s or its inverse is out of [1, 2^251)
+    InvalidS,
+    // The public key is not the x coordinate of a point of the curve
+    InvalidPublicKey,
+}
+
+impl fmt::Display for EcdsaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMessageHash => write!(f, "the message hash must be smaller than 2^251"),
+            Self::InvalidK => write!(f, "the nonce yields a signature out of range"),
+            Self::InvalidR => write!(f, "r must be in [1, 2^251)"),
+            Self::InvalidS => write!(f, "s must be in [1, 2^251)"),
+            Self::InvalidPublicKey => {
+                write!(
+                    f,
+                    "the public key is not the x coordinate of a point of the curve"
+                )
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+pub mod curve;
+pub mod ecdsa;
 pub mod field;
 pub mod scalar;
 pub mod test;
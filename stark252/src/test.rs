@@ -20,8 +20,16 @@ mod tests {
 
     use std::vec;
   use hash::poseidon_impl::PoseidonHash;
+    use hash::gmimc::{gmimc_apply_round, GmimcMode, GMIMCHASH};
     use hash::rescue::{apply_inv_mds, apply_inv_sbox, apply_sbox, Rescue, apply_mds};
-    use traits::traits::{Field, Hasher, PrimeField};
+    use hash::sponge::{GmimcSponge, Permutation, PoseidonSponge, RescueSponge, Sponge};
+    use hash::transcript::Transcript;
+    use hash::merkle::{MerklePath, MerkleTree, MerkleTreeError};
+    use hash::sparse_merkle::{SparseMerkleTree, SparseMerkleTreeError};
+    use hash::packing::{capacity_bits, modulus_bits, PackingError, PackingLayout};
+    use math::fft::EvaluationDomain;
+    use math::polynomial::{DensePolynomial, PolynomialError};
+    use traits::traits::{Field, GMIMCParameter, Hasher, PrimeField, RescueParameter};
 
     #[test]
     fn exptest1() {
@@ -728,6 +736,15 @@ mod tests {
             assert_eq!(b, c);
         }
     }
+
+    #[test]
+    fn scalar_from_bytes_wide() {
+        assert_eq!(
+            Scalar::from_bytes_wide(&[0xff; 64]),
+            Scalar(U256::from_be_hex("07d9e57c2333766ebaf0ab4cf78bbabb509cf64d14ce60b96021b3f1ea1c688c"))
+        );
+        assert_eq!(Scalar::from_bytes_wide(&[0; 64]), Scalar::ZERO);
+    }
     #[test]
     //divassign
     fn sdivassigntest() {
@@ -827,4 +844,890 @@ mod tests {
         assert_eq!(b, c);
     }
 
+    #[test]
+    fn test_gmimc_hash_and_store_states() {
+        let mut v: Vec<Fp> = (0..23).map(|_| Fp::random()).collect();
+        let mut result = [Fp::ZERO];
+        let mut hash_data = Vec::new();
+        <GMIMCHASH<Fp>>::hash_and_store_states(&mut v, &mut result, &mut hash_data);
+        assert_eq!(result[0], <GMIMCHASH<Fp>>::gmimc_hash(&v));
+
+        // one absorbed state followed by the state after each round, for every absorbed block
+        let rounds = <Fp as GMIMCParameter<Fp>>::GMIMC_NO_OF_ROUNDS;
+        let rate = <Fp as GMIMCParameter<Fp>>::GMIMC_RATE;
+        let blocks = v.len() / rate + 1;
+        assert_eq!(hash_data.len(), blocks * (rounds + 1));
+        assert_eq!(hash_data[0][..rate], v[..rate]);
+        for block in hash_data.chunks(rounds + 1) {
+            for k in 0..rounds {
+                let mut state = block[k].clone();
+                gmimc_apply_round::<Fp>(&mut state, k);
+                assert_eq!(state, block[k + 1]);
+            }
+        }
+        assert_eq!(hash_data[hash_data.len() - 1][0], result[0]);
+
+        // same layout in legacy mode
+        let v: Vec<Fp> = (0..<Fp as GMIMCParameter<Fp>>::NO_OF_COLUMNS)
+            .map(|_| Fp::random())
+            .collect();
+        let mut hash_data = Vec::new();
+        <GMIMCHASH<Fp>>::gmimc_legacy_hash_account(&v, &mut result, &mut hash_data);
+        assert_eq!(result[0], <GMIMCHASH<Fp>>::gmimic_legacy(&v));
+        assert_eq!(hash_data.len(), (v.len() / rate + 1) * (rounds + 1));
+    }
+
+    #[test]
+    fn test_gmimc_sponge() {
+        let a = Fp::random();
+        let b = Fp::random();
+        // inputs of any length are absorbed, and padding does not create collisions
+        let digests = [
+            <GMIMCHASH<Fp>>::gmimc_hash(&[]),
+            <GMIMCHASH<Fp>>::gmimc_hash(&[Fp::ZERO]),
+            <GMIMCHASH<Fp>>::gmimc_hash(&[a]),
+            <GMIMCHASH<Fp>>::gmimc_hash(&[a, Fp::ZERO]),
+            <GMIMCHASH<Fp>>::gmimc_hash(&[a, Fp::ONE]),
+            <GMIMCHASH<Fp>>::gmimc_hash(&[a, b, a, b, a]),
+            <GMIMCHASH<Fp>>::gmimc_hash(&[a, b, a, b, a, Fp::ONE]),
+        ];
+        for i in 0..digests.len() {
+            for j in 0..i {
+                assert_ne!(digests[i], digests[j]);
+            }
+        }
+        // every element contributes to the digest
+        let v: Vec<Fp> = (0..100).map(|_| Fp::random()).collect();
+        let mut w = v.clone();
+        w[99] += Fp::ONE;
+        assert_ne!(<GMIMCHASH<Fp>>::gmimc_hash(&v), <GMIMCHASH<Fp>>::gmimc_hash(&w));
+        let mut u = v.clone();
+        assert_eq!(<GMIMCHASH<Fp>>::hash(&mut u), <GMIMCHASH<Fp>>::gmimc_hash(&v));
+        assert_eq!(u, v);
+    }
+
+    #[test]
+    fn test_gmimc_legacy() {
+        // digest of 0, 1, ..., 68 computed by the fixed width GMIMCHASH::gmimic
+        let v: Vec<Fp> = (0..<Fp as GMIMCParameter<Fp>>::NO_OF_COLUMNS as u64)
+            .map(Fp::from)
+            .collect();
+        assert_eq!(
+            <GMIMCHASH<Fp>>::gmimic_legacy(&v),
+            Fp(U256::from_be_hex("02FA25CD3A9D71E4DCB5509FC53EBDF7CA8B049779DA694B6F29CAC2508B467D"))
+        );
+        assert_eq!(
+            <GMIMCHASH<Fp>>::hash_with_mode(&v, GmimcMode::Legacy),
+            <GMIMCHASH<Fp>>::gmimic_legacy(&v)
+        );
+        #[allow(deprecated)]
+        let digest = <GMIMCHASH<Fp>>::gmimic(v.clone());
+        assert_eq!(digest, <GMIMCHASH<Fp>>::gmimic_legacy(&v));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gmimc_legacy_wrong_length() {
+        let v = vec![Fp::ONE; 3];
+        <GMIMCHASH<Fp>>::gmimic_legacy(&v);
+    }
+
+    fn check_sponge<P: Permutation<Field = Fp>>() {
+        let v: Vec<Fp> = (0..13).map(|_| Fp::random()).collect();
+        let copy = v.clone();
+
+        // absorbing in chunks gives the same output as absorbing at once
+        let mut sponge = <Sponge<P>>::new();
+        sponge.absorb(&v);
+        let digest = sponge.squeeze(5);
+        assert_eq!(v, copy);
+        let mut chunked = <Sponge<P>>::new();
+        chunked.absorb(&v[..4]);
+        chunked.absorb(&[]);
+        chunked.absorb(&v[4..]);
+        assert_eq!(chunked.squeeze(3), digest[..3].to_vec());
+        assert_eq!(chunked.squeeze(2), digest[3..].to_vec());
+
+        // padding separates inputs that only differ by trailing zeros
+        let mut padded = <Sponge<P>>::new();
+        padded.absorb(&v);
+        padded.absorb(&[Fp::ZERO]);
+        assert_ne!(padded.squeeze(5), digest);
+
+        // absorbing after squeezing changes the following outputs
+        sponge.absorb(&v[..1]);
+        assert_ne!(sponge.squeeze(5), digest);
+
+        sponge.reset();
+        sponge.absorb(&v);
+        assert_eq!(sponge.squeeze(5), digest);
+    }
+
+    #[test]
+    fn test_sponge() {
+        check_sponge::<Rescue<Fp>>();
+        check_sponge::<PoseidonHash<Fp>>();
+        check_sponge::<GMIMCHASH<Fp>>();
+    }
+
+    #[test]
+    fn test_rescue_sponge_padding() {
+        let a = Fp::random();
+        let mut sponge = <RescueSponge<Fp>>::new();
+        sponge.absorb(&[a]);
+        let mut state = vec![Fp::ZERO; <Rescue<Fp>>::STATE_WIDTH];
+        state[0] = a;
+        state[1] = Fp::ONE;
+        hash::rescue::permutation(&mut state);
+        assert_eq!(sponge.squeeze(1), vec![state[0]]);
+
+        let mut poseidon = <PoseidonSponge<Fp>>::new();
+        let mut gmimc = <GmimcSponge<Fp>>::new();
+        poseidon.absorb(&[a]);
+        gmimc.absorb(&[a]);
+        assert_ne!(poseidon.squeeze(1), gmimc.squeeze(1));
+    }
+
+    // Transcript of a fixed sequence of operations
+    fn sample_transcript() -> Transcript<Rescue<Fp>> {
+        let mut transcript = <Transcript<Rescue<Fp>>>::new(b"test protocol");
+        transcript.append_field(b"commitment", &[Fp::from(1u64), Fp::from(2u64), Fp::from(3u64)]);
+        transcript.append_bytes(b"message", b"the quick brown fox jumps over the lazy dog");
+        transcript
+    }
+
+    // Packs the bits in a u64, the first bit being the least significant
+    fn bits_to_u64(bits: &[bool]) -> u64 {
+        bits.iter().rev().fold(0, |acc, bit| (acc << 1) | *bit as u64)
+    }
+
+    #[test]
+    fn test_transcript_golden() {
+        let mut transcript = sample_transcript();
+        let alpha = transcript.challenge_scalar(b"alpha");
+        let bits = transcript.challenge_bits(b"queries", 40);
+        assert_eq!(alpha, Fp(U256::from_be_hex("03B1160322FCE45CA6B47DCB4B6C9E0E460F249F7B1238C984A8667F230C466D")));
+        assert_eq!(bits_to_u64(&bits), 0x7BB7D2C9B3);
+    }
+
+    #[test]
+    fn test_transcript() {
+        // the same operations give the same challenges
+        let mut a = sample_transcript();
+        let mut b = sample_transcript();
+        assert_eq!(a.challenge_scalar(b"alpha"), b.challenge_scalar(b"alpha"));
+        assert_eq!(a.challenge_bits(b"queries", 300), b.challenge_bits(b"queries", 300));
+
+        // the challenges depend on the labels and on the messages
+        let mut c = sample_transcript();
+        let mut d = <Transcript<Rescue<Fp>>>::new(b"test protocol");
+        d.append_field(b"commitment", &[Fp::from(1u64), Fp::from(2u64), Fp::from(3u64)]);
+        d.append_bytes(b"message", b"the quick brown fox jumps over the lazy cat");
+        let mut e = <Transcript<Rescue<Fp>>>::new(b"test protocol");
+        e.append_field(b"commitment", &[Fp::from(1u64), Fp::from(2u64), Fp::from(3u64)]);
+        e.append_bytes(b"massage", b"the quick brown fox jumps over the lazy dog");
+        let alpha = c.challenge_scalar(b"alpha");
+        assert_ne!(alpha, d.challenge_scalar(b"alpha"));
+        assert_ne!(alpha, e.challenge_scalar(b"alpha"));
+        assert_ne!(alpha, sample_transcript().challenge_scalar(b"beta"));
+
+        // bytes and field elements with the same values are not confused
+        let mut f = <Transcript<Rescue<Fp>>>::new(b"");
+        let mut g = <Transcript<Rescue<Fp>>>::new(b"");
+        f.append_bytes(b"", &[7]);
+        g.append_field(b"", &[Fp::from(7u64)]);
+        assert_ne!(f.challenge_scalar(b""), g.challenge_scalar(b""));
+
+        assert_eq!(<Transcript<Rescue<Fp>>>::bytes_per_element(), 31);
+        assert_eq!(c.challenge_bits(b"queries", 0), Vec::<bool>::new());
+    }
+
+    #[test]
+    fn test_transcript_challenge_bits() {
+        // the modulus has 252 bits, so 251 bits are read per element and not the 255 bits below
+        // the size of the representation, whose top bits would always be zero
+        let bits_per_element = <Transcript<Rescue<Fp>>>::bits_per_element();
+        assert_eq!(bits_per_element, 251);
+
+        // every bit position of an element is set in some of the squeezed elements
+        let bits = sample_transcript().challenge_bits(b"queries", 64 * bits_per_element);
+        for i in 0..bits_per_element {
+            assert!(bits.chunks(bits_per_element).any(|chunk| chunk[i]));
+        }
+    }
+
+    fn check_merkle_tree<H: Hasher<FieldElement = Fp>, const ARITY: usize>(num_leaves: usize) {
+        let leaves: Vec<Fp> = (0..num_leaves).map(|_| Fp::random()).collect();
+        let tree = <MerkleTree<H, ARITY>>::new(leaves.clone()).unwrap();
+        let root = tree.root();
+
+        for (index, &leaf) in leaves.iter().enumerate() {
+            let path = tree.prove(index).unwrap();
+            assert_eq!(path.siblings.len(), tree.depth());
+            assert_eq!(<MerkleTree<H, ARITY>>::verify(root, num_leaves, index, leaf, &path), Ok(()));
+            assert_eq!(
+                <MerkleTree<H, ARITY>>::verify(root, num_leaves, index, leaf + Fp::ONE, &path),
+                Err(MerkleTreeError::InvalidProof)
+            );
+            assert_eq!(
+                <MerkleTree<H, ARITY>>::verify(root, num_leaves, index ^ 1, leaf, &path),
+                Err(MerkleTreeError::InvalidProof)
+            );
+            // the depth of the path must be the one of the tree
+            assert_eq!(
+                <MerkleTree<H, ARITY>>::verify(root, ARITY * num_leaves, index, leaf, &path),
+                Err(MerkleTreeError::InvalidProof)
+            );
+        }
+
+        // the first internal node is rejected as a leaf, with the path of its parent, in a tree of
+        // the actual size as well as in a smaller one
+        let node = tree.prove(ARITY).unwrap().siblings[1][0];
+        let path = MerklePath {
+            siblings: tree.prove(0).unwrap().siblings[1..].to_vec(),
+        };
+        assert_eq!(
+            <MerkleTree<H, ARITY>>::verify(root, num_leaves, 0, node, &path),
+            Err(MerkleTreeError::InvalidProof)
+        );
+        assert_eq!(
+            <MerkleTree<H, ARITY>>::verify(root, num_leaves / ARITY, 0, node, &path),
+            Err(MerkleTreeError::InvalidProof)
+        );
+        assert_eq!(
+            <MerkleTree<H, ARITY>>::verify(root, num_leaves, num_leaves, leaves[0], &path),
+            Err(MerkleTreeError::LeafIndexOutOfBounds(num_leaves, num_leaves))
+        );
+
+        // unsorted indexes, with a duplicate and with siblings opened together
+        let indexes = [num_leaves - 1, 0, 3, 1, 3, num_leaves / 2];
+        let opened: Vec<Fp> = indexes.iter().map(|&i| leaves[i]).collect();
+        let proof = tree.prove_batch(&indexes).unwrap();
+        assert_eq!(<MerkleTree<H, ARITY>>::verify_batch(root, num_leaves, &indexes, &opened, &proof), Ok(()));
+
+        // the nodes shared by the paths appear once
+        let paths_len: usize =
+            indexes.iter().map(|&i| tree.prove(i).unwrap().siblings.concat().len()).sum();
+        assert!(proof.nodes.concat().len() < paths_len);
+
+        let mut wrong = opened.clone();
+        wrong[2] += Fp::ONE;
+        assert_eq!(
+            <MerkleTree<H, ARITY>>::verify_batch(root, num_leaves, &indexes, &wrong, &proof),
+            Err(MerkleTreeError::InvalidProof)
+        );
+        let mut wrong = opened.clone();
+        wrong[4] += Fp::ONE;
+        assert_eq!(
+            <MerkleTree<H, ARITY>>::verify_batch(root, num_leaves, &indexes, &wrong, &proof),
+            Err(MerkleTreeError::InvalidProof)
+        );
+        assert_eq!(
+            <MerkleTree<H, ARITY>>::verify_batch(root, num_leaves, &indexes[1..], &opened[1..], &proof),
+            Err(MerkleTreeError::InvalidProof)
+        );
+        assert_eq!(
+            <MerkleTree<H, ARITY>>::verify_batch(root, num_leaves, &indexes, &opened[1..], &proof),
+            Err(MerkleTreeError::NumberOfLeavesMismatch(6, 5))
+        );
+        // the depth of the proof must be the one of the tree
+        assert_eq!(
+            <MerkleTree<H, ARITY>>::verify_batch(
+                root,
+                ARITY * num_leaves,
+                &indexes,
+                &opened,
+                &proof
+            ),
+            Err(MerkleTreeError::InvalidProof)
+        );
+        let mut shallow = proof.clone();
+        shallow.depth -= 1;
+        shallow.nodes.remove(0);
+        assert_eq!(
+            <MerkleTree<H, ARITY>>::verify_batch(root, num_leaves, &indexes, &opened, &shallow),
+            Err(MerkleTreeError::InvalidProof)
+        );
+        assert_eq!(
+            <MerkleTree<H, ARITY>>::verify_batch(root, num_leaves + 1, &indexes, &opened, &proof),
+            Err(MerkleTreeError::NumberOfLeavesNotPowerOfArity(ARITY, num_leaves + 1))
+        );
+
+        // opening all the leaves needs no other node
+        let all: Vec<usize> = (0..num_leaves).collect();
+        let proof = tree.prove_batch(&all).unwrap();
+        assert!(proof.nodes.iter().all(|nodes| nodes.is_empty()));
+        assert_eq!(<MerkleTree<H, ARITY>>::verify_batch(root, num_leaves, &all, &leaves, &proof), Ok(()));
+
+        assert_eq!(
+            tree.prove(num_leaves),
+            Err(MerkleTreeError::LeafIndexOutOfBounds(num_leaves, num_leaves))
+        );
+        assert_eq!(tree.prove_batch(&[]), Err(MerkleTreeError::TooFewLeafIndexes));
+    }
+
+    #[test]
+    fn test_merkle_tree() {
+        check_merkle_tree::<Rescue<Fp>, 2>(16);
+        check_merkle_tree::<PoseidonHash<Fp>, 2>(8);
+        check_merkle_tree::<GMIMCHASH<Fp>, 4>(16);
+        check_merkle_tree::<PoseidonHash<Fp>, 4>(16);
+    }
+
+    #[test]
+    fn test_merkle_tree_shape() {
+        let leaves: Vec<Fp> = (0..4).map(|_| Fp::random()).collect();
+        // the leaves are hashed after the tag 1
+        let mut hashes: Vec<Fp> =
+            leaves.iter().map(|&leaf| <Rescue<Fp>>::hash(&mut vec![Fp::ONE, leaf])).collect();
+        let binary = <MerkleTree<Rescue<Fp>>>::new(leaves.clone()).unwrap();
+        let left = <Rescue<Fp>>::hash(&mut hashes[..2].to_vec());
+        let right = <Rescue<Fp>>::hash(&mut hashes[2..].to_vec());
+        assert_eq!(binary.root(), <Rescue<Fp>>::hash(&mut vec![left, right]));
+        assert_eq!(binary.depth(), 2);
+        assert_eq!(binary.leaves(), &leaves[..]);
+        let quaternary = <MerkleTree<Rescue<Fp>, 4>>::new(leaves.clone()).unwrap();
+        assert_eq!(quaternary.root(), <Rescue<Fp>>::hash(&mut hashes));
+        assert_eq!(quaternary.depth(), 1);
+
+        assert_eq!(
+            <MerkleTree<Rescue<Fp>, 4>>::new(leaves[..2].to_vec()),
+            Err(MerkleTreeError::TooFewLeaves(4, 2))
+        );
+        assert_eq!(
+            <MerkleTree<Rescue<Fp>, 4>>::new(vec![Fp::ZERO; 8]),
+            Err(MerkleTreeError::NumberOfLeavesNotPowerOfArity(4, 8))
+        );
+        assert_eq!(
+            <MerkleTree<Rescue<Fp>, 3>>::new(vec![Fp::ZERO; 9]),
+            Err(MerkleTreeError::UnsupportedArity(3))
+        );
+    }
+
+    fn check_sparse_merkle_tree<H: Hasher<FieldElement = Fp>>() {
+        let mut tree = <SparseMerkleTree<H>>::new(32).unwrap();
+        let empty_root = tree.root();
+        let (a, b) = (Fp::from(rand::random::<u32>()), Fp::from(rand::random::<u32>()));
+        let (x, y) = (Fp::random(), Fp::ZERO);
+
+        let proof = tree.prove(a).unwrap();
+        assert_eq!(proof.siblings.len(), 32);
+        assert_eq!(
+            <SparseMerkleTree<H>>::verify_non_membership(empty_root, 32, a, &proof),
+            Ok(())
+        );
+
+        tree.insert(a, x).unwrap();
+        tree.insert(b, y).unwrap();
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree.insert(a, y), Err(SparseMerkleTreeError::KeyAlreadyExists));
+        let root = tree.root();
+        assert_ne!(root, empty_root);
+
+        // a zero value is a member
+        let proof = tree.prove(b).unwrap();
+        assert_eq!(
+            <SparseMerkleTree<H>>::verify_membership(root, 32, b, y, &proof),
+            Ok(())
+        );
+        assert_eq!(
+            <SparseMerkleTree<H>>::verify_non_membership(root, 32, b, &proof),
+            Err(SparseMerkleTreeError::InvalidProof)
+        );
+        let proof = tree.prove(a).unwrap();
+        assert_eq!(
+            <SparseMerkleTree<H>>::verify_membership(root, 32, a, x, &proof),
+            Ok(())
+        );
+        assert_eq!(
+            <SparseMerkleTree<H>>::verify_membership(root, 32, a, x + Fp::ONE, &proof),
+            Err(SparseMerkleTreeError::InvalidProof)
+        );
+        // the depth is the one of the tree, not the length of the proof
+        assert_eq!(
+            <SparseMerkleTree<H>>::verify_membership(root, 31, a, x, &proof),
+            Err(SparseMerkleTreeError::InvalidProof)
+        );
+        assert_eq!(
+            <SparseMerkleTree<H>>::verify_membership(root, 0, a, x, &proof),
+            Err(SparseMerkleTreeError::InvalidDepth(0))
+        );
+
+        assert_eq!(tree.update(a, y), Ok(x));
+        assert_eq!(tree.get(a), Ok(Some(y)));
+        assert_ne!(tree.root(), root);
+        assert_eq!(tree.update(a, x), Ok(y));
+        assert_eq!(tree.root(), root);
+
+        // deleting all the keys gives back the empty tree
+        assert_eq!(tree.delete(a), Ok(x));
+        assert_eq!(tree.delete(a), Err(SparseMerkleTreeError::KeyNotFound));
+        assert_eq!(tree.update(a, x), Err(SparseMerkleTreeError::KeyNotFound));
+        let proof = tree.prove(a).unwrap();
+        assert_eq!(
+            <SparseMerkleTree<H>>::verify_non_membership(tree.root(), 32, a, &proof),
+            Ok(())
+        );
+        assert_eq!(tree.delete(b), Ok(y));
+        assert!(tree.is_empty());
+        assert_eq!(tree, <SparseMerkleTree<H>>::new(32).unwrap());
+    }
+
+    #[test]
+    fn test_sparse_merkle_tree() {
+        check_sparse_merkle_tree::<GMIMCHASH<Fp>>();
+        check_sparse_merkle_tree::<PoseidonHash<Fp>>();
+        check_sparse_merkle_tree::<Rescue<Fp>>();
+    }
+
+    #[test]
+    fn test_sparse_merkle_tree_full_depth() {
+        // any field element is a key of a tree of depth 256
+        let mut tree = <SparseMerkleTree<GMIMCHASH<Fp>>>::new(256).unwrap();
+        let (key, value) = (-Fp::ONE, Fp::random());
+        tree.insert(key, value).unwrap();
+        let proof = tree.prove(key).unwrap();
+        assert_eq!(
+            <SparseMerkleTree<GMIMCHASH<Fp>>>::verify_membership(tree.root(), 256, key, value, &proof),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_sparse_merkle_tree_small_depth() {
+        let mut tree = <SparseMerkleTree<Rescue<Fp>>>::new(3).unwrap();
+        let keys: Vec<Fp> = (0..8u64).map(Fp::from).collect();
+        for &key in &keys[..5] {
+            tree.insert(key, key.square()).unwrap();
+        }
+        let mut nodes: Vec<Fp> = keys
+            .iter()
+            .map(|&key| match tree.get(key).unwrap() {
+                // leaves are prefixed with the leaf tag 1 of the Merkle trees
+                Some(value) => <Rescue<Fp>>::hash(&mut vec![Fp::from(1u64), key, value]),
+                None => Fp::ZERO,
+            })
+            .collect();
+        while nodes.len() > 1 {
+            nodes = nodes.chunks(2).map(|pair| <Rescue<Fp>>::hash(&mut pair.to_vec())).collect();
+        }
+        assert_eq!(tree.root(), nodes[0]);
+
+        assert_eq!(tree.get(Fp::from(8u64)), Err(SparseMerkleTreeError::KeyOutOfRange(3)));
+        assert_eq!(tree.insert(-Fp::ONE, Fp::ONE), Err(SparseMerkleTreeError::KeyOutOfRange(3)));
+        assert_eq!(
+            <SparseMerkleTree<Rescue<Fp>>>::new(257),
+            Err(SparseMerkleTreeError::InvalidDepth(257))
+        );
+
+        // a proof for another depth does not verify
+        let proof = tree.prove(keys[6]).unwrap();
+        let mut longer = proof.clone();
+        longer.siblings.push(Fp::ZERO);
+        let root = tree.root();
+        assert_eq!(
+            <SparseMerkleTree<Rescue<Fp>>>::verify_non_membership(root, 3, keys[6], &proof),
+            Ok(())
+        );
+        assert_eq!(
+            <SparseMerkleTree<Rescue<Fp>>>::verify_non_membership(root, 3, keys[6], &longer),
+            Err(SparseMerkleTreeError::InvalidProof)
+        );
+        assert_eq!(
+            <SparseMerkleTree<Rescue<Fp>>>::verify_non_membership(root, 4, keys[6], &longer),
+            Err(SparseMerkleTreeError::InvalidProof)
+        );
+        let mut shorter = proof.clone();
+        shorter.siblings.remove(0);
+        assert_eq!(
+            <SparseMerkleTree<Rescue<Fp>>>::verify_non_membership(root, 3, keys[6], &shorter),
+            Err(SparseMerkleTreeError::InvalidProof)
+        );
+    }
+
+    #[test]
+    fn test_rescue_modified_hash() {
+        // address, balance of more than 100 bits and nonce of 128 bits, both truncated to 100 bits
+        let value = vec![
+            Fp::from(5u64),
+            Fp(U256::from_be_hex("0000000000000000000000000000000000000123456789abcdef0123456789ab")),
+            Fp::from(u128::MAX),
+        ];
+        let mut result = [Fp::ZERO];
+        let mut hash_data = Vec::new();
+        <Rescue<Fp>>::rescue_modified_hash(value.clone(), &mut result, &mut hash_data);
+        assert_eq!(
+            result[0],
+            Fp(U256::from_be_hex("04FCFCC3776885DCA408A960FC5A7E41307411F33B7171662022ABB642517024"))
+        );
+        assert_eq!(hash_data.len(), <Fp as RescueParameter<Fp>>::RESCUE_NO_OF_ROUNDS);
+
+        // the packed element holds the nonce in its 100 least significant bits
+        let layout = <PackingLayout<Fp>>::new(&[100, 100]).unwrap();
+        let packed = layout.pack(&layout.truncate(&[value[2], value[1]])).unwrap();
+        let nonce = Fp::from(u128::MAX >> 28);
+        let balance = Fp::from(0x3456789abcdef0123456789abu128);
+        assert_eq!(packed, vec![nonce + balance * Fp::from(1u128 << 100)]);
+        assert_eq!(layout.unpack(&packed), Ok(vec![nonce, balance]));
+    }
+
+    #[test]
+    fn test_packing_layout() {
+        // 100 + 100 bits fit in an element, the third value starts a new one
+        let layout = <PackingLayout<Fp>>::new(&[100, 100, 64, 1, 251]).unwrap();
+        assert_eq!(modulus_bits::<Fp>(), 252);
+        assert_eq!(capacity_bits::<Fp>(), 251);
+        assert_eq!(layout.num_elements(), 3);
+        let values = vec![
+            Fp::from(u128::MAX >> 28),
+            Fp::from(12345u64),
+            Fp::from(u64::MAX),
+            Fp::ONE,
+            Fp(U256::from_be_hex("07FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF")),
+        ];
+        let packed = layout.pack(&values).unwrap();
+        assert_eq!(packed.len(), 3);
+        assert_eq!(packed[1], Fp::from(u64::MAX) + Fp::from(1u128 << 64));
+        assert_eq!(layout.unpack(&packed), Ok(values.clone()));
+        assert_eq!(
+            layout.hash::<Rescue<Fp>>(&values),
+            Ok(<Rescue<Fp>>::hash(&mut packed.clone()))
+        );
+
+        // range checks
+        let mut wrong = values.clone();
+        wrong[3] = Fp::from(2u64);
+        assert_eq!(layout.pack(&wrong), Err(PackingError::ValueOutOfRange(3, 1)));
+        assert_eq!(layout.truncate(&wrong)[3], Fp::ZERO);
+        assert_eq!(layout.pack(&values[1..]), Err(PackingError::WrongNumberOfValues(5, 4)));
+        assert_eq!(<PackingLayout<Fp>>::new(&[252]), Err(PackingError::InvalidWidth(252)));
+        assert_eq!(<PackingLayout<Fp>>::new(&[8, 0]), Err(PackingError::InvalidWidth(0)));
+
+        // packed elements with bits outside of the values
+        let mut wrong = packed.clone();
+        wrong[1] += Fp::from(1u128 << 65);
+        assert_eq!(layout.unpack(&wrong), Err(PackingError::InvalidPackedElement(1)));
+        assert_eq!(layout.unpack(&packed[1..]), Err(PackingError::WrongNumberOfElements(3, 2)));
+    }
+    fn random_polynomial(num_coeffs: usize) -> DensePolynomial<Fp> {
+        DensePolynomial::new((0..num_coeffs).map(|_| Fp::random()).collect())
+    }
+
+    #[test]
+    fn test_polynomial_arithmetic() {
+        let a = random_polynomial(70);
+        let b = random_polynomial(45);
+        let x = Fp::random();
+
+        let sum = &a + &b;
+        assert_eq!(sum.evaluate(x), a.evaluate(x) + b.evaluate(x));
+        let difference = &a - &b;
+        assert_eq!(difference.evaluate(x), a.evaluate(x) - b.evaluate(x));
+        assert_eq!(&a - &a, DensePolynomial::zero());
+        assert_eq!((-&a).evaluate(x), -a.evaluate(x));
+
+        // the three multiplications agree, including with unbalanced factors
+        let product = a.mul_naive(&b);
+        assert_eq!(product.degree(), 113);
+        assert_eq!(product.evaluate(x), a.evaluate(x) * b.evaluate(x));
+        assert_eq!(a.mul_karatsuba(&b), product);
+        assert_eq!(a.mul_ntt(&b), product);
+        assert_eq!(&a * &b, product);
+        let c = random_polynomial(200);
+        assert_eq!(c.mul_karatsuba(&b), c.mul_naive(&b));
+        assert_eq!(c.mul_ntt(&b), c.mul_naive(&b));
+        assert_eq!(a.mul_ntt(&DensePolynomial::zero()), DensePolynomial::zero());
+
+        // (a * b + r) / b = (a, r) when r has a lower degree than b
+        let r = random_polynomial(20);
+        let (quotient, remainder) = (&product + &r).divide_with_remainder(&b).unwrap();
+        assert_eq!(quotient, a);
+        assert_eq!(remainder, r);
+        let (quotient, remainder) = b.divide_with_remainder(&a).unwrap();
+        assert_eq!((quotient, remainder), (DensePolynomial::zero(), b.clone()));
+        assert_eq!(
+            a.divide_with_remainder(&DensePolynomial::zero()),
+            Err(PolynomialError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_polynomial_interpolation() {
+        let xs: Vec<Fp> = (0..10).map(|_| Fp::random()).collect();
+        let ys: Vec<Fp> = (0..10).map(|_| Fp::random()).collect();
+        let polynomial = DensePolynomial::interpolate(&xs, &ys).unwrap();
+        assert!(polynomial.degree() < 10);
+        assert_eq!(polynomial.evaluate_many(&xs), ys);
+
+        let vanishing = DensePolynomial::vanishing(&xs);
+        assert_eq!(vanishing.degree(), 10);
+        assert_eq!(vanishing.leading_coefficient(), Fp::ONE);
+        assert!(vanishing.evaluate_many(&xs).iter().all(|y| y.is_zero()));
+
+        let mut duplicated = xs.clone();
+        duplicated[7] = duplicated[2];
+        assert_eq!(
+            DensePolynomial::interpolate(&duplicated, &ys),
+            Err(PolynomialError::DuplicatePoint(7))
+        );
+        assert_eq!(
+            DensePolynomial::interpolate(&xs, &ys[..9]),
+            Err(PolynomialError::NumberOfValuesMismatch(10, 9))
+        );
+    }
+
+    #[test]
+    fn test_polynomial_cosets() {
+        let domain = <EvaluationDomain<Fp>>::new(16).unwrap();
+        let polynomial = random_polynomial(12);
+        let offsets = [Fp::ONE, Fp::GENERATOR, Fp::GENERATOR.square()];
+        let evaluations = polynomial.evaluate_over_cosets(&domain, &offsets).unwrap();
+        for (&offset, values) in offsets.iter().zip(&evaluations) {
+            let points: Vec<Fp> = domain.elements().iter().map(|&x| offset * x).collect();
+            assert_eq!(values, &polynomial.evaluate_many(&points));
+            assert_eq!(
+                DensePolynomial::interpolate_over_coset(&domain, offset, values).unwrap(),
+                polynomial
+            );
+
+            let vanishing = DensePolynomial::coset_vanishing(&domain, offset);
+            assert_eq!(vanishing, DensePolynomial::vanishing(&points));
+        }
+
+        assert_eq!(
+            random_polynomial(17).evaluate_over_coset(&domain, Fp::ONE),
+            Err(PolynomialError::DegreeTooLarge(16, 16))
+        );
+    }
+}
+
+#[cfg(test)]
+mod curvetest {
+    use crypto_bigint::U256;
+    use math::msm::{msm, naive_msm};
+    use math::weierstrass::JacobianPoint;
+    use traits::traits::Field;
+
+    use crate::{
+        curve::{from_x, AffinePoint, ProjectivePoint, StarkParameters},
+        field::Fp,
+        scalar::{Scalar, SCALAR_MODULUS},
+    };
+
+    #[test]
+    fn test_generator() {
+        let g = ProjectivePoint::generator();
+        assert!(g.is_on_curve());
+        assert!(g.to_affine().is_on_curve());
+        assert!(g.mul_by_words(&SCALAR_MODULUS.to_words()).is_identity());
+        assert!(ProjectivePoint::identity().is_on_curve());
+        assert!(AffinePoint::identity().is_on_curve());
+    }
+
+    #[test]
+    fn test_addition_and_doubling() {
+        let g = ProjectivePoint::generator();
+        let identity = ProjectivePoint::identity();
+        assert_eq!(g + identity, g);
+        assert_eq!(identity + g, g);
+        assert_eq!(identity.double(), identity);
+        assert_eq!(g + g, g.double());
+        assert_eq!(g - g, identity);
+
+        let p = g * Scalar::random();
+        let q = g * Scalar::random();
+        let r = g * Scalar::random();
+        assert_eq!(p + q, q + p);
+        assert_eq!((p + q) + r, p + (q + r));
+        assert_eq!(p.double().double(), p + p + p + p);
+        assert_eq!(p + q.to_affine(), p + q);
+        assert_eq!(p - q.to_affine(), p - q);
+        assert!((p + q).is_on_curve());
+        assert_eq!(p.to_affine().to_projective(), p);
+
+        // the jacobian doubling with the a * Z^4 term
+        let pj = JacobianPoint::<StarkParameters>::from(p);
+        let qj = JacobianPoint::from(q);
+        assert!(pj.double().is_on_curve());
+        assert_eq!(ProjectivePoint::from(pj.double()), p.double());
+        assert_eq!(ProjectivePoint::from(pj + qj), p + q);
+        assert_eq!(ProjectivePoint::from(pj + q.to_affine()), p + q);
+    }
+
+    #[test]
+    fn test_scalar_multiplication() {
+        let g = ProjectivePoint::generator();
+        assert!((g * Scalar::ZERO).is_identity());
+        assert_eq!(g * Scalar::ONE, g);
+        assert_eq!(g * -Scalar::ONE, -g);
+        assert_eq!(g * Scalar::from(5u64), g + g + g + g + g);
+        for _ in 0..5 {
+            let a = Scalar::random();
+            let b = Scalar::random();
+            assert_eq!(g * a, g.mul_by_words(&a.0.to_words()));
+            assert_eq!((g * a) * b, g * (a * b));
+            assert_eq!(g * a + g * b, g * (a + b));
+        }
+        // [18]G, from the test vectors of starknet-crypto
+        assert_eq!(
+            (g * Scalar::from(18u64)).to_affine().x,
+            Fp(U256::from_be_hex("019661066e96a8b9f06a1d136881ee924dfb6a885239caa5fd3f87a54c6b25c4"))
+        );
+    }
+
+    #[test]
+    fn test_from_x() {
+        let p = (ProjectivePoint::generator() * Scalar::random()).to_affine();
+        let q = from_x(p.x).unwrap();
+        assert!(q == p || q == -p);
+        assert!(q.is_on_curve());
+        assert!(from_x(Fp(U256::from_be_hex(
+            "03ee9bffffffffff26ffffffff60ffffffffffffffffffffffffffff004accff"
+        )))
+        .is_none());
+    }
+
+    #[test]
+    fn test_msm() {
+        let bases: Vec<ProjectivePoint> =
+            (0..40).map(|_| ProjectivePoint::generator() * Scalar::random()).collect();
+        let mut scalars: Vec<Scalar> = (0..40).map(|_| Scalar::random()).collect();
+        scalars[0] = Scalar::ZERO;
+        scalars[1] = Scalar::ONE;
+        scalars[2] = -Scalar::ONE;
+        assert_eq!(msm(&bases, &scalars), naive_msm(&bases, &scalars));
+    }
+}
+
+#[cfg(test)]
+mod ecdsatest {
+    use crypto_bigint::U256;
+    use traits::traits::Field;
+
+    use crate::{
+        ecdsa::{generate_k, get_public_key, sign, sign_with_k, verify, EcdsaError, Signature},
+        field::Fp,
+        scalar::Scalar,
+    };
+
+    fn fp(hex: &str) -> Fp {
+        Fp(U256::from_be_hex(hex))
+    }
+
+    fn scalar(hex: &str) -> Scalar {
+        Scalar(U256::from_be_hex(hex))
+    }
+
+    // Test vectors of starknet-crypto, ported from crypto-cpp
+    #[test]
+    fn test_public_key() {
+        assert_eq!(
+            get_public_key(&scalar("03c1e9550e66958296d11b60f8e8e7a7ad990d07fa65d5f7652c4a6c87d4e3cc")),
+            fp("077a3b314db07c45076d11f62b6f9e748a39790441823307743cf00d6597ea43")
+        );
+        assert_eq!(
+            get_public_key(&scalar("0000000000000000000000000000000000000000000000000000000000000012")),
+            fp("019661066e96a8b9f06a1d136881ee924dfb6a885239caa5fd3f87a54c6b25c4")
+        );
+    }
+
+    #[test]
+    fn test_verify_vectors() {
+        // the public key is the x coordinate of the generator, i.e. the private key is 1
+        let signature = Signature {
+            r: fp("0411494b501a98abd8262b0da1351e17899a0c4ef23dd2f96fec5ba847310b20"),
+            s: fp("0405c3191ab3883ef2b763af35bc5f5d15b3b4e99461d70e84c654a351a7c81b"),
+        };
+        let public_key = fp("01ef15c18599971b7beced415a40f0c7deacfd9b0d1819e03d723d8bc943cfca");
+        assert_eq!(verify(&public_key, &Fp::from(2u64), &signature), Ok(true));
+
+        // signing with private key 1 and k = 3 gives the same signature
+        let k = Scalar::from(3u64);
+        assert_eq!(sign_with_k(&Scalar::ONE, &Fp::from(2u64), &k), Ok(signature));
+
+        let signature = Signature {
+            r: fp("0173fd03d8b008ee7432977ac27d1e9d1a1f6c98b1a2f05fa84a21c84c44e882"),
+            s: fp("01f2c44a7798f55192f153b4c48ea5c1241fbb69e6132cc8a0da9c5b62a4286e"),
+        };
+        let public_key = fp("077a4b314db07c45076d11f62b6f9e748a39790441823307743cf00d6597ea43");
+        let message = fp("0397e76d1667c4454bfb83514e120583af836f8e32a516765497823eabe16a3f");
+        assert_eq!(verify(&public_key, &message, &signature), Ok(false));
+
+        let public_key = fp("03ee9bffffffffff26ffffffff60ffffffffffffffffffffffffffff004accff");
+        let signature = Signature {
+            r: fp("0411494b501a98abd8262b0da1351e17899a0c4ef23dd2f96fec5ba847310b20"),
+            s: fp("0405c3191ab3883ef2b763af35bc5f5d15b3b4e99461d70e84c654a351a7c81b"),
+        };
+        assert_eq!(
+            verify(&public_key, &Fp::from(2u64), &signature),
+            Err(EcdsaError::InvalidPublicKey)
+        );
+    }
+
+    // Test vectors of starknet-crypto, generated with cairo-lang
+    #[test]
+    fn test_generate_k() {
+        let vectors = [
+            ("0080977da1148412a7976215729d396b72aec9e955498757a7b859281354b4b1", "03fa56dcdbe2fb6769a83786469faf589a3d1e31c66db8b0432f741a38cdeed1", "0776cc1aa4c66417a4923768b9d4a7cfca731e862e4972ed930d8f2ad45d352b", "0013480c97bb5861404aa16e1f97a99411ba8f4039b2d54de839dea5c9f0af47"),
+            ("00acf1ce22cb1f49d4fc7a6df93cd290d28f4c5a27888c9624b07cfa193de992", "06ad6342c62315862f51722808d2764a60824f9c5894105dffbb6478cfb06a95", "01314de4fcf69889ea0cdf4aefd1cc7732d1dfbdc6476066e3132c1609756bd0", "0687b462764b919fabefcb84fe77a4eae838f45b97f49b2d24fec995ff482c04"),
+            ("010b559a3b4dc1b7137d90521cb413b397ff07963214d128a92d65aec7182f68", "07e3184f4bef18f371bc53fc412dff1b30dbc94f758490fb8e2349bae647a642", "03fe27199aaad4e700559e2436a919f4de70def585a6deb2f4c087fdf6a27c1b", "00514de5048c11bf01f3dc98a131e0a3fde03d6269cdfab69d944c8281149184"),
+            ("058a8fc2bed05af3ae202f0ea4f6e724b6d3b1034382c7a2e1a3a06bd48bf7ea", "00efacf45682998e4748e853f13a789b4729be197353eb1b8063fd425e0576f8", "05a595cc1e2dcdb26e2ee3964aaa55090bff0c02be6980f098669bc8c87fb994", "0610bd4aec3a26b00331daee8baefc2ad9c94eab42d21384851a1c4fcd5c0483"),
+        ];
+        for (message, private_key, seed, k) in vectors {
+            assert_eq!(generate_k(&fp(message), &scalar(private_key), Some(&fp(seed))), scalar(k));
+        }
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        // deterministic signature, cross-checked with an independent implementation
+        let private_key = scalar("03c1e9550e66958296d11b60f8e8e7a7ad990d07fa65d5f7652c4a6c87d4e3cc");
+        let message = fp("0397e76d1667c4454bfb83514e120583af836f8e32a516765497823eabe16a3f");
+        assert_eq!(
+            generate_k(&message, &private_key, None),
+            scalar("050a50e20a9fb5b33f618ce4ddec8df60f40d3ac3018453bcc002cee71140cd4")
+        );
+        let signature = sign(&private_key, &message).unwrap();
+        assert_eq!(
+            signature,
+            Signature {
+                r: fp("0173fd03d8b008ee7432977ac27d1e9d1a1f6c98b1a2f05fa84a21c84c44e882"),
+                s: fp("04b6d75385aed025aa222f28a0adc6d58db78ff17e51c3f59e259b131cd5a1cc"),
+            }
+        );
+        let public_key = get_public_key(&private_key);
+        assert_eq!(verify(&public_key, &message, &signature), Ok(true));
+        assert_eq!(verify(&public_key, &(message + Fp::ONE), &signature), Ok(false));
+
+        for _ in 0..3 {
+            let private_key = Scalar::random();
+            let message = Fp(Fp::random().0.shr_vartime(5));
+            let signature = sign(&private_key, &message).unwrap();
+            assert_eq!(verify(&get_public_key(&private_key), &message, &signature), Ok(true));
+            let other_key = get_public_key(&Scalar::random());
+            assert_eq!(verify(&other_key, &message, &signature), Ok(false));
+        }
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let bound = fp("0800000000000000000000000000000000000000000000000000000000000000");
+        let private_key = Scalar::from(7u64);
+        assert_eq!(sign(&private_key, &bound), Err(EcdsaError::InvalidMessageHash));
+        assert_eq!(
+            sign_with_k(&private_key, &Fp::ONE, &Scalar::ZERO),
+            Err(EcdsaError::InvalidK)
+        );
+
+        let public_key = get_public_key(&private_key);
+        let signature = sign(&private_key, &Fp::ONE).unwrap();
+        assert_eq!(
+            verify(&public_key, &bound, &signature),
+            Err(EcdsaError::InvalidMessageHash)
+        );
+        let invalid_r = Signature { r: Fp::ZERO, ..signature };
+        assert_eq!(verify(&public_key, &Fp::ONE, &invalid_r), Err(EcdsaError::InvalidR));
+        let invalid_s = Signature { s: bound, ..signature };
+        assert_eq!(verify(&public_key, &Fp::ONE, &invalid_s), Err(EcdsaError::InvalidS));
+    }
 }
@@ -122,6 +122,12 @@ pub trait PrimeField: Field<BaseField = Self> {
 
     // fn to_repr(&self)->Self::Repr;
 
+    // Returns the canonical value of the element (smaller than the modulus) as little-endian
+    // u64 words. It differs from to_words() for fields stored in Montgomery form.
+    fn to_canonical_words(&self) -> Vec<u64> {
+        self.to_words()
+    }
+
     //Modulus of the PrimeField
     const MODULUS: &'static str;
 
@@ -161,13 +167,85 @@ pub trait Extensible<const N: usize>: PrimeField + Copy {
 
 // A field is always an extension of itself.
 pub trait ExtensionOf<E: Field>: From<E> {
+    // Degree of the extension over E
+    const DEGREE: usize;
+
     fn mul_base(self, other: E) -> Self;
+
+    // Returns the DEGREE coordinates of the element over E, from the constant one up
+    fn to_base_elements(self) -> Vec<E>;
+
+    // Returns the element with the given coordinates over E, of which there must be DEGREE
+    fn from_base_elements(elements: &[E]) -> Self;
 }
 impl<E: Field> ExtensionOf<E> for E {
+    const DEGREE: usize = 1;
+
     #[inline(always)]
     fn mul_base(self, other: E) -> Self {
         self * other
     }
+
+    fn to_base_elements(self) -> Vec<E> {
+        vec![self]
+    }
+
+    fn from_base_elements(elements: &[E]) -> Self {
+        elements[0]
+    }
+}
+
+// CURVE GROUP TRAIT
+// ================================================================================================
+// Group of points of an elliptic curve, written additively. Multiplication by a scalar is the
+// scalar multiplication of the group, whose order is the modulus of ScalarField.
+pub trait CurveGroup:
+    Copy
+    + Clone
+    + Debug
+    + Send
+    + Sync
+    + Eq
+    + PartialEq
+    + Add<Self, Output = Self>
+    + Sub<Self, Output = Self>
+    + AddAssign<Self>
+    + SubAssign<Self>
+    + Neg<Output = Self>
+    + Mul<Self::ScalarField, Output = Self>
+{
+    // Specifies the field of the scalars
+    type ScalarField: Field;
+
+    // Returns the identity of the group
+    fn identity() -> Self;
+
+    // Returns the standard generator of the group
+    fn generator() -> Self;
+
+    // Indicates whether the point is the identity
+    fn is_identity(&self) -> bool;
+
+    // Returns the double of the point
+    fn double(&self) -> Self;
+}
+
+// PAIRING TRAIT
+// ================================================================================================
+// Bilinear pairing e: G1 x G2 -> GT between two groups of the same prime order. Protocols such as
+// KZG only need to compare a product of pairings with the identity of the target group.
+pub trait Pairing {
+    // Specifies the field of the scalars of both source groups
+    type ScalarField: PrimeField;
+
+    // Specifies the first source group
+    type G1: CurveGroup<ScalarField = Self::ScalarField>;
+
+    // Specifies the second source group
+    type G2: CurveGroup<ScalarField = Self::ScalarField>;
+
+    // Indicates whether the product of the pairings e(p_i, q_i) is the identity
+    fn pairing_check(terms: &[(Self::G1, Self::G2)]) -> bool;
 }
 
 // HASHER TRAITS